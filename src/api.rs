@@ -1,11 +1,14 @@
+mod batch;
 mod client;
 mod endpoint;
 pub mod error;
 mod header;
 pub mod ignore;
-mod paged;
+pub(crate) mod paged;
 mod params;
 mod query;
+mod render;
+mod upsert;
 
 pub mod booking;
 pub mod holidays;
@@ -14,6 +17,7 @@ pub mod resources;
 pub mod sort_by;
 pub mod time_entries;
 pub mod vacations;
+pub mod webhooks;
 
 pub use endpoint::Endpoint;
 pub use error::ApiError;
@@ -24,10 +28,17 @@ pub use self::client::AsyncClient;
 pub use self::client::Client;
 pub use self::client::RestClient;
 
+pub use self::params::FieldUpdate;
 pub use self::params::ParamValue;
 pub use self::params::QueryParams;
 
 pub use self::query::AsyncQuery;
 pub use self::query::Query;
 
-pub use self::paged::{paged, Paged, Pagination};
+pub use self::batch::{batch, Batch, BatchItem, BatchReport};
+
+pub use self::paged::{paged, Paged, PagedIter, Pagination};
+
+pub use self::render::{curl, render, RenderError};
+
+pub use self::upsert::{upsert, FieldChange, Matched, Upsert, UpsertOutcome, Upsertable, Upserted};
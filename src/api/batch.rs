@@ -0,0 +1,295 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use futures_util::{stream, StreamExt};
+use http::Response;
+use serde_json::Value;
+
+use super::{
+    endpoint::build_request, ApiError, AsyncClient, AsyncQuery, Client, Endpoint, Query, RestClient,
+};
+
+const DEFAULT_CONCURRENCY: usize = 4;
+
+/// A query modifier which sends a collection of endpoints, collecting the result of every one of
+/// them instead of stopping at the first error.
+#[derive(Debug, Clone)]
+pub struct Batch<E> {
+    endpoints: Vec<E>,
+    concurrency: usize,
+}
+
+/// Send all the endpoints, sequentially for [`Query`] and concurrently for [`AsyncQuery`].
+pub fn batch<E, I>(endpoints: I) -> Batch<E>
+where
+    I: IntoIterator<Item = E>,
+{
+    Batch {
+        endpoints: endpoints.into_iter().collect(),
+        concurrency: DEFAULT_CONCURRENCY,
+    }
+}
+
+impl<E> Batch<E> {
+    /// The maximum number of requests in flight when querying asynchronously.
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+}
+
+/// The result of one endpoint of a batch.
+#[derive(Debug)]
+pub struct BatchItem<E>
+where
+    E: Error + Send + Sync + 'static,
+{
+    /// The position of the endpoint in the batch.
+    pub index: usize,
+    /// The response data, `None` when Teamdeck responded without a body.
+    pub result: Result<Option<Value>, ApiError<E>>,
+}
+
+/// The results of all the endpoints of a batch, in the order the endpoints were given.
+#[derive(Debug)]
+pub struct BatchReport<E>
+where
+    E: Error + Send + Sync + 'static,
+{
+    items: Vec<BatchItem<E>>,
+}
+
+impl<E> BatchReport<E>
+where
+    E: Error + Send + Sync + 'static,
+{
+    pub fn items(&self) -> &[BatchItem<E>] {
+        &self.items
+    }
+
+    pub fn into_items(self) -> Vec<BatchItem<E>> {
+        self.items
+    }
+
+    pub fn succeeded(&self) -> impl Iterator<Item = &BatchItem<E>> {
+        self.items.iter().filter(|item| item.result.is_ok())
+    }
+
+    pub fn failed(&self) -> impl Iterator<Item = &BatchItem<E>> {
+        self.items.iter().filter(|item| item.result.is_err())
+    }
+
+    /// Whether every endpoint of the batch succeeded.
+    pub fn is_success(&self) -> bool {
+        self.items.iter().all(|item| item.result.is_ok())
+    }
+}
+
+fn request<E, C>(
+    endpoint: &E,
+    client: &C,
+) -> Result<(http::request::Builder, Vec<u8>), ApiError<C::Error>>
+where
+    E: Endpoint,
+    C: RestClient,
+{
+    let mut url = client.rest_endpoint(&endpoint.url())?;
+    endpoint.parameters().add_to_url(&mut url);
+
+    Ok(build_request(endpoint, url)?)
+}
+
+fn response_value<E>(response: Response<Bytes>) -> Result<Option<Value>, ApiError<E>>
+where
+    E: Error + Send + Sync + 'static,
+{
+    let status = response.status();
+    if status.is_success() && response.body().is_empty() {
+        return Ok(None);
+    }
+
+    let value = if let Ok(val) = serde_json::from_slice(response.body()) {
+        val
+    } else {
+        return Err(ApiError::server_error(status, response.body()));
+    };
+
+    if !status.is_success() {
+        return Err(ApiError::from_teamdeck(value));
+    }
+
+    Ok(Some(value))
+}
+
+async fn send_async<E, C>(index: usize, endpoint: &E, client: &C) -> BatchItem<C::Error>
+where
+    E: Endpoint,
+    C: AsyncClient,
+{
+    let result = match request(endpoint, client) {
+        Ok((request, body)) => client
+            .rest_async(request, body)
+            .await
+            .and_then(response_value),
+        Err(err) => Err(err),
+    };
+
+    BatchItem { index, result }
+}
+
+impl<E, C> Query<BatchReport<C::Error>, C> for Batch<E>
+where
+    E: Endpoint,
+    C: Client,
+{
+    fn query(&self, client: &C) -> Result<BatchReport<C::Error>, ApiError<C::Error>> {
+        let items = self
+            .endpoints
+            .iter()
+            .enumerate()
+            .map(|(index, endpoint)| {
+                let result = request(endpoint, client)
+                    .and_then(|(request, body)| client.rest(request, body))
+                    .and_then(response_value);
+
+                BatchItem { index, result }
+            })
+            .collect();
+
+        Ok(BatchReport { items })
+    }
+}
+
+#[async_trait]
+impl<E, C> AsyncQuery<BatchReport<C::Error>, C> for Batch<E>
+where
+    E: Endpoint + Sync,
+    C: AsyncClient + Sync,
+{
+    async fn query_async(&self, client: &C) -> Result<BatchReport<C::Error>, ApiError<C::Error>> {
+        let requests = self
+            .endpoints
+            .iter()
+            .enumerate()
+            .map(|(index, endpoint)| send_async(index, endpoint, client))
+            .collect::<Vec<_>>();
+
+        let mut items = stream::iter(requests)
+            .buffer_unordered(self.concurrency)
+            .collect::<Vec<_>>()
+            .await;
+
+        items.sort_by_key(|item| item.index);
+
+        Ok(BatchReport { items })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use http::{Method, StatusCode};
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        api::time_entries::{
+            CreateTimeEntry, DeleteTimeEntry, TimeEntryOperation, UpdateTimeEntry,
+        },
+        test::client::{ExpectedRequest, TestClient},
+    };
+
+    fn operations() -> Vec<TimeEntryOperation<'static>> {
+        let date = NaiveDate::from_ymd_opt(2026, 1, 5).unwrap();
+
+        vec![
+            CreateTimeEntry::builder()
+                .resource_id(1)
+                .project_id(2)
+                .minutes(60)
+                .start_date(date)
+                .end_date(date)
+                .build()
+                .unwrap()
+                .into(),
+            UpdateTimeEntry::builder()
+                .id(3)
+                .resource_id(1)
+                .project_id(2)
+                .minutes(90)
+                .start_date(date)
+                .end_date(date)
+                .build()
+                .unwrap()
+                .into(),
+            DeleteTimeEntry::builder().id(4).build().unwrap().into(),
+        ]
+    }
+
+    #[test]
+    fn reports_every_item() {
+        let mut client = TestClient::new();
+        client.expect(
+            ExpectedRequest::builder()
+                .method(Method::POST)
+                .path("/time-entries")
+                .response_status(StatusCode::CREATED)
+                .response_body(json!({ "id": 5 }).to_string())
+                .build()
+                .unwrap(),
+        );
+        client.expect(
+            ExpectedRequest::builder()
+                .method(Method::PUT)
+                .path("/time-entries/3")
+                .response_status(StatusCode::NOT_FOUND)
+                .response_body(json!({ "message": "not found" }).to_string())
+                .build()
+                .unwrap(),
+        );
+        client.expect(
+            ExpectedRequest::builder()
+                .method(Method::DELETE)
+                .path("/time-entries/4")
+                .response_status(StatusCode::NO_CONTENT)
+                .build()
+                .unwrap(),
+        );
+
+        let report = batch(operations()).query(&client).unwrap();
+
+        assert!(!report.is_success());
+        assert_eq!(report.items().len(), 3);
+        assert_eq!(
+            report.items()[0].result.as_ref().unwrap(),
+            &Some(json!({ "id": 5 }))
+        );
+        assert_eq!(report.items()[2].result.as_ref().unwrap(), &None);
+
+        let failed = report.failed().map(|item| item.index).collect::<Vec<_>>();
+        assert_eq!(failed, vec![1]);
+        if let Err(ApiError::Teamdeck { msg }) = &report.items()[1].result {
+            assert_eq!(msg, "not found");
+        } else {
+            panic!("unexpected result: {:?}", report.items()[1].result);
+        }
+    }
+
+    #[test]
+    fn empty_batch_succeeds() {
+        let client = TestClient::new();
+
+        let report = batch(Vec::<TimeEntryOperation>::new())
+            .query(&client)
+            .unwrap();
+
+        assert!(report.is_success());
+        assert_eq!(report.succeeded().count(), 0);
+    }
+
+    #[test]
+    fn concurrency_is_at_least_one() {
+        assert_eq!(batch(operations()).concurrency(0).concurrency, 1);
+    }
+}
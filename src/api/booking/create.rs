@@ -0,0 +1,179 @@
+use chrono::NaiveDate;
+use derive_builder::Builder;
+use http::Method;
+use params::ParamValue;
+use std::borrow::Cow;
+
+use crate::api::{
+    error::BodyError,
+    header,
+    params::{self, JsonParams},
+    upsert::Upsertable,
+    Endpoint,
+};
+
+use super::{Bookings, UpdateBooking};
+
+#[derive(Debug, Builder, Clone)]
+pub struct CreateBooking<'a> {
+    resource_id: u64,
+    project_id: u64,
+    minutes: u64,
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+    #[builder(default)]
+    weekend_booking: Option<bool>,
+    #[builder(default)]
+    holidays_booking: Option<bool>,
+    #[builder(default)]
+    vacations_booking: Option<bool>,
+    #[builder(setter(into), default)]
+    description: Option<Cow<'a, str>>,
+    #[builder(setter(into), default)]
+    external_id: Option<Cow<'a, str>>,
+    #[builder(default)]
+    creator_resource_id: Option<u64>,
+    #[builder(default)]
+    editor_resource_id: Option<u64>,
+    #[builder(default)]
+    disable_notifications: Option<bool>,
+}
+
+impl<'a> CreateBooking<'a> {
+    pub fn builder() -> CreateBookingBuilder<'a> {
+        CreateBookingBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for CreateBooking<'a> {
+    fn url(&self) -> Cow<'static, str> {
+        "bookings".into()
+    }
+
+    fn method(&self) -> http::Method {
+        Method::POST
+    }
+
+    fn body(&self) -> Result<Option<Vec<u8>>, BodyError> {
+        let mut params = JsonParams::default();
+
+        params
+            .push("resource_id", self.resource_id)?
+            .push("project_id", self.project_id)?
+            .push("minutes", self.minutes)?
+            .push("start_date", self.start_date.as_value())?
+            .push("end_date", self.end_date.as_value())?
+            .push_opt("weekend_booking", self.weekend_booking)?
+            .push_opt("holidays_booking", self.holidays_booking)?
+            .push_opt("vacations_booking", self.vacations_booking)?
+            .push_param_value_opt("description", self.description.as_ref())?
+            .push_param_value_opt("external_id", self.external_id.as_ref())?
+            .push_opt("creator_resource_id", self.creator_resource_id)?
+            .push_opt("editor_resource_id", self.editor_resource_id)?;
+
+        Ok(Some(params.to_body()?))
+    }
+
+    fn headers(&self) -> Option<http::HeaderMap> {
+        let mut headers = http::HeaderMap::new();
+        if let Some(disable_notifications) = self.disable_notifications {
+            header::disable_notifications_header(&mut headers, disable_notifications);
+        }
+        Some(headers)
+    }
+}
+
+impl<'a> Upsertable for CreateBooking<'a> {
+    type Lookup = Bookings<'a>;
+    type Update = UpdateBooking<'a>;
+
+    fn lookup(&self) -> Option<Self::Lookup> {
+        let external_id = self.external_id.clone()?;
+
+        let lookup = Bookings::builder()
+            .external_id(external_id)
+            .build()
+            .expect("all required fields are set");
+
+        Some(lookup)
+    }
+
+    fn update(&self, id: u64) -> Self::Update {
+        UpdateBooking::builder()
+            .id(id)
+            .resource_id(self.resource_id)
+            .project_id(self.project_id)
+            .minutes(self.minutes)
+            .start_date(self.start_date)
+            .end_date(self.end_date)
+            .weekend_booking(self.weekend_booking)
+            .holidays_booking(self.holidays_booking)
+            .vacations_booking(self.vacations_booking)
+            .description(self.description.clone())
+            .external_id(self.external_id.clone())
+            .creator_resource_id(self.creator_resource_id)
+            .editor_resource_id(self.editor_resource_id)
+            .disable_notifications(self.disable_notifications)
+            .build()
+            .expect("all required fields are set")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        api::{self, Query},
+        test::client::{ExpectedRequest, TestClient},
+    };
+    use chrono::NaiveDate;
+    use http::Method;
+    use serde_json::json;
+
+    #[test]
+    fn create_booking_request() {
+        let endpoint = api::ignore(
+            CreateBooking::builder()
+                .resource_id(1)
+                .project_id(2)
+                .minutes(480)
+                .start_date(NaiveDate::from_ymd_opt(2026, 1, 5).unwrap())
+                .end_date(NaiveDate::from_ymd_opt(2026, 1, 9).unwrap())
+                .description(Some("description".into()))
+                .external_id(Some("external_id".into()))
+                .build()
+                .unwrap(),
+        );
+
+        let expected = ExpectedRequest::builder()
+            .method(Method::POST)
+            .path("/bookings")
+            .request_body(json!({
+                "resource_id": 1,
+                "project_id": 2,
+                "minutes": 480,
+                "start_date": "2026-01-05",
+                "end_date": "2026-01-09",
+                "description": "description",
+                "external_id": "external_id",
+            }))
+            .build()
+            .unwrap();
+
+        let client = TestClient::expecting(expected);
+
+        endpoint.query(&client).unwrap();
+    }
+
+    #[test]
+    fn missing_minutes() {
+        let endpoint = CreateBooking::builder()
+            .resource_id(1)
+            .project_id(2)
+            .start_date(NaiveDate::from_ymd_opt(2026, 1, 5).unwrap())
+            .end_date(NaiveDate::from_ymd_opt(2026, 1, 9).unwrap())
+            .build();
+
+        assert!(endpoint.is_err());
+    }
+}
@@ -1,12 +1,18 @@
 use derive_builder::Builder;
 use http::Method;
 use std::borrow::Cow;
+use std::str::FromStr;
 
-use crate::api::{paged::Pageable, sort_by::SortBy, Endpoint, ParamValue, QueryParams};
+use crate::api::{
+    paged::Pageable,
+    sort_by::{self, SortBy, UnknownSortField},
+    Endpoint, ParamValue, QueryParams,
+};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 
 pub enum BookingsSortBy {
+    #[default]
     StartDate,
     EndDate,
     Minutes,
@@ -14,12 +20,6 @@ pub enum BookingsSortBy {
     ProjectId,
 }
 
-impl Default for BookingsSortBy {
-    fn default() -> Self {
-        Self::StartDate
-    }
-}
-
 impl BookingsSortBy {
     fn as_str(self) -> &'static str {
         match self {
@@ -38,6 +38,23 @@ impl ParamValue<'static> for BookingsSortBy {
     }
 }
 
+impl FromStr for BookingsSortBy {
+    type Err = UnknownSortField;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        sort_by::parse_field(
+            value,
+            &[
+                Self::StartDate,
+                Self::EndDate,
+                Self::Minutes,
+                Self::ResourceId,
+                Self::ProjectId,
+            ],
+        )
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub enum BookingsExpand {
     Tags,
@@ -99,7 +116,7 @@ impl<'a> Endpoint for Bookings<'a> {
         Method::GET
     }
 
-    fn parameters(&self) -> crate::api::QueryParams {
+    fn parameters(&self) -> crate::api::QueryParams<'_> {
         let mut params = QueryParams::default();
 
         params
@@ -256,7 +273,7 @@ mod tests {
     fn endpoint_date() {
         let endpoint = api::ignore(
             Bookings::builder()
-                .date(NaiveDate::from_ymd(2023, 1, 7))
+                .date(NaiveDate::from_ymd_opt(2023, 1, 7).unwrap())
                 .build()
                 .unwrap(),
         );
@@ -1,7 +1,11 @@
+mod create;
 mod many;
 mod single;
 mod tags;
+mod update;
 
+pub use create::{CreateBooking, CreateBookingBuilder, CreateBookingBuilderError};
 pub use many::{Bookings, BookingsBuilder, BookingsBuilderError, BookingsSortBy};
 pub use single::{Booking, BookingBuilder, BookingBuilderError};
 pub use tags::*;
+pub use update::{UpdateBooking, UpdateBookingBuilder, UpdateBookingBuilderError};
@@ -29,7 +29,7 @@ impl Endpoint for Booking {
         Method::GET
     }
 
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
         params.push_opt("expand", self.expand);
 
@@ -0,0 +1,166 @@
+use chrono::NaiveDate;
+use derive_builder::Builder;
+use http::Method;
+use params::ParamValue;
+use std::borrow::Cow;
+
+use crate::api::{
+    error::BodyError,
+    header::disable_notifications_header,
+    params::{self, JsonParams},
+    Endpoint,
+};
+
+#[derive(Debug, Builder, Clone)]
+pub struct UpdateBooking<'a> {
+    id: u64,
+    resource_id: u64,
+    project_id: u64,
+    minutes: u64,
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+    #[builder(default)]
+    weekend_booking: Option<bool>,
+    #[builder(default)]
+    holidays_booking: Option<bool>,
+    #[builder(default)]
+    vacations_booking: Option<bool>,
+    #[builder(setter(into), default)]
+    description: Option<Cow<'a, str>>,
+    #[builder(setter(into), default)]
+    external_id: Option<Cow<'a, str>>,
+    #[builder(default)]
+    creator_resource_id: Option<u64>,
+    #[builder(default)]
+    editor_resource_id: Option<u64>,
+    #[builder(default)]
+    disable_notifications: Option<bool>,
+}
+
+impl<'a> UpdateBooking<'a> {
+    pub fn builder() -> UpdateBookingBuilder<'a> {
+        UpdateBookingBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for UpdateBooking<'a> {
+    fn url(&self) -> Cow<'static, str> {
+        format!("bookings/{}", self.id).into()
+    }
+
+    fn method(&self) -> http::Method {
+        Method::PUT
+    }
+
+    fn headers(&self) -> Option<http::HeaderMap> {
+        let mut headers = http::HeaderMap::new();
+        if let Some(disable_notifications) = self.disable_notifications {
+            disable_notifications_header(&mut headers, disable_notifications);
+        }
+        Some(headers)
+    }
+
+    fn body(&self) -> Result<Option<Vec<u8>>, BodyError> {
+        let mut params = JsonParams::default();
+
+        params
+            .push("resource_id", self.resource_id)?
+            .push("project_id", self.project_id)?
+            .push("minutes", self.minutes)?
+            .push("start_date", self.start_date.as_value())?
+            .push("end_date", self.end_date.as_value())?
+            .push_opt("weekend_booking", self.weekend_booking)?
+            .push_opt("holidays_booking", self.holidays_booking)?
+            .push_opt("vacations_booking", self.vacations_booking)?
+            .push_param_value_opt("description", self.description.as_ref())?
+            .push_param_value_opt("external_id", self.external_id.as_ref())?
+            .push_opt("creator_resource_id", self.creator_resource_id)?
+            .push_opt("editor_resource_id", self.editor_resource_id)?;
+
+        Ok(Some(params.to_body()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        api::{self, header::DISABLE_NOTIFICATION_HEADER, Query},
+        test::client::{ExpectedRequest, TestClient},
+    };
+    use chrono::NaiveDate;
+    use http::Method;
+    use serde_json::json;
+
+    #[test]
+    fn update_booking_request() {
+        let endpoint = api::ignore(
+            UpdateBooking::builder()
+                .id(7)
+                .resource_id(1)
+                .project_id(2)
+                .minutes(240)
+                .start_date(NaiveDate::from_ymd_opt(2026, 1, 5).unwrap())
+                .end_date(NaiveDate::from_ymd_opt(2026, 1, 9).unwrap())
+                .build()
+                .unwrap(),
+        );
+
+        let expected = ExpectedRequest::builder()
+            .method(Method::PUT)
+            .path("/bookings/7")
+            .request_body(json!({
+                "resource_id": 1,
+                "project_id": 2,
+                "minutes": 240,
+                "start_date": "2026-01-05",
+                "end_date": "2026-01-09",
+            }))
+            .build()
+            .unwrap();
+
+        let client = TestClient::expecting(expected);
+
+        endpoint.query(&client).unwrap();
+    }
+
+    #[test]
+    fn missing_booking_id() {
+        let endpoint = UpdateBooking::builder()
+            .resource_id(1)
+            .project_id(2)
+            .minutes(240)
+            .start_date(NaiveDate::from_ymd_opt(2026, 1, 5).unwrap())
+            .end_date(NaiveDate::from_ymd_opt(2026, 1, 9).unwrap())
+            .build();
+
+        assert!(endpoint.is_err());
+    }
+
+    #[test]
+    fn disable_notifications() {
+        let endpoint = api::ignore(
+            UpdateBooking::builder()
+                .id(7)
+                .resource_id(1)
+                .project_id(2)
+                .minutes(240)
+                .start_date(NaiveDate::from_ymd_opt(2026, 1, 5).unwrap())
+                .end_date(NaiveDate::from_ymd_opt(2026, 1, 9).unwrap())
+                .disable_notifications(Some(true))
+                .build()
+                .unwrap(),
+        );
+
+        let expected = ExpectedRequest::builder()
+            .method(Method::PUT)
+            .path("/bookings/7")
+            .request_headers(vec![(DISABLE_NOTIFICATION_HEADER.into(), "true".into())])
+            .build()
+            .unwrap();
+
+        let client = TestClient::expecting(expected);
+
+        endpoint.query(&client).unwrap();
+    }
+}
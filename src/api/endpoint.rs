@@ -1,6 +1,7 @@
 use std::borrow::Cow;
 
 use async_trait::async_trait;
+use http::request::Builder as RequestBuilder;
 use http::Uri;
 use http::{HeaderMap, Method, Request};
 use serde::de::DeserializeOwned;
@@ -19,7 +20,7 @@ pub trait Endpoint {
     fn headers(&self) -> Option<HeaderMap> {
         None
     }
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         QueryParams::default()
     }
 }
@@ -33,19 +34,7 @@ where
     fn query(&self, client: &C) -> Result<T, ApiError<C::Error>> {
         let mut url = client.rest_endpoint(&self.url())?;
         self.parameters().add_to_url(&mut url);
-        let mut request = Request::builder()
-            .method(self.method())
-            .uri(url_to_http_uri(url))
-            .header("Accept", "application/json")
-            .header("Content-Type", "application/json");
-
-        if let Some(headers) = self.headers() {
-            for (key, value) in headers.iter() {
-                request = request.header(key, value);
-            }
-        }
-
-        let body = self.body()?.unwrap_or_default();
+        let (request, body) = build_request(self, url)?;
 
         let response = client.rest(request, body)?;
         let status = response.status();
@@ -73,19 +62,7 @@ where
     async fn query_async(&self, client: &C) -> Result<T, ApiError<C::Error>> {
         let mut url = client.rest_endpoint(&self.url())?;
         self.parameters().add_to_url(&mut url);
-        let mut request = Request::builder()
-            .method(self.method())
-            .uri(url_to_http_uri(url))
-            .header("Accept", "application/json")
-            .header("Content-Type", "application/json");
-
-        if let Some(headers) = self.headers() {
-            for (key, value) in headers.iter() {
-                request = request.header(key, value);
-            }
-        }
-
-        let body = self.body()?.unwrap_or_default();
+        let (request, body) = build_request(self, url)?;
 
         let response = client.rest_async(request, body).await?;
         let status = response.status();
@@ -103,6 +80,31 @@ where
     }
 }
 
+/// Build the request for an endpoint, sent to `url` which already has the query parameters.
+pub(crate) fn build_request<E>(
+    endpoint: &E,
+    url: Url,
+) -> Result<(RequestBuilder, Vec<u8>), BodyError>
+where
+    E: Endpoint + ?Sized,
+{
+    let mut request = Request::builder()
+        .method(endpoint.method())
+        .uri(url_to_http_uri(url))
+        .header("Accept", "application/json")
+        .header("Content-Type", "application/json");
+
+    if let Some(headers) = endpoint.headers() {
+        for (key, value) in headers.iter() {
+            request = request.header(key, value);
+        }
+    }
+
+    let body = endpoint.body()?.unwrap_or_default();
+
+    Ok((request, body))
+}
+
 pub fn url_to_http_uri(url: Url) -> Uri {
     url.as_str()
         .parse::<Uri>()
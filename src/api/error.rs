@@ -116,6 +116,27 @@ where
         }
     }
 
+    /// Convert the client error with `f`, keeping every other error as it is.
+    pub(crate) fn map_client<F, M>(self, f: M) -> ApiError<F>
+    where
+        F: Error + Send + Sync + 'static,
+        M: FnOnce(E) -> F,
+    {
+        match self {
+            ApiError::Client { source } => ApiError::Client { source: f(source) },
+            ApiError::UrlParse { source } => ApiError::UrlParse { source },
+            ApiError::Body { source } => ApiError::Body { source },
+            ApiError::Json { source } => ApiError::Json { source },
+            ApiError::DataType { source, typename } => ApiError::DataType { source, typename },
+            ApiError::Teamdeck { msg } => ApiError::Teamdeck { msg },
+            ApiError::TeamdeckService { status, data } => {
+                ApiError::TeamdeckService { status, data }
+            }
+            ApiError::TeamdeckObject { obj } => ApiError::TeamdeckObject { obj },
+            ApiError::TeamdeckUnrecognized { obj } => ApiError::TeamdeckUnrecognized { obj },
+        }
+    }
+
     pub(crate) fn server_error(status: http::StatusCode, body: &bytes::Bytes) -> Self {
         Self::TeamdeckService {
             status,
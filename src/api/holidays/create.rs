@@ -0,0 +1,92 @@
+use chrono::NaiveDate;
+use derive_builder::Builder;
+use http::Method;
+use params::ParamValue;
+use std::borrow::Cow;
+
+use crate::api::{
+    error::BodyError,
+    params::{self, JsonParams},
+    Endpoint,
+};
+
+#[derive(Debug, Builder, Clone)]
+pub struct CreateHoliday<'a> {
+    #[builder(setter(into))]
+    name: Cow<'a, str>,
+    date: NaiveDate,
+    /// The organization unit which has the holiday, the whole organization when `None`.
+    #[builder(default)]
+    organization_unit_id: Option<u64>,
+}
+
+impl<'a> CreateHoliday<'a> {
+    pub fn builder() -> CreateHolidayBuilder<'a> {
+        CreateHolidayBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for CreateHoliday<'a> {
+    fn url(&self) -> Cow<'static, str> {
+        "holidays".into()
+    }
+
+    fn method(&self) -> http::Method {
+        Method::POST
+    }
+
+    fn body(&self) -> Result<Option<Vec<u8>>, BodyError> {
+        let mut params = JsonParams::default();
+
+        params
+            .push_param_value("name", &self.name)?
+            .push("date", self.date.as_value())?
+            .push_opt("organization_unit_id", self.organization_unit_id)?;
+
+        Ok(Some(params.to_body()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        api::{self, Query},
+        test::client::{ExpectedRequest, TestClient},
+    };
+    use serde_json::json;
+
+    #[test]
+    fn create_holiday_request() {
+        let endpoint = api::ignore(
+            CreateHoliday::builder()
+                .name("Epiphany")
+                .date(NaiveDate::from_ymd_opt(2026, 1, 6).unwrap())
+                .organization_unit_id(Some(3))
+                .build()
+                .unwrap(),
+        );
+
+        let expected = ExpectedRequest::builder()
+            .method(Method::POST)
+            .path("/holidays")
+            .request_body(json!({
+                "name": "Epiphany",
+                "date": "2026-01-06",
+                "organization_unit_id": 3,
+            }))
+            .build()
+            .unwrap();
+
+        let client = TestClient::expecting(expected);
+
+        endpoint.query(&client).unwrap();
+    }
+
+    #[test]
+    fn missing_date() {
+        let endpoint = CreateHoliday::builder().name("Epiphany").build();
+
+        assert!(endpoint.is_err());
+    }
+}
@@ -4,19 +4,14 @@ use derive_builder::Builder;
 use http::Method;
 use std::borrow::Cow;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum HolidaysSortBy {
     Name,
+    #[default]
     Date,
     OrganizationUnitId,
 }
 
-impl Default for HolidaysSortBy {
-    fn default() -> Self {
-        Self::Date
-    }
-}
-
 impl HolidaysSortBy {
     fn as_str(self) -> &'static str {
         match self {
@@ -63,7 +58,7 @@ impl<'a> Endpoint for Holidays<'a> {
         Method::GET
     }
 
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
 
         params
@@ -1,5 +1,7 @@
+mod create;
 mod many;
 mod single;
 
+pub use create::{CreateHoliday, CreateHolidayBuilder, CreateHolidayBuilderError};
 pub use many::{Holidays, HolidaysBuilder, HolidaysBuilderError, HolidaysSortBy};
 pub use single::{Holiday, HolidayBuilder, HolidayBuilderError};
@@ -1,5 +1,4 @@
 use async_trait::async_trait;
-use http::Request;
 
 use crate::api::{ApiError, AsyncClient, AsyncQuery, Client, Endpoint, Query};
 
@@ -25,19 +24,7 @@ where
         let mut url = client.rest_endpoint(&self.endpoint.url())?;
         self.endpoint.parameters().add_to_url(&mut url);
 
-        let mut req = Request::builder()
-            .method(self.endpoint.method())
-            .uri(endpoint::url_to_http_uri(url))
-            .header("Accept", "application/json")
-            .header("Content-Type", "application/json");
-
-        if let Some(headers) = self.endpoint.headers() {
-            for (key, value) in headers.iter() {
-                req = req.header(key, value);
-            }
-        }
-
-        let data = self.endpoint.body()?.unwrap_or_default();
+        let (req, data) = endpoint::build_request(&self.endpoint, url)?;
         let rsp = client.rest(req, data)?;
         if !rsp.status().is_success() {
             let v = if let Ok(v) = serde_json::from_slice(rsp.body()) {
@@ -62,10 +49,7 @@ where
         let mut url = client.rest_endpoint(&self.endpoint.url())?;
         self.endpoint.parameters().add_to_url(&mut url);
 
-        let req = Request::builder()
-            .method(self.endpoint.method())
-            .uri(endpoint::url_to_http_uri(url));
-        let data = self.endpoint.body()?.unwrap_or_default();
+        let (req, data) = endpoint::build_request(&self.endpoint, url)?;
         let rsp = client.rest_async(req, data).await?;
         if !rsp.status().is_success() {
             let v = if let Ok(v) = serde_json::from_slice(rsp.body()) {
@@ -1,22 +1,19 @@
+use std::error::Error;
+
 use async_trait::async_trait;
-use http::Request;
+use bytes::Bytes;
+use http::Response;
 use serde::de::DeserializeOwned;
+use url::Url;
 
-use super::{
-    endpoint::url_to_http_uri, ApiError, AsyncClient, AsyncQuery, Client, Endpoint, Query,
-};
+use super::{endpoint::build_request, ApiError, AsyncClient, AsyncQuery, Client, Endpoint, Query};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum Pagination {
+    #[default]
     All,
 }
 
-impl Default for Pagination {
-    fn default() -> Self {
-        Self::All
-    }
-}
-
 const MAX_PAGE_SIZE: usize = 10;
 
 impl Pagination {
@@ -56,79 +53,131 @@ pub fn paged<E>(endpoint: E, pagination: Pagination) -> Paged<E> {
 
 pub trait Pageable {}
 
-impl<E, T, C> Query<Vec<T>, C> for Paged<E>
+impl<E> Paged<E>
 where
-    E: Endpoint,
-    E: Pageable,
-    T: DeserializeOwned,
-    C: Client,
+    E: Endpoint + Pageable,
 {
-    fn query(&self, client: &C) -> Result<Vec<T>, super::ApiError<<C>::Error>> {
-        let mut page_num = 0;
-        let mut results = vec![];
+    /// Iterate over the results, requesting each page only once the previous one has been
+    /// consumed, so large collections are never held in memory at once.
+    pub fn iter<'a, T, C>(&'a self, client: &'a C) -> PagedIter<'a, E, T, C>
+    where
+        T: DeserializeOwned,
+        C: Client,
+    {
+        PagedIter {
+            paged: self,
+            client,
+            url: None,
+            page_num: 1,
+            page: Vec::new().into_iter(),
+            done: false,
+        }
+    }
+}
 
-        let url = {
-            let mut url = client.rest_endpoint(&self.endpoint.url())?;
-            self.endpoint.parameters().add_to_url(&mut url);
-            url
-        };
+fn page_url(url: &Url, page_num: u64) -> Url {
+    let mut page_url = url.clone();
+    page_url
+        .query_pairs_mut()
+        .append_pair("page", &page_num.to_string());
 
-        loop {
-            let page_url = {
-                let page_str = format!("{}", page_num);
-                let mut page_url = url.clone();
+    page_url
+}
 
-                {
-                    let mut pairs = page_url.query_pairs_mut();
-                    pairs.append_pair("page", &page_str);
-                }
+fn page_results<T, E>(response: Response<Bytes>) -> Result<Vec<T>, ApiError<E>>
+where
+    T: DeserializeOwned,
+    E: Error + Send + Sync + 'static,
+{
+    let status = response.status();
+    let value = if let Ok(val) = serde_json::from_slice(response.body()) {
+        val
+    } else {
+        return Err(ApiError::server_error(status, response.body()));
+    };
 
-                page_url
-            };
+    if !status.is_success() {
+        return Err(ApiError::from_teamdeck(value));
+    }
 
-            let mut request = Request::builder()
-                .method(self.endpoint.method())
-                .uri(url_to_http_uri(page_url))
-                .header("Accept", "application/json")
-                .header("Content-Type", "application/json");
+    serde_json::from_value::<Vec<T>>(value).map_err(ApiError::data_type::<Vec<T>>)
+}
 
-            if let Some(headers) = self.endpoint.headers() {
-                for (key, value) in headers.iter() {
-                    request = request.header(key, value);
-                }
-            }
+/// An iterator over the results of a paged endpoint, created by [`Paged::iter`].
+///
+/// The iterator ends after the first error.
+pub struct PagedIter<'a, E, T, C> {
+    paged: &'a Paged<E>,
+    client: &'a C,
+    url: Option<Url>,
+    page_num: u64,
+    page: std::vec::IntoIter<T>,
+    done: bool,
+}
 
-            let body = self.endpoint.body()?.unwrap_or_default();
+impl<'a, E, T, C> PagedIter<'a, E, T, C>
+where
+    E: Endpoint,
+    T: DeserializeOwned,
+    C: Client,
+{
+    fn next_page(&mut self) -> Result<Vec<T>, ApiError<C::Error>> {
+        let url = match &self.url {
+            Some(url) => url,
+            None => {
+                let mut url = self.client.rest_endpoint(&self.paged.endpoint.url())?;
+                self.paged.endpoint.parameters().add_to_url(&mut url);
+                self.url.insert(url)
+            }
+        };
 
-            let response = client.rest(request, body)?;
-            let status = response.status();
-            let value = if let Ok(val) = serde_json::from_slice(response.body()) {
-                val
-            } else {
-                return Err(ApiError::server_error(status, response.body()));
-            };
+        let (request, body) = build_request(&self.paged.endpoint, page_url(url, self.page_num))?;
+        let page = page_results(self.client.rest(request, body)?)?;
 
-            if !status.is_success() {
-                return Err(ApiError::from_teamdeck(value));
-            }
+        self.page_num += 1;
+        self.done = self.paged.pagination.is_last_page(page.len());
 
-            let page =
-                serde_json::from_value::<Vec<T>>(value).map_err(ApiError::data_type::<Vec<T>>)?;
-            let page_len = page.len();
+        Ok(page)
+    }
+}
 
-            let is_last_page = {
-                results.extend(page);
-                self.pagination.is_last_page(page_len)
-            };
+impl<'a, E, T, C> Iterator for PagedIter<'a, E, T, C>
+where
+    E: Endpoint,
+    T: DeserializeOwned,
+    C: Client,
+{
+    type Item = Result<T, ApiError<C::Error>>;
 
-            if is_last_page {
-                break;
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(result) = self.page.next() {
+                return Some(Ok(result));
+            }
+            if self.done {
+                return None;
             }
 
-            page_num += 1;
+            match self.next_page() {
+                Ok(page) => self.page = page.into_iter(),
+                Err(err) => {
+                    self.done = true;
+                    return Some(Err(err));
+                }
+            }
         }
+    }
+}
 
-        Ok(results)
+impl<E, T, C> Query<Vec<T>, C> for Paged<E>
+where
+    E: Endpoint,
+    E: Pageable,
+    T: DeserializeOwned,
+    C: Client,
+{
+    fn query(&self, client: &C) -> Result<Vec<T>, super::ApiError<<C>::Error>> {
+        self.iter(client).collect()
     }
 }
 
@@ -151,46 +200,10 @@ where
         };
 
         loop {
-            let page_url = {
-                let page_str = format!("{}", page_num);
-                let mut page_url = url.clone();
-
-                {
-                    let mut pairs = page_url.query_pairs_mut();
-                    pairs.append_pair("page", &page_str);
-                }
-
-                page_url
-            };
-
-            let mut request = Request::builder()
-                .method(self.endpoint.method())
-                .uri(url_to_http_uri(page_url))
-                .header("Accept", "application/json")
-                .header("Content-Type", "application/json");
-
-            if let Some(headers) = self.endpoint.headers() {
-                for (key, value) in headers.iter() {
-                    request = request.header(key, value);
-                }
-            }
-
-            let body = self.endpoint.body()?.unwrap_or_default();
+            let (request, body) = build_request(&self.endpoint, page_url(&url, page_num))?;
 
             let response = client.rest_async(request, body).await?;
-            let status = response.status();
-            let value = if let Ok(val) = serde_json::from_slice(response.body()) {
-                val
-            } else {
-                return Err(ApiError::server_error(status, response.body()));
-            };
-
-            if !status.is_success() {
-                return Err(ApiError::from_teamdeck(value));
-            }
-
-            let page =
-                serde_json::from_value::<Vec<T>>(value).map_err(ApiError::data_type::<Vec<T>>)?;
+            let page = page_results::<T, _>(response)?;
             let page_len = page.len();
 
             let is_last_page = {
@@ -254,12 +267,12 @@ mod tests {
 
     #[test]
     fn test_teamdeck_non_json_response() {
-        let endpoint = api::paged(Dummy::default(), Pagination::All);
+        let endpoint = api::paged(Dummy, Pagination::All);
 
         let expected = ExpectedRequest::builder()
             .method(Method::GET)
             .path("/paged_dummy")
-            .query(vec![("page".into(), "0".into())])
+            .query(vec![("page".into(), "1".into())])
             .response_body("not json")
             .build()
             .unwrap();
@@ -278,12 +291,12 @@ mod tests {
 
     #[test]
     fn test_teamdeck_error_bad_json() {
-        let endpoint = Dummy::default();
+        let endpoint = Dummy;
 
         let expected = ExpectedRequest::builder()
             .method(Method::GET)
             .path("/paged_dummy")
-            .query(vec![("page".into(), "0".into())])
+            .query(vec![("page".into(), "1".into())])
             .response_status(StatusCode::NOT_FOUND)
             .response_body("")
             .build()
@@ -303,12 +316,12 @@ mod tests {
 
     #[test]
     fn test_teamdeck_error_detection() {
-        let endpoint = Dummy::default();
+        let endpoint = Dummy;
 
         let expected = ExpectedRequest::builder()
             .method(Method::GET)
             .path("/paged_dummy")
-            .query(vec![("page".into(), "0".into())])
+            .query(vec![("page".into(), "1".into())])
             .response_status(StatusCode::NOT_FOUND)
             .response_body(
                 json!({
@@ -333,12 +346,12 @@ mod tests {
 
     #[test]
     fn test_teamdeck_error_detection_legacy() {
-        let endpoint = Dummy::default();
+        let endpoint = Dummy;
 
         let expected = ExpectedRequest::builder()
             .method(Method::GET)
             .path("/paged_dummy")
-            .query(vec![("page".into(), "0".into())])
+            .query(vec![("page".into(), "1".into())])
             .response_status(StatusCode::NOT_FOUND)
             .response_body(
                 json!({
@@ -367,12 +380,12 @@ mod tests {
             "bogus": "dummy error message",
         });
 
-        let endpoint = Dummy::default();
+        let endpoint = Dummy;
 
         let expected = ExpectedRequest::builder()
             .method(Method::GET)
             .path("/paged_dummy")
-            .query(vec![("page".into(), "0".into())])
+            .query(vec![("page".into(), "1".into())])
             .response_status(StatusCode::NOT_FOUND)
             .response_body(err_obj.to_string())
             .build()
@@ -404,7 +417,7 @@ mod tests {
             ExpectedRequest::builder()
                 .method(Method::GET)
                 .path("/paged_dummy")
-                .query(vec![("page".into(), i.to_string())])
+                .query(vec![("page".into(), (i + 1).to_string())])
                 .response_body(json!(page).to_string())
                 .build()
                 .unwrap()
@@ -416,7 +429,7 @@ mod tests {
             client.expect(req);
         }
 
-        let query = Dummy::default();
+        let query = Dummy;
 
         let res: Vec<DummyResult> = api::paged(query, Pagination::All).query(&client).unwrap();
 
@@ -427,6 +440,33 @@ mod tests {
         }
     }
 
+    #[test]
+    fn iter_requests_pages_as_they_are_consumed() {
+        let page = (0..10)
+            .map(|value| DummyResult { value })
+            .collect::<Vec<_>>();
+
+        let expected = ExpectedRequest::builder()
+            .method(Method::GET)
+            .path("/paged_dummy")
+            .query(vec![("page".into(), "1".into())])
+            .response_body(json!(page).to_string())
+            .build()
+            .unwrap();
+
+        let client = TestClient::expecting(expected);
+
+        let endpoint = api::paged(Dummy, Pagination::All);
+        let mut iter = endpoint.iter::<DummyResult, _>(&client);
+
+        let first_page = iter.by_ref().take(10).collect::<Result<Vec<_>, _>>();
+        assert_eq!(first_page.unwrap().len(), 10);
+
+        // The second page is only requested now, and is missing.
+        assert!(matches!(iter.next(), Some(Err(_))));
+        assert!(iter.next().is_none());
+    }
+
     #[tokio::test]
     #[ignore = "Throws error 'Cannot drop a runtime in a context where blocking is not allowed. This happens when a runtime is dropped from within an asynchronous context.'"]
     async fn test_pagination_all_async() {
@@ -442,7 +482,7 @@ mod tests {
             ExpectedRequest::builder()
                 .method(Method::GET)
                 .path("/paged_dummy")
-                .query(vec![("page".into(), i.to_string())])
+                .query(vec![("page".into(), (i + 1).to_string())])
                 .response_body(json!(page).to_string())
                 .build()
                 .unwrap()
@@ -454,7 +494,7 @@ mod tests {
             client.expect(req);
         }
 
-        let query = Dummy::default();
+        let query = Dummy;
 
         let res: Vec<DummyResult> = api::paged(query, Pagination::All)
             .query_async(&client)
@@ -470,7 +510,7 @@ mod tests {
 
     #[test]
     fn passes_custom_headers() {
-        let query = Dummy::default();
+        let query = Dummy;
         assert!(query.headers().is_some());
 
         let endpoint = api::paged(query, Pagination::All);
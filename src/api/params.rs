@@ -137,6 +137,24 @@ impl<'a> QueryParams<'a> {
     }
 }
 
+/// The change to make to a field in a partial update.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FieldUpdate<T> {
+    /// Leave the field as it is, the field is not sent.
+    #[default]
+    Unchanged,
+    /// Clear the field, the field is sent as `null`.
+    Clear,
+    /// Set the field to a new value.
+    Set(T),
+}
+
+impl<T> From<T> for FieldUpdate<T> {
+    fn from(value: T) -> Self {
+        Self::Set(value)
+    }
+}
+
 #[derive(Debug, Default, Clone)]
 pub struct JsonParams<'a> {
     params: HashMap<Cow<'a, str>, Value>,
@@ -201,7 +219,59 @@ impl<'a> JsonParams<'a> {
         Ok(self)
     }
 
+    /// Push a field of a partial update, skipping it when it is unchanged.
+    pub fn push_field_update<'b, K, V>(
+        &mut self,
+        key: K,
+        value: &FieldUpdate<V>,
+    ) -> Result<&mut Self, serde_json::Error>
+    where
+        K: Into<Cow<'a, str>>,
+        V: Serialize,
+        'b: 'a,
+    {
+        match value {
+            FieldUpdate::Unchanged => {}
+            FieldUpdate::Clear => {
+                self.params.insert(key.into(), Value::Null);
+            }
+            FieldUpdate::Set(value) => {
+                self.params.insert(key.into(), serde_json::to_value(value)?);
+            }
+        }
+        Ok(self)
+    }
+
     pub fn to_body(&self) -> Result<Vec<u8>, serde_json::Error> {
         serde_json::to_vec(&self.params)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn field_update_is_unchanged_by_default() {
+        assert_eq!(FieldUpdate::<u64>::default(), FieldUpdate::Unchanged);
+    }
+
+    #[test]
+    fn push_field_update() {
+        let mut params = JsonParams::default();
+
+        params
+            .push_field_update("unchanged", &FieldUpdate::<u64>::Unchanged)
+            .unwrap()
+            .push_field_update("cleared", &FieldUpdate::<u64>::Clear)
+            .unwrap()
+            .push_field_update("set", &FieldUpdate::Set(1))
+            .unwrap();
+
+        let body: Value = serde_json::from_slice(&params.to_body().unwrap()).unwrap();
+
+        assert_eq!(body, json!({ "cleared": null, "set": 1 }));
+    }
+}
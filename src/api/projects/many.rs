@@ -1,21 +1,22 @@
-use crate::api::{paged::Pageable, params::ParamValue, sort_by::SortBy, Endpoint, QueryParams};
+use crate::api::{
+    paged::Pageable,
+    params::ParamValue,
+    sort_by::{self, SortBy, UnknownSortField},
+    Endpoint, QueryParams,
+};
 use derive_builder::Builder;
 use http::Method;
 use std::borrow::Cow;
+use std::str::FromStr;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum ProjectsSortBy {
+    #[default]
     Name,
     Color,
     Archived,
 }
 
-impl Default for ProjectsSortBy {
-    fn default() -> Self {
-        Self::Name
-    }
-}
-
 impl ProjectsSortBy {
     fn as_str(self) -> &'static str {
         match self {
@@ -32,6 +33,14 @@ impl ParamValue<'static> for ProjectsSortBy {
     }
 }
 
+impl FromStr for ProjectsSortBy {
+    type Err = UnknownSortField;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        sort_by::parse_field(value, &[Self::Name, Self::Color, Self::Archived])
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub enum ProjectsExpand {
     Tags,
@@ -82,7 +91,7 @@ impl<'a> Endpoint for Projects<'a> {
         "projects".into()
     }
 
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
         params.push_opt("sort", self.sort);
         params.push_opt("page", self.page);
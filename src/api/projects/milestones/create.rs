@@ -0,0 +1,125 @@
+use chrono::NaiveDate;
+use derive_builder::Builder;
+use http::Method;
+use params::ParamValue;
+use std::borrow::Cow;
+
+use crate::api::{
+    error::BodyError,
+    params::{self, JsonParams},
+    Endpoint,
+};
+
+#[derive(Debug, Builder, Clone)]
+pub struct CreateMilestone<'a> {
+    project_id: u64,
+    #[builder(setter(into))]
+    name: Cow<'a, str>,
+    #[builder(setter(into), default)]
+    description: Option<Cow<'a, str>>,
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+}
+
+impl<'a> CreateMilestone<'a> {
+    pub fn builder() -> CreateMilestoneBuilder<'a> {
+        CreateMilestoneBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for CreateMilestone<'a> {
+    fn url(&self) -> Cow<'static, str> {
+        format!("projects/{}/milestones", self.project_id).into()
+    }
+
+    fn method(&self) -> http::Method {
+        Method::POST
+    }
+
+    fn body(&self) -> Result<Option<Vec<u8>>, BodyError> {
+        let mut params = JsonParams::default();
+
+        params
+            .push_param_value("name", &self.name)?
+            .push_param_value_opt("description", self.description.as_ref())?
+            .push("start_date", self.start_date.as_value())?
+            .push("end_date", self.end_date.as_value())?;
+
+        Ok(Some(params.to_body()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        api::{self, Query},
+        test::client::{ExpectedRequest, TestClient},
+    };
+    use chrono::NaiveDate;
+    use http::Method;
+    use serde_json::json;
+
+    #[test]
+    fn create_milestone_request() {
+        let endpoint = api::ignore(
+            CreateMilestone::builder()
+                .project_id(1)
+                .name("Release 1.0")
+                .description(Some("First public release".into()))
+                .start_date(NaiveDate::from_ymd_opt(2026, 1, 1).unwrap())
+                .end_date(NaiveDate::from_ymd_opt(2026, 1, 2).unwrap())
+                .build()
+                .unwrap(),
+        );
+
+        let expected = ExpectedRequest::builder()
+            .method(Method::POST)
+            .path("/projects/1/milestones")
+            .request_body(json!({
+                "name": "Release 1.0",
+                "description": "First public release",
+                "start_date": "2026-01-01",
+                "end_date": "2026-01-02",
+            }))
+            .build()
+            .unwrap();
+
+        let client = TestClient::expecting(expected);
+
+        endpoint.query(&client).unwrap();
+    }
+
+    #[test]
+    fn missing_project_id() {
+        let endpoint = CreateMilestone::builder()
+            .name("Release 1.0")
+            .start_date(NaiveDate::from_ymd_opt(2026, 1, 1).unwrap())
+            .end_date(NaiveDate::from_ymd_opt(2026, 1, 2).unwrap())
+            .build();
+
+        assert!(endpoint.is_err());
+    }
+
+    #[test]
+    fn missing_name() {
+        let endpoint = CreateMilestone::builder()
+            .project_id(1)
+            .start_date(NaiveDate::from_ymd_opt(2026, 1, 1).unwrap())
+            .end_date(NaiveDate::from_ymd_opt(2026, 1, 2).unwrap())
+            .build();
+
+        assert!(endpoint.is_err());
+    }
+
+    #[test]
+    fn missing_start_date() {
+        let endpoint = CreateMilestone::builder()
+            .project_id(1)
+            .name("Release 1.0")
+            .end_date(NaiveDate::from_ymd_opt(2026, 1, 2).unwrap())
+            .build();
+
+        assert!(endpoint.is_err());
+    }
+}
@@ -0,0 +1,64 @@
+use derive_builder::Builder;
+use http::Method;
+use std::borrow::Cow;
+
+use crate::api::Endpoint;
+
+#[derive(Debug, Builder)]
+pub struct DeleteMilestone {
+    project_id: u64,
+    id: u64,
+}
+
+impl DeleteMilestone {
+    pub fn builder() -> DeleteMilestoneBuilder {
+        DeleteMilestoneBuilder::default()
+    }
+}
+
+impl Endpoint for DeleteMilestone {
+    fn url(&self) -> Cow<'static, str> {
+        format!("projects/{}/milestones/{}", self.project_id, self.id).into()
+    }
+
+    fn method(&self) -> http::Method {
+        Method::DELETE
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        api::{self, Query},
+        test::client::{ExpectedRequest, TestClient},
+    };
+
+    #[test]
+    fn delete_milestone() {
+        let endpoint = api::ignore(
+            DeleteMilestone::builder()
+                .project_id(1)
+                .id(2)
+                .build()
+                .unwrap(),
+        );
+
+        let expected = ExpectedRequest::builder()
+            .method(Method::DELETE)
+            .path("/projects/1/milestones/2")
+            .build()
+            .unwrap();
+
+        let client = TestClient::expecting(expected);
+
+        endpoint.query(&client).unwrap();
+    }
+
+    #[test]
+    fn missing_id() {
+        let endpoint = DeleteMilestone::builder().project_id(1).build();
+
+        assert!(endpoint.is_err());
+    }
+}
@@ -0,0 +1,173 @@
+use crate::api::{paged::Pageable, params::ParamValue, sort_by::SortBy, Endpoint, QueryParams};
+use chrono::NaiveDate;
+use derive_builder::Builder;
+use http::Method;
+use std::borrow::Cow;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MilestonesSortBy {
+    Id,
+    Name,
+    #[default]
+    StartDate,
+    EndDate,
+}
+
+impl MilestonesSortBy {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Id => "id",
+            Self::Name => "name",
+            Self::StartDate => "start_date",
+            Self::EndDate => "end_date",
+        }
+    }
+}
+
+impl ParamValue<'static> for MilestonesSortBy {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct Milestones<'a> {
+    project_id: u64,
+    #[builder(default)]
+    sort: Option<SortBy<MilestonesSortBy>>,
+    #[builder(default)]
+    page: Option<u64>,
+    #[builder(setter(into), default)]
+    name: Option<Cow<'a, str>>,
+    #[builder(default)]
+    start_date_from: Option<NaiveDate>,
+    #[builder(default)]
+    start_date_to: Option<NaiveDate>,
+    #[builder(default)]
+    end_date_from: Option<NaiveDate>,
+    #[builder(default)]
+    end_date_to: Option<NaiveDate>,
+    #[builder(default)]
+    date: Option<NaiveDate>,
+}
+
+impl<'a> Milestones<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> MilestonesBuilder<'a> {
+        MilestonesBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for Milestones<'a> {
+    fn url(&self) -> Cow<'static, str> {
+        format!("projects/{}/milestones", self.project_id).into()
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+
+        params
+            .push_opt("sort", self.sort)
+            .push_opt("page", self.page)
+            .push_opt("name", self.name.as_ref())
+            .push_opt("start_date_from", self.start_date_from)
+            .push_opt("start_date_to", self.start_date_to)
+            .push_opt("end_date_from", self.end_date_from)
+            .push_opt("end_date_to", self.end_date_to)
+            .push_opt("date", self.date);
+
+        params
+    }
+}
+
+impl<'a> Pageable for Milestones<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use http::Method;
+
+    use super::*;
+    use crate::{
+        api::{self, sort_by::SortBy, Query},
+        test::client::{ExpectedRequest, TestClient},
+    };
+
+    #[test]
+    fn project_id_is_required() {
+        assert!(Milestones::builder().build().is_err());
+    }
+
+    #[test]
+    fn sort_by_default() {
+        assert_eq!(MilestonesSortBy::default(), MilestonesSortBy::StartDate);
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = api::ignore(Milestones::builder().project_id(1).build().unwrap());
+
+        let expected = ExpectedRequest::builder()
+            .method(Method::GET)
+            .path("/projects/1/milestones")
+            .build()
+            .unwrap();
+
+        let client = TestClient::expecting(expected);
+
+        endpoint.query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_sort_descending() {
+        let endpoint = api::ignore(
+            Milestones::builder()
+                .project_id(1)
+                .sort(SortBy::Desc(MilestonesSortBy::EndDate))
+                .build()
+                .unwrap(),
+        );
+
+        let expected = ExpectedRequest::builder()
+            .method(Method::GET)
+            .path("/projects/1/milestones")
+            .query(vec![("sort".into(), "-end_date".into())])
+            .build()
+            .unwrap();
+
+        let client = TestClient::expecting(expected);
+
+        endpoint.query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_date_range() {
+        let endpoint = api::ignore(
+            Milestones::builder()
+                .project_id(1)
+                .start_date_from(NaiveDate::from_ymd_opt(2026, 1, 1).unwrap())
+                .end_date_to(NaiveDate::from_ymd_opt(2026, 3, 31).unwrap())
+                .build()
+                .unwrap(),
+        );
+
+        let expected = ExpectedRequest::builder()
+            .method(Method::GET)
+            .path("/projects/1/milestones")
+            .query(vec![
+                ("start_date_from".into(), "2026-01-01".into()),
+                ("end_date_to".into(), "2026-03-31".into()),
+            ])
+            .build()
+            .unwrap();
+
+        let client = TestClient::expecting(expected);
+
+        endpoint.query(&client).unwrap();
+    }
+}
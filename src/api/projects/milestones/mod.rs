@@ -0,0 +1,11 @@
+mod create;
+mod delete;
+mod many;
+mod single;
+mod update;
+
+pub use create::{CreateMilestone, CreateMilestoneBuilder, CreateMilestoneBuilderError};
+pub use delete::{DeleteMilestone, DeleteMilestoneBuilder, DeleteMilestoneBuilderError};
+pub use many::{Milestones, MilestonesBuilder, MilestonesBuilderError, MilestonesSortBy};
+pub use single::{Milestone, MilestoneBuilder, MilestoneBuilderError};
+pub use update::{UpdateMilestone, UpdateMilestoneBuilder, UpdateMilestoneBuilderError};
@@ -0,0 +1,55 @@
+use derive_builder::Builder;
+use http::Method;
+use std::borrow::Cow;
+
+use crate::api::Endpoint;
+
+#[derive(Debug, Builder)]
+pub struct Milestone {
+    project_id: u64,
+    id: u64,
+}
+
+impl Milestone {
+    pub fn builder() -> MilestoneBuilder {
+        MilestoneBuilder::default()
+    }
+}
+
+impl Endpoint for Milestone {
+    fn url(&self) -> Cow<'static, str> {
+        format!("projects/{}/milestones/{}", self.project_id, self.id).into()
+    }
+
+    fn method(&self) -> http::Method {
+        Method::GET
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Milestone;
+    use crate::{
+        api::{self, Query},
+        test::client::{ExpectedRequest, TestClient},
+    };
+
+    #[test]
+    fn milestone_id_is_required() {
+        assert!(Milestone::builder().project_id(1).build().is_err());
+    }
+
+    #[test]
+    fn milestone() {
+        let endpoint = api::ignore(Milestone::builder().project_id(1).id(2).build().unwrap());
+
+        let expected = ExpectedRequest::builder()
+            .path("/projects/1/milestones/2")
+            .build()
+            .unwrap();
+
+        let client = TestClient::expecting(expected);
+
+        endpoint.query(&client).unwrap();
+    }
+}
@@ -0,0 +1,104 @@
+use chrono::NaiveDate;
+use derive_builder::Builder;
+use http::Method;
+use params::ParamValue;
+use std::borrow::Cow;
+
+use crate::api::{
+    error::BodyError,
+    params::{self, JsonParams},
+    Endpoint,
+};
+
+#[derive(Debug, Builder, Clone)]
+pub struct UpdateMilestone<'a> {
+    project_id: u64,
+    id: u64,
+    #[builder(setter(into))]
+    name: Cow<'a, str>,
+    #[builder(setter(into), default)]
+    description: Option<Cow<'a, str>>,
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+}
+
+impl<'a> UpdateMilestone<'a> {
+    pub fn builder() -> UpdateMilestoneBuilder<'a> {
+        UpdateMilestoneBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for UpdateMilestone<'a> {
+    fn url(&self) -> Cow<'static, str> {
+        format!("projects/{}/milestones/{}", self.project_id, self.id).into()
+    }
+
+    fn method(&self) -> http::Method {
+        Method::PUT
+    }
+
+    fn body(&self) -> Result<Option<Vec<u8>>, BodyError> {
+        let mut params = JsonParams::default();
+
+        params
+            .push_param_value("name", &self.name)?
+            .push_param_value_opt("description", self.description.as_ref())?
+            .push("start_date", self.start_date.as_value())?
+            .push("end_date", self.end_date.as_value())?;
+
+        Ok(Some(params.to_body()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        api::{self, Query},
+        test::client::{ExpectedRequest, TestClient},
+    };
+    use chrono::NaiveDate;
+    use http::Method;
+    use serde_json::json;
+
+    #[test]
+    fn update_milestone_request() {
+        let endpoint = api::ignore(
+            UpdateMilestone::builder()
+                .project_id(1)
+                .id(2)
+                .name("Release 1.1")
+                .start_date(NaiveDate::from_ymd_opt(2026, 2, 1).unwrap())
+                .end_date(NaiveDate::from_ymd_opt(2026, 2, 2).unwrap())
+                .build()
+                .unwrap(),
+        );
+
+        let expected = ExpectedRequest::builder()
+            .method(Method::PUT)
+            .path("/projects/1/milestones/2")
+            .request_body(json!({
+                "name": "Release 1.1",
+                "start_date": "2026-02-01",
+                "end_date": "2026-02-02",
+            }))
+            .build()
+            .unwrap();
+
+        let client = TestClient::expecting(expected);
+
+        endpoint.query(&client).unwrap();
+    }
+
+    #[test]
+    fn missing_milestone_id() {
+        let endpoint = UpdateMilestone::builder()
+            .project_id(1)
+            .name("Release 1.1")
+            .start_date(NaiveDate::from_ymd_opt(2026, 2, 1).unwrap())
+            .end_date(NaiveDate::from_ymd_opt(2026, 2, 2).unwrap())
+            .build();
+
+        assert!(endpoint.is_err());
+    }
+}
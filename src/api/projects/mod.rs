@@ -1,5 +1,7 @@
 mod many;
+mod milestones;
 mod single;
 
 pub use many::{Projects, ProjectsBuilder, ProjectsBuilderError, ProjectsSortBy};
+pub use milestones::*;
 pub use single::{Project, ProjectBuilder, ProjectBuilderError};
@@ -0,0 +1,160 @@
+use http::Request;
+use thiserror::Error;
+use url::Url;
+
+use super::{endpoint::build_request, error::BodyError, Endpoint};
+
+/// The header carrying the Teamdeck API key.
+const API_KEY_HEADER: &str = "x-api-key";
+/// The shell variable the API key is read from in rendered `curl` commands.
+const API_KEY_VARIABLE: &str = "$TEAMDECK_API_KEY";
+
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum RenderError {
+    #[error("failed to parse url: {}", source)]
+    UrlParse {
+        #[from]
+        source: url::ParseError,
+    },
+    #[error("failed to create the body: {}", source)]
+    Body {
+        #[from]
+        source: BodyError,
+    },
+    #[error("`http` error: {}", source)]
+    Http {
+        #[from]
+        source: http::Error,
+    },
+}
+
+/// Build the request an endpoint would send to the Teamdeck API at `base_url`, without sending
+/// it.
+///
+/// The request is the same as the one given to a [`Client`](super::Client), so it does not have
+/// the API key header, which the client adds.
+pub fn render<E>(endpoint: &E, base_url: &Url) -> Result<Request<Vec<u8>>, RenderError>
+where
+    E: Endpoint + ?Sized,
+{
+    let mut url = base_url.join(&endpoint.url())?;
+    endpoint.parameters().add_to_url(&mut url);
+    if url.query() == Some("") {
+        url.set_query(None);
+    }
+
+    let (request, body) = build_request(endpoint, url)?;
+
+    Ok(request.body(body)?)
+}
+
+/// Quote a value for a POSIX shell.
+fn quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', r"'\''"))
+}
+
+/// Format a request as a `curl` command which can be pasted into a shell.
+///
+/// The API key is never written out: the command reads it from the `TEAMDECK_API_KEY`
+/// environment variable instead.
+pub fn curl<B>(request: &Request<B>) -> String
+where
+    B: AsRef<[u8]>,
+{
+    let mut parts = vec![format!(
+        "curl -X {} {}",
+        request.method(),
+        quote(&request.uri().to_string())
+    )];
+
+    for (name, value) in request.headers() {
+        if name == API_KEY_HEADER {
+            continue;
+        }
+        let header = format!("{}: {}", name, String::from_utf8_lossy(value.as_bytes()));
+        parts.push(format!("-H {}", quote(&header)));
+    }
+    parts.push(format!("-H \"{}: {}\"", API_KEY_HEADER, API_KEY_VARIABLE));
+
+    let body = request.body().as_ref();
+    if !body.is_empty() {
+        parts.push(format!("--data {}", quote(&String::from_utf8_lossy(body))));
+    }
+
+    parts.join(" \\\n  ")
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use http::Method;
+
+    use super::*;
+    use crate::api::{resources::Resources, time_entries::CreateTimeEntry};
+
+    fn base_url() -> Url {
+        Url::parse("https://api.teamdeck.io/v1/").unwrap()
+    }
+
+    #[test]
+    fn renders_query_parameters() {
+        let endpoint = Resources::builder()
+            .active(true)
+            .name("Ada")
+            .build()
+            .unwrap();
+
+        let request = render(&endpoint, &base_url()).unwrap();
+
+        assert_eq!(request.method(), Method::GET);
+        assert_eq!(
+            request.uri(),
+            "https://api.teamdeck.io/v1/resources?name=Ada&active=1"
+        );
+        assert_eq!(request.headers()["accept"], "application/json");
+        assert!(request.body().is_empty());
+    }
+
+    #[test]
+    fn renders_body_and_headers() {
+        let date = NaiveDate::from_ymd_opt(2026, 1, 5).unwrap();
+        let endpoint = CreateTimeEntry::builder()
+            .resource_id(1)
+            .project_id(2)
+            .minutes(60)
+            .start_date(date)
+            .end_date(date)
+            .disable_notifications(Some(true))
+            .build()
+            .unwrap();
+
+        let request = render(&endpoint, &base_url()).unwrap();
+
+        assert_eq!(request.method(), Method::POST);
+        assert_eq!(request.uri(), "https://api.teamdeck.io/v1/time-entries");
+        assert_eq!(request.headers()["x-td-disable-notifications"], "true");
+        let body: serde_json::Value = serde_json::from_slice(request.body()).unwrap();
+        assert_eq!(body["minutes"], 60);
+        assert_eq!(body["start_date"], "2026-01-05");
+    }
+
+    #[test]
+    fn curl_masks_api_key() {
+        let request = Request::builder()
+            .method(Method::PUT)
+            .uri("https://api.teamdeck.io/v1/time-entries/1")
+            .header("Content-Type", "application/json")
+            .header(API_KEY_HEADER, "secret")
+            .body(br#"{"description":"it's done"}"#.to_vec())
+            .unwrap();
+
+        assert_eq!(
+            curl(&request),
+            "curl -X PUT 'https://api.teamdeck.io/v1/time-entries/1' \\\n  \
+             -H 'content-type: application/json' \\\n  \
+             -H \"x-api-key: $TEAMDECK_API_KEY\" \\\n  \
+             --data '{\"description\":\"it'\\''s done\"}'"
+        );
+    }
+}
@@ -1,22 +1,23 @@
-use crate::api::{paged::Pageable, params::ParamValue, sort_by::SortBy, Endpoint, QueryParams};
+use crate::api::{
+    paged::Pageable,
+    params::ParamValue,
+    sort_by::{self, SortBy, UnknownSortField},
+    Endpoint, QueryParams,
+};
 use derive_builder::Builder;
 use http::Method;
 use std::borrow::Cow;
+use std::str::FromStr;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum ResourcesSortBy {
     Name,
+    #[default]
     Email,
     Role,
     Active,
 }
 
-impl Default for ResourcesSortBy {
-    fn default() -> Self {
-        Self::Email
-    }
-}
-
 impl ResourcesSortBy {
     fn as_str(self) -> &'static str {
         match self {
@@ -34,6 +35,14 @@ impl ParamValue<'static> for ResourcesSortBy {
     }
 }
 
+impl FromStr for ResourcesSortBy {
+    type Err = UnknownSortField;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        sort_by::parse_field(value, &[Self::Name, Self::Email, Self::Role, Self::Active])
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub enum ResourcesExpand {
     CustomFieldValues,
@@ -86,7 +95,7 @@ impl<'a> Endpoint for Resources<'a> {
         Method::GET
     }
 
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
 
         params
@@ -1,5 +1,7 @@
 mod many;
 mod single;
 
-pub use self::many::{Resources, ResourcesBuilder, ResourcesBuilderError, ResourcesSortBy};
+pub use self::many::{
+    Resources, ResourcesBuilder, ResourcesBuilderError, ResourcesExpand, ResourcesSortBy,
+};
 pub use self::single::{Resource, ResourceBuilder, ResourceBuilderError};
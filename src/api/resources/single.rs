@@ -27,7 +27,7 @@ impl Endpoint for Resource {
         format!("resources/{}", self.id).into()
     }
 
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
         params.push_opt("expand", self.expand);
 
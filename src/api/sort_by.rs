@@ -1,7 +1,31 @@
 use std::borrow::Cow;
+use std::str::FromStr;
+
+use thiserror::Error;
 
 use super::ParamValue;
 
+/// A sort field which the endpoint does not support.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("unknown sort field `{}`", field)]
+pub struct UnknownSortField {
+    pub field: String,
+}
+
+/// Find the field of `fields` whose parameter value is `value`.
+pub(crate) fn parse_field<T>(value: &str, fields: &[T]) -> Result<T, UnknownSortField>
+where
+    T: ParamValue<'static> + Copy,
+{
+    fields
+        .iter()
+        .find(|field| field.as_value() == value)
+        .copied()
+        .ok_or_else(|| UnknownSortField {
+            field: value.into(),
+        })
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SortBy<T>
 where
@@ -33,23 +57,34 @@ where
     }
 }
 
+/// Parse a sort parameter as Teamdeck writes it: the field name, prefixed with `-` for a
+/// descending sort.
+impl<T> FromStr for SortBy<T>
+where
+    T: ParamValue<'static> + FromStr<Err = UnknownSortField>,
+{
+    type Err = UnknownSortField;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.strip_prefix('-') {
+            Some(field) => Ok(Self::Desc(field.parse()?)),
+            None => Ok(Self::Asc(value.parse()?)),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
     enum DummySortBy {
+        #[default]
         Name,
         Email,
         Active,
     }
 
-    impl Default for DummySortBy {
-        fn default() -> Self {
-            Self::Name
-        }
-    }
-
     impl ParamValue<'static> for DummySortBy {
         fn as_value(&self) -> Cow<'static, str> {
             match self {
@@ -60,6 +95,14 @@ mod tests {
         }
     }
 
+    impl FromStr for DummySortBy {
+        type Err = UnknownSortField;
+
+        fn from_str(value: &str) -> Result<Self, Self::Err> {
+            parse_field(value, &[Self::Name, Self::Email, Self::Active])
+        }
+    }
+
     #[test]
     fn ascending_inner_default_by_default() {
         let sort_by: SortBy<DummySortBy> = Default::default();
@@ -68,7 +111,7 @@ mod tests {
 
     #[test]
     fn returns_inner_param_value_when_ascending() {
-        let sort_by_values = vec![
+        let sort_by_values = [
             SortBy::Asc(DummySortBy::Name),
             SortBy::Asc(DummySortBy::Email),
             SortBy::Asc(DummySortBy::Active),
@@ -85,7 +128,7 @@ mod tests {
 
     #[test]
     fn adds_hyphen_prefix_to_inner_values_when_descending() {
-        let sort_by_values = vec![
+        let sort_by_values = [
             SortBy::Desc(DummySortBy::Name),
             SortBy::Desc(DummySortBy::Email),
             SortBy::Desc(DummySortBy::Active),
@@ -100,4 +143,20 @@ mod tests {
 
         assert_eq!(param_values, expected_values)
     }
+
+    #[test]
+    fn parses_sort_direction() {
+        assert_eq!("email".parse(), Ok(SortBy::Asc(DummySortBy::Email)));
+        assert_eq!("-active".parse(), Ok(SortBy::Desc(DummySortBy::Active)));
+    }
+
+    #[test]
+    fn rejects_unknown_field() {
+        assert_eq!(
+            "-role".parse::<SortBy<DummySortBy>>(),
+            Err(UnknownSortField {
+                field: "role".into()
+            })
+        );
+    }
 }
@@ -8,9 +8,12 @@ use crate::api::{
     error::BodyError,
     header,
     params::{self, JsonParams},
+    upsert::Upsertable,
     Endpoint,
 };
 
+use super::{TimeEntries, UpdateTimeEntry};
+
 #[derive(Debug, Builder, Clone)]
 pub struct CreateTimeEntry<'a> {
     resource_id: u64,
@@ -80,6 +83,42 @@ impl<'a> Endpoint for CreateTimeEntry<'a> {
     }
 }
 
+impl<'a> Upsertable for CreateTimeEntry<'a> {
+    type Lookup = TimeEntries;
+    type Update = UpdateTimeEntry<'a>;
+
+    fn lookup(&self) -> Option<Self::Lookup> {
+        let external_id = self.external_id.as_ref()?;
+
+        let lookup = TimeEntries::builder()
+            .external_id(vec![external_id.to_string()])
+            .build()
+            .expect("all required fields are set");
+
+        Some(lookup)
+    }
+
+    fn update(&self, id: u64) -> Self::Update {
+        UpdateTimeEntry::builder()
+            .id(id)
+            .resource_id(self.resource_id)
+            .project_id(self.project_id)
+            .minutes(self.minutes)
+            .weekend_booking(self.weekend_booking)
+            .holidays_booking(self.holidays_booking)
+            .vacations_booking(self.vacations_booking)
+            .description(self.description.clone())
+            .external_id(self.external_id.clone())
+            .start_date(self.start_date)
+            .end_date(self.end_date)
+            .creator_resource_id(self.creator_resource_id)
+            .editor_resource_id(self.editor_resource_id)
+            .disable_notifications(self.disable_notifications)
+            .build()
+            .expect("all required fields are set")
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -103,8 +142,8 @@ mod tests {
                 .vacations_booking(Some(true))
                 .description(Some("description".into()))
                 .external_id(Some("external_id".into()))
-                .start_date(NaiveDate::from_ymd(2020, 1, 1))
-                .end_date(NaiveDate::from_ymd(2020, 1, 2))
+                .start_date(NaiveDate::from_ymd_opt(2020, 1, 1).unwrap())
+                .end_date(NaiveDate::from_ymd_opt(2020, 1, 2).unwrap())
                 .creator_resource_id(Some(4))
                 .editor_resource_id(Some(5))
                 .build()
@@ -143,8 +182,8 @@ mod tests {
                 .resource_id(1)
                 .project_id(2)
                 .minutes(3)
-                .start_date(NaiveDate::from_ymd(2020, 1, 1))
-                .end_date(NaiveDate::from_ymd(2020, 1, 2))
+                .start_date(NaiveDate::from_ymd_opt(2020, 1, 1).unwrap())
+                .end_date(NaiveDate::from_ymd_opt(2020, 1, 2).unwrap())
                 .build()
                 .unwrap(),
         );
@@ -172,8 +211,8 @@ mod tests {
         let endpoint = CreateTimeEntry::builder()
             .project_id(2)
             .minutes(3)
-            .start_date(NaiveDate::from_ymd(2020, 1, 1))
-            .end_date(NaiveDate::from_ymd(2020, 1, 2))
+            .start_date(NaiveDate::from_ymd_opt(2020, 1, 1).unwrap())
+            .end_date(NaiveDate::from_ymd_opt(2020, 1, 2).unwrap())
             .build();
 
         assert!(endpoint.is_err());
@@ -184,8 +223,8 @@ mod tests {
         let endpoint = CreateTimeEntry::builder()
             .resource_id(1)
             .minutes(3)
-            .start_date(NaiveDate::from_ymd(2020, 1, 1))
-            .end_date(NaiveDate::from_ymd(2020, 1, 2))
+            .start_date(NaiveDate::from_ymd_opt(2020, 1, 1).unwrap())
+            .end_date(NaiveDate::from_ymd_opt(2020, 1, 2).unwrap())
             .build();
 
         assert!(endpoint.is_err());
@@ -196,8 +235,8 @@ mod tests {
         let endpoint = CreateTimeEntry::builder()
             .resource_id(1)
             .project_id(2)
-            .start_date(NaiveDate::from_ymd(2020, 1, 1))
-            .end_date(NaiveDate::from_ymd(2020, 1, 2))
+            .start_date(NaiveDate::from_ymd_opt(2020, 1, 1).unwrap())
+            .end_date(NaiveDate::from_ymd_opt(2020, 1, 2).unwrap())
             .build();
 
         assert!(endpoint.is_err());
@@ -209,7 +248,7 @@ mod tests {
             .resource_id(1)
             .project_id(2)
             .minutes(3)
-            .end_date(NaiveDate::from_ymd(2020, 1, 2))
+            .end_date(NaiveDate::from_ymd_opt(2020, 1, 2).unwrap())
             .build();
 
         assert!(endpoint.is_err());
@@ -221,7 +260,7 @@ mod tests {
             .resource_id(1)
             .project_id(2)
             .minutes(3)
-            .start_date(NaiveDate::from_ymd(2020, 1, 1))
+            .start_date(NaiveDate::from_ymd_opt(2020, 1, 1).unwrap())
             .build();
 
         assert!(endpoint.is_err());
@@ -234,8 +273,8 @@ mod tests {
                 .resource_id(1)
                 .project_id(2)
                 .minutes(3)
-                .start_date(NaiveDate::from_ymd(2020, 1, 1))
-                .end_date(NaiveDate::from_ymd(2020, 1, 2))
+                .start_date(NaiveDate::from_ymd_opt(2020, 1, 1).unwrap())
+                .end_date(NaiveDate::from_ymd_opt(2020, 1, 2).unwrap())
                 .disable_notifications(Some(true))
                 .build()
                 .unwrap(),
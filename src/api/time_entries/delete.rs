@@ -4,7 +4,7 @@ use std::borrow::Cow;
 
 use crate::api::{header::disable_notifications_header, Endpoint};
 
-#[derive(Debug, Builder)]
+#[derive(Debug, Builder, Clone)]
 pub struct DeleteTimeEntry {
     id: usize,
     #[builder(default)]
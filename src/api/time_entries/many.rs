@@ -4,7 +4,7 @@ use derive_builder::Builder;
 use http::Method;
 use std::borrow::Cow;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum TimeEntriesSortBy {
     ResourceId,
     ProjectId,
@@ -14,18 +14,13 @@ pub enum TimeEntriesSortBy {
     VacationsBooking,
     Description,
     ExternalId,
+    #[default]
     StartDate,
     EndDate,
     CreatorResourceId,
     EditorResourceId,
 }
 
-impl Default for TimeEntriesSortBy {
-    fn default() -> Self {
-        Self::StartDate
-    }
-}
-
 impl TimeEntriesSortBy {
     fn as_str(self) -> &'static str {
         match self {
@@ -112,7 +107,7 @@ impl Endpoint for TimeEntries {
         Method::GET
     }
 
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
 
         params
@@ -1,6 +1,8 @@
 mod create;
 mod delete;
 mod many;
+mod operation;
+mod partial_update;
 mod single;
 mod tags;
 mod update;
@@ -10,6 +12,10 @@ pub use delete::{DeleteTimeEntry, DeleteTimeEntryBuilder, DeleteTimeEntryBuilder
 pub use many::{
     TimeEntries, TimeEntriesBuilder, TimeEntriesBuilderError, TimeEntriesExpand, TimeEntriesSortBy,
 };
+pub use operation::TimeEntryOperation;
+pub use partial_update::{
+    PartialUpdateTimeEntry, PartialUpdateTimeEntryBuilder, PartialUpdateTimeEntryBuilderError,
+};
 pub use single::{TimeEntry, TimeEntryBuilder, TimeEntryBuilderError};
 pub use tags::*;
 pub use update::{UpdateTimeEntry, UpdateTimeEntryBuilder, UpdateTimeEntryBuilderError};
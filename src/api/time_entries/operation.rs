@@ -0,0 +1,73 @@
+use http::Method;
+use std::borrow::Cow;
+
+use crate::api::{error::BodyError, Endpoint, QueryParams};
+
+use super::{CreateTimeEntry, DeleteTimeEntry, PartialUpdateTimeEntry, UpdateTimeEntry};
+
+/// Any write to a time entry, so that creates, updates and deletes can be sent in one
+/// [`batch`](crate::api::batch()).
+#[derive(Debug, Clone)]
+pub enum TimeEntryOperation<'a> {
+    Create(CreateTimeEntry<'a>),
+    Update(UpdateTimeEntry<'a>),
+    PartialUpdate(PartialUpdateTimeEntry<'a>),
+    Delete(DeleteTimeEntry),
+}
+
+impl<'a> TimeEntryOperation<'a> {
+    fn endpoint(&self) -> &dyn Endpoint {
+        match self {
+            Self::Create(endpoint) => endpoint,
+            Self::Update(endpoint) => endpoint,
+            Self::PartialUpdate(endpoint) => endpoint,
+            Self::Delete(endpoint) => endpoint,
+        }
+    }
+}
+
+impl<'a> Endpoint for TimeEntryOperation<'a> {
+    fn url(&self) -> Cow<'static, str> {
+        self.endpoint().url()
+    }
+
+    fn method(&self) -> Method {
+        self.endpoint().method()
+    }
+
+    fn body(&self) -> Result<Option<Vec<u8>>, BodyError> {
+        self.endpoint().body()
+    }
+
+    fn headers(&self) -> Option<http::HeaderMap> {
+        self.endpoint().headers()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        self.endpoint().parameters()
+    }
+}
+
+impl<'a> From<CreateTimeEntry<'a>> for TimeEntryOperation<'a> {
+    fn from(endpoint: CreateTimeEntry<'a>) -> Self {
+        Self::Create(endpoint)
+    }
+}
+
+impl<'a> From<UpdateTimeEntry<'a>> for TimeEntryOperation<'a> {
+    fn from(endpoint: UpdateTimeEntry<'a>) -> Self {
+        Self::Update(endpoint)
+    }
+}
+
+impl<'a> From<PartialUpdateTimeEntry<'a>> for TimeEntryOperation<'a> {
+    fn from(endpoint: PartialUpdateTimeEntry<'a>) -> Self {
+        Self::PartialUpdate(endpoint)
+    }
+}
+
+impl<'a> From<DeleteTimeEntry> for TimeEntryOperation<'a> {
+    fn from(endpoint: DeleteTimeEntry) -> Self {
+        Self::Delete(endpoint)
+    }
+}
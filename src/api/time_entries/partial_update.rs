@@ -0,0 +1,199 @@
+use chrono::NaiveDate;
+use derive_builder::Builder;
+use http::Method;
+use std::borrow::Cow;
+
+use crate::api::{
+    error::BodyError,
+    header::disable_notifications_header,
+    params::{FieldUpdate, JsonParams},
+    Endpoint, ParamValue,
+};
+
+/// Update only some fields of a time entry.
+///
+/// Fields which are not set are not sent, so they keep their current value. The `description`
+/// and `external_id` can also be cleared.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct PartialUpdateTimeEntry<'a> {
+    id: u64,
+    #[builder(default)]
+    resource_id: Option<u64>,
+    #[builder(default)]
+    project_id: Option<u64>,
+    #[builder(default)]
+    minutes: Option<u64>,
+    #[builder(default)]
+    weekend_booking: Option<bool>,
+    #[builder(default)]
+    holidays_booking: Option<bool>,
+    #[builder(default)]
+    vacations_booking: Option<bool>,
+    #[builder(setter(custom), default)]
+    description: FieldUpdate<Cow<'a, str>>,
+    #[builder(setter(custom), default)]
+    external_id: FieldUpdate<Cow<'a, str>>,
+    #[builder(default)]
+    start_date: Option<NaiveDate>,
+    #[builder(default)]
+    end_date: Option<NaiveDate>,
+    #[builder(default)]
+    editor_resource_id: Option<u64>,
+    #[builder(default)]
+    disable_notifications: Option<bool>,
+}
+
+impl<'a> PartialUpdateTimeEntry<'a> {
+    pub fn builder() -> PartialUpdateTimeEntryBuilder<'a> {
+        PartialUpdateTimeEntryBuilder::default()
+    }
+}
+
+impl<'a> PartialUpdateTimeEntryBuilder<'a> {
+    pub fn description<V: Into<Cow<'a, str>>>(&mut self, description: V) -> &mut Self {
+        self.description = Some(FieldUpdate::Set(description.into()));
+        self
+    }
+
+    /// Remove the description of the time entry.
+    pub fn clear_description(&mut self) -> &mut Self {
+        self.description = Some(FieldUpdate::Clear);
+        self
+    }
+
+    pub fn external_id<V: Into<Cow<'a, str>>>(&mut self, external_id: V) -> &mut Self {
+        self.external_id = Some(FieldUpdate::Set(external_id.into()));
+        self
+    }
+
+    /// Remove the external id of the time entry.
+    pub fn clear_external_id(&mut self) -> &mut Self {
+        self.external_id = Some(FieldUpdate::Clear);
+        self
+    }
+}
+
+impl<'a> Endpoint for PartialUpdateTimeEntry<'a> {
+    fn url(&self) -> Cow<'static, str> {
+        format!("time-entries/{}", self.id).into()
+    }
+
+    fn method(&self) -> http::Method {
+        Method::PUT
+    }
+
+    fn headers(&self) -> Option<http::HeaderMap> {
+        let mut headers = http::HeaderMap::new();
+        if let Some(disable_notifications) = self.disable_notifications {
+            disable_notifications_header(&mut headers, disable_notifications);
+        }
+        Some(headers)
+    }
+
+    fn body(&self) -> Result<Option<Vec<u8>>, BodyError> {
+        let mut params = JsonParams::default();
+
+        params
+            .push_opt("resource_id", self.resource_id)?
+            .push_opt("project_id", self.project_id)?
+            .push_opt("minutes", self.minutes)?
+            .push_opt("weekend_booking", self.weekend_booking)?
+            .push_opt("holidays_booking", self.holidays_booking)?
+            .push_opt("vacations_booking", self.vacations_booking)?
+            .push_field_update("description", &self.description)?
+            .push_field_update("external_id", &self.external_id)?
+            .push_opt("start_date", self.start_date.map(|date| date.as_value()))?
+            .push_opt("end_date", self.end_date.map(|date| date.as_value()))?
+            .push_opt("editor_resource_id", self.editor_resource_id)?;
+
+        Ok(Some(params.to_body()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        api::{self, Query},
+        test::client::{ExpectedRequest, TestClient},
+    };
+    use http::Method;
+    use serde_json::json;
+
+    #[test]
+    fn sends_only_set_fields() {
+        let endpoint = api::ignore(
+            PartialUpdateTimeEntry::builder()
+                .id(1)
+                .description("code review")
+                .build()
+                .unwrap(),
+        );
+
+        let expected = ExpectedRequest::builder()
+            .method(Method::PUT)
+            .path("/time-entries/1")
+            .request_body(json!({
+                "description": "code review",
+            }))
+            .build()
+            .unwrap();
+
+        let client = TestClient::expecting(expected);
+
+        endpoint.query(&client).unwrap();
+    }
+
+    #[test]
+    fn clears_fields() {
+        let endpoint = api::ignore(
+            PartialUpdateTimeEntry::builder()
+                .id(1)
+                .minutes(90)
+                .end_date(NaiveDate::from_ymd_opt(2026, 1, 6).unwrap())
+                .clear_description()
+                .clear_external_id()
+                .build()
+                .unwrap(),
+        );
+
+        let expected = ExpectedRequest::builder()
+            .method(Method::PUT)
+            .path("/time-entries/1")
+            .request_body(json!({
+                "minutes": 90,
+                "end_date": "2026-01-06",
+                "description": null,
+                "external_id": null,
+            }))
+            .build()
+            .unwrap();
+
+        let client = TestClient::expecting(expected);
+
+        endpoint.query(&client).unwrap();
+    }
+
+    #[test]
+    fn last_setter_wins() {
+        let endpoint = PartialUpdateTimeEntry::builder()
+            .id(1)
+            .clear_description()
+            .description("kept")
+            .build()
+            .unwrap();
+
+        let body: serde_json::Value =
+            serde_json::from_slice(&endpoint.body().unwrap().unwrap()).unwrap();
+
+        assert_eq!(body, json!({ "description": "kept" }));
+    }
+
+    #[test]
+    fn missing_id() {
+        let endpoint = PartialUpdateTimeEntry::builder().minutes(30).build();
+
+        assert!(endpoint.is_err());
+    }
+}
@@ -3,20 +3,15 @@ use derive_builder::Builder;
 use http::Method;
 use std::borrow::Cow;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum TimeEntryTagsSortBy {
     Id,
+    #[default]
     Name,
     Icon,
     Color,
 }
 
-impl Default for TimeEntryTagsSortBy {
-    fn default() -> Self {
-        Self::Name
-    }
-}
-
 impl TimeEntryTagsSortBy {
     fn as_str(self) -> &'static str {
         match self {
@@ -63,7 +58,7 @@ impl<'a> Endpoint for TimeEntryTags<'a> {
         "time-entry-tags".into()
     }
 
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
 
         params.push_opt("sort", self.sort);
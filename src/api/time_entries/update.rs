@@ -105,8 +105,8 @@ mod tests {
                 .vacations_booking(Some(true))
                 .description(Some("description".into()))
                 .external_id(Some("external_id".into()))
-                .start_date(NaiveDate::from_ymd(2020, 1, 1))
-                .end_date(NaiveDate::from_ymd(2020, 1, 2))
+                .start_date(NaiveDate::from_ymd_opt(2020, 1, 1).unwrap())
+                .end_date(NaiveDate::from_ymd_opt(2020, 1, 2).unwrap())
                 .creator_resource_id(Some(4))
                 .editor_resource_id(Some(5))
                 .build()
@@ -146,8 +146,8 @@ mod tests {
                 .resource_id(1)
                 .project_id(2)
                 .minutes(3)
-                .start_date(NaiveDate::from_ymd(2020, 1, 1))
-                .end_date(NaiveDate::from_ymd(2020, 1, 2))
+                .start_date(NaiveDate::from_ymd_opt(2020, 1, 1).unwrap())
+                .end_date(NaiveDate::from_ymd_opt(2020, 1, 2).unwrap())
                 .build()
                 .unwrap(),
         );
@@ -176,8 +176,8 @@ mod tests {
             .resource_id(1)
             .project_id(2)
             .minutes(3)
-            .start_date(NaiveDate::from_ymd(2020, 1, 1))
-            .end_date(NaiveDate::from_ymd(2020, 1, 2))
+            .start_date(NaiveDate::from_ymd_opt(2020, 1, 1).unwrap())
+            .end_date(NaiveDate::from_ymd_opt(2020, 1, 2).unwrap())
             .build();
 
         assert!(endpoint.is_err());
@@ -188,8 +188,8 @@ mod tests {
         let endpoint = UpdateTimeEntry::builder()
             .project_id(2)
             .minutes(3)
-            .start_date(NaiveDate::from_ymd(2020, 1, 1))
-            .end_date(NaiveDate::from_ymd(2020, 1, 2))
+            .start_date(NaiveDate::from_ymd_opt(2020, 1, 1).unwrap())
+            .end_date(NaiveDate::from_ymd_opt(2020, 1, 2).unwrap())
             .build();
 
         assert!(endpoint.is_err());
@@ -200,8 +200,8 @@ mod tests {
         let endpoint = UpdateTimeEntry::builder()
             .resource_id(1)
             .minutes(3)
-            .start_date(NaiveDate::from_ymd(2020, 1, 1))
-            .end_date(NaiveDate::from_ymd(2020, 1, 2))
+            .start_date(NaiveDate::from_ymd_opt(2020, 1, 1).unwrap())
+            .end_date(NaiveDate::from_ymd_opt(2020, 1, 2).unwrap())
             .build();
 
         assert!(endpoint.is_err());
@@ -212,8 +212,8 @@ mod tests {
         let endpoint = UpdateTimeEntry::builder()
             .resource_id(1)
             .project_id(2)
-            .start_date(NaiveDate::from_ymd(2020, 1, 1))
-            .end_date(NaiveDate::from_ymd(2020, 1, 2))
+            .start_date(NaiveDate::from_ymd_opt(2020, 1, 1).unwrap())
+            .end_date(NaiveDate::from_ymd_opt(2020, 1, 2).unwrap())
             .build();
 
         assert!(endpoint.is_err());
@@ -225,7 +225,7 @@ mod tests {
             .resource_id(1)
             .project_id(2)
             .minutes(3)
-            .end_date(NaiveDate::from_ymd(2020, 1, 2))
+            .end_date(NaiveDate::from_ymd_opt(2020, 1, 2).unwrap())
             .build();
 
         assert!(endpoint.is_err());
@@ -237,7 +237,7 @@ mod tests {
             .resource_id(1)
             .project_id(2)
             .minutes(3)
-            .start_date(NaiveDate::from_ymd(2020, 1, 1))
+            .start_date(NaiveDate::from_ymd_opt(2020, 1, 1).unwrap())
             .build();
 
         assert!(endpoint.is_err());
@@ -251,8 +251,8 @@ mod tests {
                 .resource_id(1)
                 .project_id(2)
                 .minutes(3)
-                .start_date(NaiveDate::from_ymd(2020, 1, 1))
-                .end_date(NaiveDate::from_ymd(2020, 1, 2))
+                .start_date(NaiveDate::from_ymd_opt(2020, 1, 1).unwrap())
+                .end_date(NaiveDate::from_ymd_opt(2020, 1, 2).unwrap())
                 .disable_notifications(Some(true))
                 .build()
                 .unwrap(),
@@ -0,0 +1,463 @@
+use async_trait::async_trait;
+use log::warn;
+use serde_json::{Map, Value};
+
+use crate::api::{ignore, ApiError, AsyncClient, AsyncQuery, Client, Endpoint, Query, RestClient};
+
+/// A create endpoint which can be matched to an existing record by its `external_id`.
+pub trait Upsertable: Endpoint {
+    /// The list endpoint used to find existing records.
+    type Lookup: Endpoint;
+    /// The endpoint used to update an existing record.
+    type Update: Endpoint;
+
+    /// A list endpoint filtered by the `external_id` of the record, `None` if it has none.
+    fn lookup(&self) -> Option<Self::Lookup>;
+
+    /// An update endpoint setting the fields of this record on the record with the given id.
+    fn update(&self, id: u64) -> Self::Update;
+}
+
+/// What an upsert did to the record.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UpsertOutcome {
+    Created,
+    Updated,
+    Unchanged,
+}
+
+/// The result of an upsert.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Upserted {
+    /// The id of the created or matched record.
+    pub id: u64,
+    pub outcome: UpsertOutcome,
+}
+
+/// A query modifier which updates the record with the same `external_id` instead of creating a
+/// duplicate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Upsert<E> {
+    endpoint: E,
+}
+
+/// Create the record, or update the existing record with the same `external_id`.
+///
+/// Records are left untouched when all the fields sent by the create endpoint already have the
+/// requested values. Records without an `external_id` are always created.
+pub fn upsert<E>(endpoint: E) -> Upsert<E> {
+    Upsert { endpoint }
+}
+
+/// A field which an upsert changes on the matched record.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldChange {
+    pub field: String,
+    pub old: Value,
+    pub new: Value,
+}
+
+/// The existing record an upsert matched by `external_id`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Matched {
+    pub id: u64,
+    pub record: Value,
+    /// The fields sent by the create endpoint which differ on the record, none when the record is
+    /// unchanged.
+    pub changes: Vec<FieldChange>,
+}
+
+impl<E> Upsert<E>
+where
+    E: Upsertable,
+{
+    /// Find the existing record with the same `external_id` and the fields the upsert would
+    /// change on it, without changing anything.
+    pub fn matched<C>(&self, client: &C) -> Result<Option<Matched>, ApiError<C::Error>>
+    where
+        C: Client,
+    {
+        let records: Vec<Value> = match self.endpoint.lookup() {
+            Some(lookup) => lookup.query(client)?,
+            None => vec![],
+        };
+
+        self.match_records::<C>(records)
+    }
+
+    /// Find the existing record with the same `external_id` and the fields the upsert would
+    /// change on it, without changing anything.
+    pub async fn matched_async<C>(&self, client: &C) -> Result<Option<Matched>, ApiError<C::Error>>
+    where
+        E: Sync,
+        E::Lookup: Sync + Send,
+        C: AsyncClient + Sync,
+    {
+        let records: Vec<Value> = match self.endpoint.lookup() {
+            Some(lookup) => lookup.query_async(client).await?,
+            None => vec![],
+        };
+
+        self.match_records::<C>(records)
+    }
+
+    fn match_records<C>(&self, records: Vec<Value>) -> Result<Option<Matched>, ApiError<C::Error>>
+    where
+        C: RestClient,
+    {
+        let count = records.len();
+        let record = match records.into_iter().next() {
+            Some(record) => record,
+            None => return Ok(None),
+        };
+        if count > 1 {
+            warn!(
+                target: "teamdeck",
+                "{} records share the external id of {}, updating the first one",
+                count,
+                self.endpoint.url()
+            );
+        }
+
+        let body = self.endpoint.body()?.unwrap_or_default();
+        let desired: Map<String, Value> = serde_json::from_slice(&body)?;
+        let changes = desired
+            .into_iter()
+            .filter(|(field, value)| record.get(field) != Some(value))
+            .map(|(field, new)| FieldChange {
+                old: record.get(&field).cloned().unwrap_or(Value::Null),
+                field,
+                new,
+            })
+            .collect();
+
+        Ok(Some(Matched {
+            id: record_id::<C>(&record)?,
+            record,
+            changes,
+        }))
+    }
+}
+
+fn record_id<C>(record: &Value) -> Result<u64, ApiError<C::Error>>
+where
+    C: RestClient,
+{
+    record
+        .get("id")
+        .and_then(Value::as_u64)
+        .ok_or_else(|| ApiError::TeamdeckUnrecognized {
+            obj: record.clone(),
+        })
+}
+
+impl<E, C> Query<Upserted, C> for Upsert<E>
+where
+    E: Upsertable,
+    C: Client,
+{
+    fn query(&self, client: &C) -> Result<Upserted, ApiError<C::Error>> {
+        if let Some(matched) = self.matched(client)? {
+            if matched.changes.is_empty() {
+                return Ok(Upserted {
+                    id: matched.id,
+                    outcome: UpsertOutcome::Unchanged,
+                });
+            }
+
+            ignore(self.endpoint.update(matched.id)).query(client)?;
+
+            return Ok(Upserted {
+                id: matched.id,
+                outcome: UpsertOutcome::Updated,
+            });
+        }
+
+        let created: Value = self.endpoint.query(client)?;
+
+        Ok(Upserted {
+            id: record_id::<C>(&created)?,
+            outcome: UpsertOutcome::Created,
+        })
+    }
+}
+
+#[async_trait]
+impl<E, C> AsyncQuery<Upserted, C> for Upsert<E>
+where
+    E: Upsertable + Sync,
+    E::Lookup: Sync + Send,
+    E::Update: Sync + Send,
+    C: AsyncClient + Sync,
+{
+    async fn query_async(&self, client: &C) -> Result<Upserted, ApiError<C::Error>> {
+        if let Some(matched) = self.matched_async(client).await? {
+            if matched.changes.is_empty() {
+                return Ok(Upserted {
+                    id: matched.id,
+                    outcome: UpsertOutcome::Unchanged,
+                });
+            }
+
+            ignore(self.endpoint.update(matched.id))
+                .query_async(client)
+                .await?;
+
+            return Ok(Upserted {
+                id: matched.id,
+                outcome: UpsertOutcome::Updated,
+            });
+        }
+
+        let created: Value = self.endpoint.query_async(client).await?;
+
+        Ok(Upserted {
+            id: record_id::<C>(&created)?,
+            outcome: UpsertOutcome::Created,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use http::Method;
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        api::{booking::CreateBooking, time_entries::CreateTimeEntry},
+        test::client::{ExpectedRequest, TestClient},
+    };
+
+    fn time_entry(external_id: Option<&'static str>) -> CreateTimeEntry<'static> {
+        CreateTimeEntry::builder()
+            .resource_id(1)
+            .project_id(2)
+            .minutes(60)
+            .start_date(NaiveDate::from_ymd_opt(2026, 1, 5).unwrap())
+            .end_date(NaiveDate::from_ymd_opt(2026, 1, 5).unwrap())
+            .external_id(external_id.map(Into::into))
+            .build()
+            .unwrap()
+    }
+
+    fn existing_time_entry(minutes: u64) -> Value {
+        json!({
+            "id": 10,
+            "resource_id": 1,
+            "project_id": 2,
+            "minutes": minutes,
+            "start_date": "2026-01-05",
+            "end_date": "2026-01-05",
+            "description": null,
+            "external_id": "import-1",
+        })
+    }
+
+    #[test]
+    fn creates_missing_record() {
+        let mut client = TestClient::new();
+        client.expect(
+            ExpectedRequest::builder()
+                .method(Method::GET)
+                .path("/time-entries")
+                .query(vec![("external_id".into(), "import-1".into())])
+                .response_body("[]")
+                .build()
+                .unwrap(),
+        );
+        client.expect(
+            ExpectedRequest::builder()
+                .method(Method::POST)
+                .path("/time-entries")
+                .response_body(json!({ "id": 11 }).to_string())
+                .build()
+                .unwrap(),
+        );
+
+        let upserted = upsert(time_entry(Some("import-1"))).query(&client).unwrap();
+
+        assert_eq!(
+            upserted,
+            Upserted {
+                id: 11,
+                outcome: UpsertOutcome::Created
+            }
+        );
+    }
+
+    #[test]
+    fn updates_changed_record() {
+        let mut client = TestClient::new();
+        client.expect(
+            ExpectedRequest::builder()
+                .method(Method::GET)
+                .path("/time-entries")
+                .query(vec![("external_id".into(), "import-1".into())])
+                .response_body(json!([existing_time_entry(30)]).to_string())
+                .build()
+                .unwrap(),
+        );
+        client.expect(
+            ExpectedRequest::builder()
+                .method(Method::PUT)
+                .path("/time-entries/10")
+                .request_body(json!({
+                    "resource_id": 1,
+                    "project_id": 2,
+                    "minutes": 60,
+                    "start_date": "2026-01-05",
+                    "end_date": "2026-01-05",
+                    "external_id": "import-1",
+                }))
+                .build()
+                .unwrap(),
+        );
+
+        let upserted = upsert(time_entry(Some("import-1"))).query(&client).unwrap();
+
+        assert_eq!(
+            upserted,
+            Upserted {
+                id: 10,
+                outcome: UpsertOutcome::Updated
+            }
+        );
+    }
+
+    #[test]
+    fn leaves_unchanged_record() {
+        let client = TestClient::expecting(
+            ExpectedRequest::builder()
+                .method(Method::GET)
+                .path("/time-entries")
+                .query(vec![("external_id".into(), "import-1".into())])
+                .response_body(json!([existing_time_entry(60)]).to_string())
+                .build()
+                .unwrap(),
+        );
+
+        let upserted = upsert(time_entry(Some("import-1"))).query(&client).unwrap();
+
+        assert_eq!(
+            upserted,
+            Upserted {
+                id: 10,
+                outcome: UpsertOutcome::Unchanged
+            }
+        );
+    }
+
+    #[test]
+    fn reports_changed_fields_of_matched_record() {
+        let client = TestClient::expecting(
+            ExpectedRequest::builder()
+                .method(Method::GET)
+                .path("/time-entries")
+                .query(vec![("external_id".into(), "import-1".into())])
+                .response_body(json!([existing_time_entry(30)]).to_string())
+                .build()
+                .unwrap(),
+        );
+
+        let matched = upsert(time_entry(Some("import-1")))
+            .matched(&client)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(matched.id, 10);
+        assert_eq!(
+            matched.changes,
+            vec![FieldChange {
+                field: "minutes".into(),
+                old: json!(30),
+                new: json!(60),
+            }]
+        );
+    }
+
+    #[test]
+    fn creates_record_without_external_id() {
+        let client = TestClient::expecting(
+            ExpectedRequest::builder()
+                .method(Method::POST)
+                .path("/time-entries")
+                .response_body(json!({ "id": 12 }).to_string())
+                .build()
+                .unwrap(),
+        );
+
+        let upserted = upsert(time_entry(None)).query(&client).unwrap();
+
+        assert_eq!(upserted.outcome, UpsertOutcome::Created);
+    }
+
+    #[test]
+    fn updates_booking() {
+        let mut client = TestClient::new();
+        client.expect(
+            ExpectedRequest::builder()
+                .method(Method::GET)
+                .path("/bookings")
+                .query(vec![("external_id".into(), "plan-1".into())])
+                .response_body(
+                    json!([{
+                        "id": 20,
+                        "resource_id": 1,
+                        "project_id": 2,
+                        "minutes": 240,
+                        "start_date": "2026-01-05",
+                        "end_date": "2026-01-09",
+                        "external_id": "plan-1",
+                    }])
+                    .to_string(),
+                )
+                .build()
+                .unwrap(),
+        );
+        client.expect(
+            ExpectedRequest::builder()
+                .method(Method::PUT)
+                .path("/bookings/20")
+                .build()
+                .unwrap(),
+        );
+
+        let booking = CreateBooking::builder()
+            .resource_id(1)
+            .project_id(2)
+            .minutes(480)
+            .start_date(NaiveDate::from_ymd_opt(2026, 1, 5).unwrap())
+            .end_date(NaiveDate::from_ymd_opt(2026, 1, 9).unwrap())
+            .external_id(Some("plan-1".into()))
+            .build()
+            .unwrap();
+
+        let upserted = upsert(booking).query(&client).unwrap();
+
+        assert_eq!(
+            upserted,
+            Upserted {
+                id: 20,
+                outcome: UpsertOutcome::Updated
+            }
+        );
+    }
+
+    #[test]
+    fn rejects_record_without_id() {
+        let client = TestClient::expecting(
+            ExpectedRequest::builder()
+                .method(Method::POST)
+                .path("/time-entries")
+                .response_body("{}")
+                .build()
+                .unwrap(),
+        );
+
+        let err = upsert(time_entry(None)).query(&client).unwrap_err();
+
+        assert!(matches!(err, ApiError::TeamdeckUnrecognized { .. }));
+    }
+}
@@ -0,0 +1,125 @@
+use chrono::NaiveDate;
+use derive_builder::Builder;
+use http::Method;
+use params::ParamValue;
+use std::borrow::Cow;
+
+use crate::api::{
+    error::BodyError,
+    header,
+    params::{self, JsonParams},
+    Endpoint,
+};
+use crate::models::VacationStatus;
+
+#[derive(Debug, Builder, Clone)]
+pub struct CreateVacation<'a> {
+    resource_id: u64,
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+    #[builder(default)]
+    status: Option<VacationStatus>,
+    #[builder(default)]
+    period_id: Option<u64>,
+    #[builder(default)]
+    reason_id: Option<u64>,
+    #[builder(setter(into), default)]
+    description: Option<Cow<'a, str>>,
+    #[builder(setter(into), default)]
+    external_id: Option<Cow<'a, str>>,
+    #[builder(default)]
+    disable_notifications: Option<bool>,
+}
+
+impl<'a> CreateVacation<'a> {
+    pub fn builder() -> CreateVacationBuilder<'a> {
+        CreateVacationBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for CreateVacation<'a> {
+    fn url(&self) -> Cow<'static, str> {
+        "vacations".into()
+    }
+
+    fn method(&self) -> http::Method {
+        Method::POST
+    }
+
+    fn body(&self) -> Result<Option<Vec<u8>>, BodyError> {
+        let mut params = JsonParams::default();
+
+        params
+            .push("resource_id", self.resource_id)?
+            .push("start_date", self.start_date.as_value())?
+            .push("end_date", self.end_date.as_value())?
+            .push_opt("status", self.status)?
+            .push_opt("period_id", self.period_id)?
+            .push_opt("reason_id", self.reason_id)?
+            .push_param_value_opt("description", self.description.as_ref())?
+            .push_param_value_opt("external_id", self.external_id.as_ref())?;
+
+        Ok(Some(params.to_body()?))
+    }
+
+    fn headers(&self) -> Option<http::HeaderMap> {
+        let mut headers = http::HeaderMap::new();
+        if let Some(disable_notifications) = self.disable_notifications {
+            header::disable_notifications_header(&mut headers, disable_notifications);
+        }
+        Some(headers)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        api::{self, Query},
+        test::client::{ExpectedRequest, TestClient},
+    };
+    use serde_json::json;
+
+    #[test]
+    fn create_vacation_request() {
+        let endpoint = api::ignore(
+            CreateVacation::builder()
+                .resource_id(1)
+                .start_date(NaiveDate::from_ymd_opt(2026, 8, 3).unwrap())
+                .end_date(NaiveDate::from_ymd_opt(2026, 8, 14).unwrap())
+                .status(Some(VacationStatus::Approved))
+                .reason_id(Some(2))
+                .external_id(Some("leave-42".into()))
+                .build()
+                .unwrap(),
+        );
+
+        let expected = ExpectedRequest::builder()
+            .method(Method::POST)
+            .path("/vacations")
+            .request_body(json!({
+                "resource_id": 1,
+                "start_date": "2026-08-03",
+                "end_date": "2026-08-14",
+                "status": "approved",
+                "reason_id": 2,
+                "external_id": "leave-42",
+            }))
+            .build()
+            .unwrap();
+
+        let client = TestClient::expecting(expected);
+
+        endpoint.query(&client).unwrap();
+    }
+
+    #[test]
+    fn missing_resource() {
+        let endpoint = CreateVacation::builder()
+            .start_date(NaiveDate::from_ymd_opt(2026, 8, 3).unwrap())
+            .end_date(NaiveDate::from_ymd_opt(2026, 8, 14).unwrap())
+            .build();
+
+        assert!(endpoint.is_err());
+    }
+}
@@ -1,10 +1,16 @@
-use crate::api::{paged::Pageable, params::ParamValue, sort_by::SortBy, Endpoint, QueryParams};
+use crate::api::{
+    paged::Pageable,
+    params::ParamValue,
+    sort_by::{self, SortBy, UnknownSortField},
+    Endpoint, QueryParams,
+};
 use chrono::NaiveDate;
 use derive_builder::Builder;
 use http::Method;
 use std::borrow::Cow;
+use std::str::FromStr;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum VacationsSortBy {
     ResourceId,
     Status,
@@ -13,6 +19,7 @@ pub enum VacationsSortBy {
     ReasonId,
     Description,
     ExternalId,
+    #[default]
     StartDate,
     EndDate,
     CreatorResourceId,
@@ -20,12 +27,6 @@ pub enum VacationsSortBy {
     EditorResourceId,
 }
 
-impl Default for VacationsSortBy {
-    fn default() -> Self {
-        Self::StartDate
-    }
-}
-
 impl VacationsSortBy {
     fn as_str(self) -> &'static str {
         match self {
@@ -51,6 +52,30 @@ impl ParamValue<'static> for VacationsSortBy {
     }
 }
 
+impl FromStr for VacationsSortBy {
+    type Err = UnknownSortField;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        sort_by::parse_field(
+            value,
+            &[
+                Self::ResourceId,
+                Self::Status,
+                Self::PeriodId,
+                Self::RequestedApproverId,
+                Self::ReasonId,
+                Self::Description,
+                Self::ExternalId,
+                Self::StartDate,
+                Self::EndDate,
+                Self::CreatorResourceId,
+                Self::ApproverResourceId,
+                Self::EditorResourceId,
+            ],
+        )
+    }
+}
+
 #[derive(Debug, Builder)]
 #[builder(setter(strip_option))]
 pub struct Vacations {
@@ -89,7 +114,7 @@ impl Endpoint for Vacations {
         Method::GET
     }
 
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
 
         params
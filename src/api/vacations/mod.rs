@@ -1,8 +1,10 @@
+mod create;
 mod many;
 mod periods;
 mod reasons;
 mod single;
 
+pub use create::{CreateVacation, CreateVacationBuilder, CreateVacationBuilderError};
 pub use many::{Vacations, VacationsBuilder, VacationsBuilderError, VacationsSortBy};
 pub use periods::*;
 pub use reasons::*;
@@ -3,20 +3,15 @@ use derive_builder::Builder;
 use http::Method;
 use std::borrow::Cow;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum VacationPeriodsSortBy {
     Id,
+    #[default]
     Name,
     Archived,
     HoursPerDay,
 }
 
-impl Default for VacationPeriodsSortBy {
-    fn default() -> Self {
-        Self::Name
-    }
-}
-
 impl VacationPeriodsSortBy {
     fn as_str(self) -> &'static str {
         match self {
@@ -62,7 +57,7 @@ impl<'a> Endpoint for VacationPeriods<'a> {
         Method::GET
     }
 
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
 
         params
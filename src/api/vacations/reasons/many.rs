@@ -3,8 +3,9 @@ use derive_builder::Builder;
 use http::Method;
 use std::borrow::Cow;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum VacationReasonsSortBy {
+    #[default]
     Name,
     Archived,
     Payable,
@@ -12,12 +13,6 @@ pub enum VacationReasonsSortBy {
     Id,
 }
 
-impl Default for VacationReasonsSortBy {
-    fn default() -> Self {
-        Self::Name
-    }
-}
-
 impl VacationReasonsSortBy {
     fn as_str(self) -> &'static str {
         match self {
@@ -65,7 +60,7 @@ impl<'a> Endpoint for VacationReasons<'a> {
         Method::GET
     }
 
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
 
         params
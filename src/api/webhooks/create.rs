@@ -0,0 +1,133 @@
+use derive_builder::Builder;
+use http::Method;
+use std::borrow::Cow;
+
+use crate::api::{error::BodyError, params::JsonParams, Endpoint, ParamValue};
+
+/// The kinds of events a webhook can be subscribed to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WebhookEventKind {
+    BookingCreated,
+    BookingUpdated,
+    BookingDeleted,
+    TimeEntryCreated,
+    TimeEntryUpdated,
+    TimeEntryDeleted,
+    VacationCreated,
+    VacationUpdated,
+    VacationApproved,
+    VacationRejected,
+    VacationDeleted,
+}
+
+impl WebhookEventKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::BookingCreated => "booking.created",
+            Self::BookingUpdated => "booking.updated",
+            Self::BookingDeleted => "booking.deleted",
+            Self::TimeEntryCreated => "time_entry.created",
+            Self::TimeEntryUpdated => "time_entry.updated",
+            Self::TimeEntryDeleted => "time_entry.deleted",
+            Self::VacationCreated => "vacation.created",
+            Self::VacationUpdated => "vacation.updated",
+            Self::VacationApproved => "vacation.approved",
+            Self::VacationRejected => "vacation.rejected",
+            Self::VacationDeleted => "vacation.deleted",
+        }
+    }
+}
+
+impl ParamValue<'static> for WebhookEventKind {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+#[derive(Debug, Builder, Clone)]
+pub struct CreateWebhook<'a> {
+    #[builder(setter(into))]
+    url: Cow<'a, str>,
+    events: Vec<WebhookEventKind>,
+    #[builder(setter(into), default)]
+    secret: Option<Cow<'a, str>>,
+}
+
+impl<'a> CreateWebhook<'a> {
+    pub fn builder() -> CreateWebhookBuilder<'a> {
+        CreateWebhookBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for CreateWebhook<'a> {
+    fn url(&self) -> Cow<'static, str> {
+        "webhooks".into()
+    }
+
+    fn method(&self) -> http::Method {
+        Method::POST
+    }
+
+    fn body(&self) -> Result<Option<Vec<u8>>, BodyError> {
+        let mut params = JsonParams::default();
+
+        params
+            .push_param_value("url", &self.url)?
+            .push(
+                "events",
+                self.events.iter().map(|e| e.as_str()).collect::<Vec<_>>(),
+            )?
+            .push_param_value_opt("secret", self.secret.as_ref())?;
+
+        Ok(Some(params.to_body()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        api::{self, Query},
+        test::client::{ExpectedRequest, TestClient},
+    };
+    use serde_json::json;
+
+    #[test]
+    fn create_webhook_request() {
+        let endpoint = api::ignore(
+            CreateWebhook::builder()
+                .url("https://example.com/hooks/teamdeck")
+                .events(vec![
+                    WebhookEventKind::BookingCreated,
+                    WebhookEventKind::VacationApproved,
+                ])
+                .secret(Some("s3cr3t".into()))
+                .build()
+                .unwrap(),
+        );
+
+        let expected = ExpectedRequest::builder()
+            .method(Method::POST)
+            .path("/webhooks")
+            .request_body(json!({
+                "url": "https://example.com/hooks/teamdeck",
+                "events": ["booking.created", "vacation.approved"],
+                "secret": "s3cr3t",
+            }))
+            .build()
+            .unwrap();
+
+        let client = TestClient::expecting(expected);
+
+        endpoint.query(&client).unwrap();
+    }
+
+    #[test]
+    fn missing_events() {
+        let endpoint = CreateWebhook::builder()
+            .url("https://example.com/hooks/teamdeck")
+            .build();
+
+        assert!(endpoint.is_err());
+    }
+}
@@ -0,0 +1,50 @@
+use derive_builder::Builder;
+use http::Method;
+use std::borrow::Cow;
+
+use crate::api::Endpoint;
+
+#[derive(Debug, Builder)]
+pub struct DeleteWebhook {
+    id: u64,
+}
+
+impl DeleteWebhook {
+    pub fn builder() -> DeleteWebhookBuilder {
+        DeleteWebhookBuilder::default()
+    }
+}
+
+impl Endpoint for DeleteWebhook {
+    fn url(&self) -> Cow<'static, str> {
+        format!("webhooks/{}", self.id).into()
+    }
+
+    fn method(&self) -> http::Method {
+        Method::DELETE
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        api::{self, Query},
+        test::client::{ExpectedRequest, TestClient},
+    };
+
+    #[test]
+    fn delete_webhook() {
+        let endpoint = api::ignore(DeleteWebhook::builder().id(1).build().unwrap());
+
+        let expected = ExpectedRequest::builder()
+            .method(Method::DELETE)
+            .path("/webhooks/1")
+            .build()
+            .unwrap();
+
+        let client = TestClient::expecting(expected);
+
+        endpoint.query(&client).unwrap();
+    }
+}
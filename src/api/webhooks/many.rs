@@ -0,0 +1,79 @@
+use derive_builder::Builder;
+use http::Method;
+use std::borrow::Cow;
+
+use crate::api::{paged::Pageable, Endpoint, QueryParams};
+
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct Webhooks {
+    #[builder(default)]
+    page: Option<u64>,
+}
+
+impl Webhooks {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> WebhooksBuilder {
+        WebhooksBuilder::default()
+    }
+}
+
+impl Endpoint for Webhooks {
+    fn url(&self) -> Cow<'static, str> {
+        "webhooks".into()
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+
+        params.push_opt("page", self.page);
+
+        params
+    }
+}
+
+impl Pageable for Webhooks {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        api::{self, Query},
+        test::client::{ExpectedRequest, TestClient},
+    };
+
+    #[test]
+    fn endpoint() {
+        let endpoint = api::ignore(Webhooks::builder().build().unwrap());
+
+        let expected = ExpectedRequest::builder()
+            .method(Method::GET)
+            .path("/webhooks")
+            .build()
+            .unwrap();
+
+        let client = TestClient::expecting(expected);
+
+        endpoint.query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_page() {
+        let endpoint = api::ignore(Webhooks::builder().page(2).build().unwrap());
+
+        let expected = ExpectedRequest::builder()
+            .method(Method::GET)
+            .path("/webhooks")
+            .query(vec![("page".into(), "2".into())])
+            .build()
+            .unwrap();
+
+        let client = TestClient::expecting(expected);
+
+        endpoint.query(&client).unwrap();
+    }
+}
@@ -0,0 +1,11 @@
+mod create;
+mod delete;
+mod many;
+mod single;
+
+pub use create::{
+    CreateWebhook, CreateWebhookBuilder, CreateWebhookBuilderError, WebhookEventKind,
+};
+pub use delete::{DeleteWebhook, DeleteWebhookBuilder, DeleteWebhookBuilderError};
+pub use many::{Webhooks, WebhooksBuilder, WebhooksBuilderError};
+pub use single::{Webhook, WebhookBuilder, WebhookBuilderError};
@@ -0,0 +1,55 @@
+use derive_builder::Builder;
+use http::Method;
+use std::borrow::Cow;
+
+use crate::api::Endpoint;
+
+#[derive(Debug, Builder)]
+pub struct Webhook {
+    id: u64,
+}
+
+impl Webhook {
+    pub fn builder() -> WebhookBuilder {
+        WebhookBuilder::default()
+    }
+}
+
+impl Endpoint for Webhook {
+    fn url(&self) -> Cow<'static, str> {
+        format!("webhooks/{}", self.id).into()
+    }
+
+    fn method(&self) -> http::Method {
+        Method::GET
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        api::{self, Query},
+        test::client::{ExpectedRequest, TestClient},
+    };
+
+    #[test]
+    fn webhook_id_is_required() {
+        assert!(Webhook::builder().build().is_err());
+    }
+
+    #[test]
+    fn webhook() {
+        let endpoint = api::ignore(Webhook::builder().id(1).build().unwrap());
+
+        let expected = ExpectedRequest::builder()
+            .method(Method::GET)
+            .path("/webhooks/1")
+            .build()
+            .unwrap();
+
+        let client = TestClient::expecting(expected);
+
+        endpoint.query(&client).unwrap();
+    }
+}
@@ -0,0 +1,274 @@
+//! Searches for the resources with time to spare, e.g. to staff a project: who is free next week
+//! for 20 hours?
+
+use std::cmp::Reverse;
+use std::collections::HashMap;
+
+use chrono::NaiveDate;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::api::{
+    self,
+    resources::{Resources, ResourcesExpand},
+    ApiError, Client, Pagination, Query,
+};
+use crate::capacity::{bookings, BookingsOf, Capacity, DayCapacity};
+use crate::models::Booking;
+
+/// A resource and the time it has left over the search dates, in minutes.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Candidate {
+    pub resource_id: u64,
+    pub name: Option<String>,
+    pub email: Option<String>,
+    pub role: Option<String>,
+    /// The time left after holidays and vacations.
+    pub available: u64,
+    pub booked: u64,
+    /// The time left after bookings too. Over-booked days do not take from other days.
+    pub free: u64,
+    /// Whether the free time covers the required time.
+    pub fits: bool,
+}
+
+/// Finds the active resources matching some filters and ranks them by the time they have free
+/// from `from` to `to` inclusive, after holidays, vacations and bookings.
+#[derive(Debug, Clone)]
+pub struct AvailabilitySearch {
+    from: NaiveDate,
+    to: NaiveDate,
+    required: u64,
+    capacity: Capacity,
+    role: Option<String>,
+    custom_fields: Vec<(u64, String)>,
+}
+
+fn text(resource: &Value, field: &str) -> Option<String> {
+    resource[field].as_str().map(String::from)
+}
+
+/// A custom field value as text, whether it is a string or not.
+fn value_text(value: &Value) -> String {
+    match value {
+        Value::String(value) => value.clone(),
+        value => value.to_string(),
+    }
+}
+
+impl AvailabilitySearch {
+    pub fn new(from: NaiveDate, to: NaiveDate, required_hours: f64) -> Self {
+        Self {
+            from,
+            to,
+            required: (required_hours * 60.0).round().max(0.0) as u64,
+            capacity: Capacity::default(),
+            role: None,
+            custom_fields: Vec::new(),
+        }
+    }
+
+    /// The working time of resources, Monday to Friday for 8 hours by default.
+    pub fn capacity(mut self, capacity: Capacity) -> Self {
+        self.capacity = capacity;
+        self
+    }
+
+    /// Only resources with this role, ignoring case.
+    pub fn role(mut self, role: impl Into<String>) -> Self {
+        self.role = Some(role.into().to_lowercase());
+        self
+    }
+
+    /// Only resources with this value of a custom field, ignoring case.
+    pub fn custom_field(mut self, custom_field_id: u64, value: impl Into<String>) -> Self {
+        self.custom_fields
+            .push((custom_field_id, value.into().to_lowercase()));
+        self
+    }
+
+    /// Whether a resource record matches the filters. Custom field values are read from the
+    /// `custom_field_values` the records are expanded with.
+    pub fn matches(&self, resource: &Value) -> bool {
+        let role = self.role.as_ref().is_none_or(|role| {
+            resource["role"]
+                .as_str()
+                .is_some_and(|candidate| candidate.to_lowercase() == *role)
+        });
+        let values = resource["custom_field_values"]
+            .as_array()
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        let custom_fields = self.custom_fields.iter().all(|(id, value)| {
+            values.iter().any(|candidate| {
+                candidate["custom_field_id"].as_u64() == Some(*id)
+                    && value_text(&candidate["value"]).to_lowercase() == *value
+            })
+        });
+
+        role && custom_fields
+    }
+
+    /// Rank resources by their free time, given the resource records, their capacity on each day
+    /// of the search and their bookings. The resources with the most free time come first.
+    pub fn calculate(
+        &self,
+        resources: &[Value],
+        capacity: &[DayCapacity],
+        bookings: &[Booking],
+    ) -> Vec<Candidate> {
+        // (available, booked, free) by resource.
+        let mut time: HashMap<u64, (u64, u64, u64)> = HashMap::new();
+        for day in capacity {
+            let booked = bookings
+                .iter()
+                .filter(|booking| day.books(booking))
+                .map(|booking| booking.minutes)
+                .sum::<u64>();
+
+            let time = time.entry(day.resource_id).or_default();
+            time.0 += day.available;
+            time.1 += booked;
+            time.2 += day.available.saturating_sub(booked);
+        }
+
+        let mut candidates = resources
+            .iter()
+            .filter(|resource| self.matches(resource))
+            .filter_map(|resource| {
+                let resource_id = resource["id"].as_u64()?;
+                let (available, booked, free) = time.get(&resource_id).copied().unwrap_or_default();
+                Some(Candidate {
+                    resource_id,
+                    name: text(resource, "name"),
+                    email: text(resource, "email"),
+                    role: text(resource, "role"),
+                    available,
+                    booked,
+                    free,
+                    fits: free >= self.required,
+                })
+            })
+            .collect::<Vec<_>>();
+        candidates.sort_by_key(|candidate| (Reverse(candidate.free), candidate.resource_id));
+
+        candidates
+    }
+
+    /// Fetch the active resources, their holidays, vacations and bookings, and rank the ones
+    /// matching the filters.
+    pub fn fetch<C: Client>(&self, client: &C) -> Result<Vec<Candidate>, ApiError<C::Error>> {
+        let mut builder = Resources::builder();
+        builder.active(true);
+        if !self.custom_fields.is_empty() {
+            builder.expand(ResourcesExpand::CustomFieldValues);
+        }
+        let resources: Vec<Value> =
+            api::paged(builder.build().unwrap(), Pagination::All).query(client)?;
+        let resources = resources
+            .into_iter()
+            .filter(|resource| self.matches(resource))
+            .collect::<Vec<_>>();
+        let ids = resources
+            .iter()
+            .filter_map(|resource| resource["id"].as_u64())
+            .collect::<Vec<_>>();
+
+        let capacity = self.capacity.fetch(&ids, self.from, self.to, client)?;
+
+        let bookings = if ids.is_empty() {
+            vec![]
+        } else {
+            bookings(
+                BookingsOf::Resources(&ids),
+                self.from,
+                Some(self.to),
+                client,
+            )?
+        };
+
+        Ok(self.calculate(&resources, &capacity, &bookings))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::testing::fixtures::{booking, date, insert_holiday, vacation};
+    use crate::testing::FakeTeamdeck;
+
+    fn teamdeck() -> FakeTeamdeck {
+        let teamdeck = FakeTeamdeck::new();
+        let resource = |name: &str, role: &str, active, team: &str| {
+            json!({
+                "name": name,
+                "role": role,
+                "active": active,
+                "custom_field_values": [{ "custom_field_id": 3, "value": team }],
+            })
+        };
+        for resource in [
+            resource("Ada", "Developer", true, "Engine"),
+            resource("Grace", "Developer", true, "Compiler"),
+            resource("Charles", "Developer", false, "Engine"),
+            resource("Alan", "Designer", true, "Engine"),
+        ] {
+            teamdeck.insert("resources", resource);
+        }
+        insert_holiday(&teamdeck, "2026-06-01");
+        teamdeck.insert(
+            "vacations",
+            vacation(2, "2026-06-04", "2026-06-05", "approved"),
+        );
+        teamdeck.insert("bookings", booking(1, 10, "2026-06-01", "2026-06-05", 600));
+
+        teamdeck
+    }
+
+    #[test]
+    fn ranks_resources_by_free_time() {
+        let teamdeck = teamdeck();
+
+        let candidates = AvailabilitySearch::new(date(1), date(7), 20.0)
+            .fetch(&teamdeck)
+            .unwrap();
+
+        let candidates = candidates
+            .iter()
+            .map(|candidate| {
+                (
+                    candidate.name.as_deref().unwrap(),
+                    candidate.available / 60,
+                    candidate.booked / 60,
+                    candidate.free / 60,
+                    candidate.fits,
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            candidates,
+            vec![
+                ("Alan", 32, 0, 32, true),
+                ("Grace", 16, 0, 16, false),
+                ("Ada", 32, 40, 0, false),
+            ]
+        );
+    }
+
+    #[test]
+    fn filters_by_role_and_custom_fields() {
+        let teamdeck = teamdeck();
+
+        let candidates = AvailabilitySearch::new(date(1), date(7), 20.0)
+            .role("developer")
+            .custom_field(3, "engine")
+            .fetch(&teamdeck)
+            .unwrap();
+
+        assert_eq!(candidates.len(), 1);
+        assert_eq!(candidates[0].name.as_deref(), Some("Ada"));
+        assert_eq!(candidates[0].role.as_deref(), Some("Developer"));
+    }
+}
@@ -0,0 +1,90 @@
+use std::collections::HashMap;
+use std::error::Error;
+
+use chrono::{Duration, Local, NaiveDate};
+use clap::Args;
+use serde_json::Value;
+use teamdeck::api::{
+    self, booking::Bookings, holidays::Holidays, projects::Projects, resources::Resources,
+    vacations::Vacations, Client, Endpoint, Pagination, Query,
+};
+use teamdeck::icalendar::Calendar;
+use teamdeck::models::{Booking, Holiday, Vacation};
+
+/// The length of a feed without an end date.
+const DEFAULT_DAYS: i64 = 90;
+
+#[derive(Debug, Args)]
+pub struct CalendarArgs {
+    /// The first day of the feed, today by default.
+    #[arg(long)]
+    pub from: Option<NaiveDate>,
+    /// The last day of the feed, 90 days after the first by default.
+    #[arg(long)]
+    pub to: Option<NaiveDate>,
+    /// Only the vacations and bookings of these resource ids, for a personal or team feed.
+    #[arg(long, value_delimiter = ',')]
+    pub resource: Vec<u64>,
+    /// Leave out bookings.
+    #[arg(long)]
+    pub no_bookings: bool,
+    /// The name calendar apps show for the feed.
+    #[arg(long, default_value = "Teamdeck")]
+    pub name: String,
+}
+
+fn names<E, C>(endpoint: E, client: &C) -> Result<HashMap<u64, String>, Box<dyn Error>>
+where
+    E: Endpoint,
+    api::Paged<E>: Query<Vec<Value>, C>,
+    C: Client,
+{
+    let records = api::paged(endpoint, Pagination::All).query(client)?;
+
+    Ok(records
+        .iter()
+        .filter_map(|record| Some((record["id"].as_u64()?, record["name"].as_str()?.to_owned())))
+        .collect())
+}
+
+impl CalendarArgs {
+    /// The feed as iCalendar text.
+    pub fn run<C: Client>(&self, client: &C) -> Result<String, Box<dyn Error>> {
+        let from = self.from.unwrap_or_else(|| Local::now().date_naive());
+        let to = self.to.unwrap_or(from + Duration::days(DEFAULT_DAYS));
+
+        let mut calendar = Calendar::new(self.name.as_str())
+            .resource_names(names(Resources::builder().build()?, client)?)
+            .project_names(names(Projects::builder().build()?, client)?);
+        if !self.resource.is_empty() {
+            calendar = calendar.resources(self.resource.iter().copied());
+        }
+
+        let mut builder = Vacations::builder();
+        builder.end_date_from(from).start_date_to(to);
+        if !self.resource.is_empty() {
+            builder.resource_id(self.resource.clone());
+        }
+        let vacations: Vec<Vacation> =
+            api::paged(builder.build()?, Pagination::All).query(client)?;
+
+        let endpoint = Holidays::builder().from(from).to(to).build()?;
+        let holidays: Vec<Holiday> = api::paged(endpoint, Pagination::All).query(client)?;
+
+        calendar.add_vacations(&vacations).add_holidays(&holidays);
+
+        if !self.no_bookings {
+            let mut builder = Bookings::builder();
+            builder.end_date_from(from).start_date_to(to);
+            if let [resource] = self.resource[..] {
+                builder.resource_id(resource);
+            }
+            let bookings: Vec<Booking> =
+                api::paged(builder.build()?, Pagination::All).query(client)?;
+
+            calendar.add_bookings(&bookings);
+        }
+
+        Ok(calendar.to_string())
+    }
+}
@@ -0,0 +1,292 @@
+use std::error::Error;
+
+use chrono::NaiveDate;
+use clap::{Args, Subcommand};
+use serde_json::Value;
+use teamdeck::api::{
+    self,
+    booking::{Booking, Bookings, BookingsSortBy},
+    projects::{Project, Projects, ProjectsSortBy},
+    resources::{Resource, Resources, ResourcesSortBy},
+    sort_by::SortBy,
+    vacations::{Vacation, Vacations, VacationsSortBy},
+    Client, Pagination, Query,
+};
+
+/// Records fetched by a command, with the columns to show in tables and CSV.
+#[derive(Debug)]
+pub struct Fetched {
+    pub columns: &'static [&'static str],
+    pub records: Records,
+    /// A line printed after a table, such as a total.
+    pub footer: Option<String>,
+}
+
+#[derive(Debug)]
+pub enum Records {
+    List(Vec<Value>),
+    One(Value),
+}
+
+const RESOURCE_COLUMNS: &[&str] = &["id", "name", "email", "role", "active"];
+const PROJECT_COLUMNS: &[&str] = &["id", "name", "color", "archived"];
+const BOOKING_COLUMNS: &[&str] = &[
+    "id",
+    "resource_id",
+    "project_id",
+    "start_date",
+    "end_date",
+    "minutes",
+    "description",
+];
+const VACATION_COLUMNS: &[&str] = &[
+    "id",
+    "resource_id",
+    "start_date",
+    "end_date",
+    "status",
+    "description",
+];
+
+fn list<E, C>(
+    endpoint: E,
+    client: &C,
+    columns: &'static [&'static str],
+) -> Result<Fetched, Box<dyn Error>>
+where
+    E: api::Endpoint,
+    api::Paged<E>: Query<Vec<Value>, C>,
+    C: Client,
+{
+    let records = api::paged(endpoint, Pagination::All).query(client)?;
+
+    Ok(Fetched {
+        columns,
+        records: Records::List(records),
+        footer: None,
+    })
+}
+
+fn get<E, C>(
+    endpoint: E,
+    client: &C,
+    columns: &'static [&'static str],
+) -> Result<Fetched, Box<dyn Error>>
+where
+    E: api::Endpoint,
+    C: Client,
+{
+    let record = endpoint.query(client)?;
+
+    Ok(Fetched {
+        columns,
+        records: Records::One(record),
+        footer: None,
+    })
+}
+
+#[derive(Debug, Subcommand)]
+pub enum ResourcesCommand {
+    /// List resources.
+    List(ResourcesList),
+    /// Show a resource.
+    Get { id: usize },
+}
+
+#[derive(Debug, Args)]
+pub struct ResourcesList {
+    /// Only active resources.
+    #[arg(long, conflicts_with = "inactive")]
+    pub active: bool,
+    /// Only inactive resources.
+    #[arg(long)]
+    pub inactive: bool,
+    /// Only resources whose name contains this text.
+    #[arg(long)]
+    pub name: Option<String>,
+    #[arg(long)]
+    pub email: Option<String>,
+    /// The field to sort by, prefixed with `-` for a descending order, e.g. `-name`.
+    #[arg(long, allow_hyphen_values = true)]
+    pub sort: Option<SortBy<ResourcesSortBy>>,
+}
+
+impl ResourcesCommand {
+    pub fn run<C: Client>(&self, client: &C) -> Result<Fetched, Box<dyn Error>> {
+        match self {
+            Self::List(args) => {
+                let mut builder = Resources::builder();
+                if args.active || args.inactive {
+                    builder.active(args.active);
+                }
+                if let Some(name) = &args.name {
+                    builder.name(name.clone());
+                }
+                if let Some(email) = &args.email {
+                    builder.email(email.clone());
+                }
+                if let Some(sort) = args.sort {
+                    builder.sort(sort);
+                }
+
+                list(builder.build()?, client, RESOURCE_COLUMNS)
+            }
+            Self::Get { id } => get(
+                Resource::builder().id(*id).build()?,
+                client,
+                RESOURCE_COLUMNS,
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+pub enum ProjectsCommand {
+    /// List projects.
+    List(ProjectsList),
+    /// Show a project.
+    Get { id: usize },
+}
+
+#[derive(Debug, Args)]
+pub struct ProjectsList {
+    /// Only archived projects.
+    #[arg(long)]
+    pub archived: bool,
+    /// Only projects whose name contains this text.
+    #[arg(long)]
+    pub name: Option<String>,
+    /// The field to sort by, prefixed with `-` for a descending order, e.g. `-name`.
+    #[arg(long, allow_hyphen_values = true)]
+    pub sort: Option<SortBy<ProjectsSortBy>>,
+}
+
+impl ProjectsCommand {
+    pub fn run<C: Client>(&self, client: &C) -> Result<Fetched, Box<dyn Error>> {
+        match self {
+            Self::List(args) => {
+                let mut builder = Projects::builder();
+                if args.archived {
+                    builder.archived(true);
+                }
+                if let Some(name) = &args.name {
+                    builder.name(name.clone());
+                }
+                if let Some(sort) = args.sort {
+                    builder.sort(sort);
+                }
+
+                list(builder.build()?, client, PROJECT_COLUMNS)
+            }
+            Self::Get { id } => get(Project::builder().id(*id).build()?, client, PROJECT_COLUMNS),
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+pub enum BookingsCommand {
+    /// List bookings.
+    List(BookingsList),
+    /// Show a booking.
+    Get { id: usize },
+}
+
+#[derive(Debug, Args)]
+pub struct BookingsList {
+    /// Only bookings which end on or after this date.
+    #[arg(long)]
+    pub from: Option<NaiveDate>,
+    /// Only bookings which start on or before this date.
+    #[arg(long)]
+    pub to: Option<NaiveDate>,
+    /// Only bookings of this project id.
+    #[arg(long)]
+    pub project: Option<u64>,
+    /// Only bookings of this resource id.
+    #[arg(long)]
+    pub resource: Option<u64>,
+    /// The field to sort by, prefixed with `-` for a descending order, e.g. `-start_date`.
+    #[arg(long, allow_hyphen_values = true)]
+    pub sort: Option<SortBy<BookingsSortBy>>,
+}
+
+impl BookingsCommand {
+    pub fn run<C: Client>(&self, client: &C) -> Result<Fetched, Box<dyn Error>> {
+        match self {
+            Self::List(args) => {
+                let mut builder = Bookings::builder();
+                if let Some(from) = args.from {
+                    builder.end_date_from(from);
+                }
+                if let Some(to) = args.to {
+                    builder.start_date_to(to);
+                }
+                if let Some(project) = args.project {
+                    builder.project_id(project);
+                }
+                if let Some(resource) = args.resource {
+                    builder.resource_id(resource);
+                }
+                if let Some(sort) = args.sort {
+                    builder.sort(sort);
+                }
+
+                list(builder.build()?, client, BOOKING_COLUMNS)
+            }
+            Self::Get { id } => get(Booking::builder().id(*id).build()?, client, BOOKING_COLUMNS),
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+pub enum VacationsCommand {
+    /// List vacations.
+    List(VacationsList),
+    /// Show a vacation.
+    Get { id: u64 },
+}
+
+#[derive(Debug, Args)]
+pub struct VacationsList {
+    /// Only vacations which end on or after this date.
+    #[arg(long)]
+    pub from: Option<NaiveDate>,
+    /// Only vacations which start on or before this date.
+    #[arg(long)]
+    pub to: Option<NaiveDate>,
+    /// Only vacations of these resource ids.
+    #[arg(long, value_delimiter = ',')]
+    pub resource: Vec<u64>,
+    /// The field to sort by, prefixed with `-` for a descending order, e.g. `-start_date`.
+    #[arg(long, allow_hyphen_values = true)]
+    pub sort: Option<SortBy<VacationsSortBy>>,
+}
+
+impl VacationsCommand {
+    pub fn run<C: Client>(&self, client: &C) -> Result<Fetched, Box<dyn Error>> {
+        match self {
+            Self::List(args) => {
+                let mut builder = Vacations::builder();
+                if let Some(from) = args.from {
+                    builder.end_date_from(from);
+                }
+                if let Some(to) = args.to {
+                    builder.start_date_to(to);
+                }
+                if !args.resource.is_empty() {
+                    builder.resource_id(args.resource.clone());
+                }
+                if let Some(sort) = args.sort {
+                    builder.sort(sort);
+                }
+
+                list(builder.build()?, client, VACATION_COLUMNS)
+            }
+            Self::Get { id } => get(
+                Vacation::builder().id(*id).build()?,
+                client,
+                VACATION_COLUMNS,
+            ),
+        }
+    }
+}
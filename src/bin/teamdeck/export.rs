@@ -0,0 +1,104 @@
+use std::error::Error;
+use std::io;
+
+use chrono::NaiveDate;
+use clap::{Args, ValueEnum};
+use teamdeck::api::{booking::Bookings, time_entries::TimeEntries, vacations::Vacations, Client};
+use teamdeck::export::{
+    CsvExport, DurationFormat, Names, BOOKING_COLUMNS, TIME_ENTRY_COLUMNS, VACATION_COLUMNS,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Exported {
+    TimeEntries,
+    Bookings,
+    Vacations,
+}
+
+#[derive(Debug, Args)]
+pub struct ExportArgs {
+    /// What to export.
+    #[arg(value_enum)]
+    pub exported: Exported,
+    /// Only records on or after this date.
+    #[arg(long)]
+    pub from: Option<NaiveDate>,
+    /// Only records on or before this date.
+    #[arg(long)]
+    pub to: Option<NaiveDate>,
+    /// The columns to write, e.g. `start_date,resource,project,minutes`. `resource` and `project`
+    /// are names rather than ids.
+    #[arg(long, value_delimiter = ',')]
+    pub columns: Vec<String>,
+    /// How to write dates, e.g. `%d.%m.%Y`.
+    #[arg(long)]
+    pub date_format: Option<String>,
+    /// How to write minutes: `minutes`, `hours` or `hours-minutes`.
+    #[arg(long, default_value = "minutes")]
+    pub durations: DurationFormat,
+}
+
+impl ExportArgs {
+    fn export(&self, columns: &[&str]) -> Result<CsvExport, Box<dyn Error>> {
+        let mut export = if self.columns.is_empty() {
+            CsvExport::new(columns.iter().copied())
+        } else {
+            CsvExport::new(&self.columns)
+        }
+        .durations(self.durations);
+        if let Some(format) = &self.date_format {
+            export = export.date_format(format)?;
+        }
+
+        Ok(export)
+    }
+
+    /// Write the records as CSV to standard output, returning how many were written.
+    pub fn run<C: Client>(&self, client: &C) -> Result<u64, Box<dyn Error>> {
+        let out = io::stdout().lock();
+
+        let count = match self.exported {
+            Exported::TimeEntries => {
+                let mut builder = TimeEntries::builder();
+                if let Some(from) = self.from {
+                    builder.start_date_from(from);
+                }
+                if let Some(to) = self.to {
+                    builder.start_date_to(to);
+                }
+
+                self.export(TIME_ENTRY_COLUMNS)?
+                    .names(Names::fetch(client)?)
+                    .write(builder.build()?, client, out)?
+            }
+            Exported::Bookings => {
+                let mut builder = Bookings::builder();
+                if let Some(from) = self.from {
+                    builder.end_date_from(from);
+                }
+                if let Some(to) = self.to {
+                    builder.start_date_to(to);
+                }
+
+                self.export(BOOKING_COLUMNS)?
+                    .names(Names::fetch(client)?)
+                    .write(builder.build()?, client, out)?
+            }
+            Exported::Vacations => {
+                let mut builder = Vacations::builder();
+                if let Some(from) = self.from {
+                    builder.end_date_from(from);
+                }
+                if let Some(to) = self.to {
+                    builder.start_date_to(to);
+                }
+
+                self.export(VACATION_COLUMNS)?
+                    .names(Names::fetch(client)?)
+                    .write(builder.build()?, client, out)?
+            }
+        };
+
+        Ok(count)
+    }
+}
@@ -0,0 +1,196 @@
+use std::error::Error;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::PathBuf;
+
+use clap::{Args, ValueEnum};
+use serde_json::{json, Value};
+use teamdeck::api::{Client, UpsertOutcome};
+use teamdeck::import::{self, Change, Directory, Field, Importer, Mapping};
+
+use crate::output::{self, Format};
+
+const PREVIEW_COLUMNS: &[&str] = &[
+    "line",
+    "action",
+    "start_date",
+    "resource_id",
+    "project_id",
+    "minutes",
+    "changes",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum InputFormat {
+    Csv,
+    /// JSON Lines, one object per line.
+    Jsonl,
+}
+
+/// Parse a `field=column` mapping.
+fn parse_mapping(value: &str) -> Result<(Field, String), String> {
+    let (field, column) = value
+        .split_once('=')
+        .ok_or_else(|| format!("invalid mapping `{}`, expected e.g. start_date=Day", value))?;
+
+    Ok((
+        field.parse().map_err(|err| format!("{}", err))?,
+        column.into(),
+    ))
+}
+
+#[derive(Debug, Args)]
+pub struct ImportArgs {
+    /// The CSV or JSON Lines file of time entries.
+    pub file: PathBuf,
+    /// The format of the file, guessed from its extension by default.
+    #[arg(long, value_enum)]
+    pub input: Option<InputFormat>,
+    /// Read a field from another column, e.g. `start_date=Day`. The fields are email,
+    /// resource_id, project, project_id, start_date, end_date, minutes, hours, description and
+    /// external_id.
+    #[arg(long = "map", value_parser = parse_mapping)]
+    pub mappings: Vec<(Field, String)>,
+    /// Import without asking for confirmation.
+    #[arg(long, short)]
+    pub yes: bool,
+}
+
+impl ImportArgs {
+    fn rows(&self) -> Result<Vec<import::SourceRow>, Box<dyn Error>> {
+        let input = self.input.unwrap_or_else(|| {
+            match self
+                .file
+                .extension()
+                .and_then(|extension| extension.to_str())
+            {
+                Some("jsonl" | "ndjson") => InputFormat::Jsonl,
+                _ => InputFormat::Csv,
+            }
+        });
+        let file = File::open(&self.file)?;
+
+        Ok(match input {
+            InputFormat::Csv => import::read_csv(file)?,
+            InputFormat::Jsonl => import::read_json_lines(BufReader::new(file))?,
+        })
+    }
+
+    fn confirm(&self, count: usize) -> io::Result<bool> {
+        if self.yes {
+            return Ok(true);
+        }
+
+        eprint!("Import {} time entries? [y/N] ", count);
+        io::stderr().flush()?;
+        let mut answer = String::new();
+        io::stdin().lock().read_line(&mut answer)?;
+
+        Ok(matches!(answer.trim(), "y" | "Y" | "yes"))
+    }
+
+    /// Validate the file, show what would change, and import it once confirmed.
+    pub fn run<C: Client>(&self, client: &C, format: Format) -> Result<(), Box<dyn Error>> {
+        let mapping = self
+            .mappings
+            .iter()
+            .fold(Mapping::default(), |mapping, (field, column)| {
+                mapping.column(*field, column)
+            });
+        let importer = Importer::new(mapping, Directory::fetch(client)?);
+
+        let validated = importer.validate(&self.rows()?);
+        if !validated.errors.is_empty() {
+            for error in &validated.errors {
+                eprintln!("{}", error);
+            }
+            return Err(format!(
+                "{} problems found, nothing was imported",
+                validated.errors.len()
+            )
+            .into());
+        }
+
+        let changes = import::preview(&validated.entries, client)?;
+        let preview = validated
+            .entries
+            .iter()
+            .zip(&changes)
+            .map(|(entry, change)| {
+                let (action, changes) = match change {
+                    Change::Create => ("create", String::new()),
+                    Change::Update { fields, .. } => {
+                        let fields = fields
+                            .iter()
+                            .map(|field| format!("{}: {} -> {}", field.field, field.old, field.new))
+                            .collect::<Vec<_>>();
+                        ("update", fields.join(", "))
+                    }
+                    Change::Unchanged { .. } => ("unchanged", String::new()),
+                };
+
+                json!({
+                    "line": entry.line,
+                    "action": action,
+                    "start_date": entry.start_date,
+                    "resource_id": entry.resource_id,
+                    "project_id": entry.project_id,
+                    "minutes": entry.minutes,
+                    "external_id": entry.external_id,
+                    "changes": changes,
+                })
+            })
+            .collect::<Vec<Value>>();
+        output::write_records(io::stdout().lock(), format, PREVIEW_COLUMNS, &preview)?;
+
+        let pending = validated
+            .entries
+            .into_iter()
+            .zip(&changes)
+            .filter(|(_, change)| !matches!(change, Change::Unchanged { .. }))
+            .map(|(entry, _)| entry)
+            .collect::<Vec<_>>();
+        if pending.is_empty() {
+            eprintln!("Everything is already imported");
+            return Ok(());
+        }
+        if !self.confirm(pending.len())? {
+            eprintln!("Nothing was imported");
+            return Ok(());
+        }
+
+        let (mut created, mut updated, mut failed) = (0, 0, 0);
+        for (entry, result) in pending.iter().zip(import::apply(&pending, client)) {
+            match result {
+                Ok(upserted) if upserted.outcome == UpsertOutcome::Created => created += 1,
+                Ok(_) => updated += 1,
+                Err(err) => {
+                    eprintln!("line {}: {}", entry.line, err);
+                    failed += 1;
+                }
+            }
+        }
+        eprintln!("Created {}, updated {} time entries", created, updated);
+
+        if failed > 0 {
+            return Err(format!("{} time entries failed to import", failed).into());
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_mappings() {
+        assert_eq!(
+            parse_mapping("start_date=Day"),
+            Ok((Field::StartDate, "Day".into()))
+        );
+        assert!(parse_mapping("date=Day").is_err());
+        assert!(parse_mapping("start_date").is_err());
+    }
+}
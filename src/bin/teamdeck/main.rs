@@ -0,0 +1,243 @@
+//! A command-line client for the Teamdeck API.
+
+mod calendar;
+mod entities;
+mod export;
+mod import;
+mod output;
+mod time;
+#[cfg(feature = "tui")]
+mod timesheet;
+
+use std::error::Error;
+use std::io::{self, Write};
+use std::process::ExitCode;
+
+use clap::{Parser, Subcommand};
+use teamdeck::Teamdeck;
+
+use crate::calendar::CalendarArgs;
+use crate::entities::{
+    BookingsCommand, Fetched, ProjectsCommand, Records, ResourcesCommand, VacationsCommand,
+};
+use crate::export::ExportArgs;
+use crate::import::ImportArgs;
+use crate::output::Format;
+use crate::time::{EditArgs, LogArgs, WeekArgs};
+#[cfg(feature = "tui")]
+use crate::timesheet::TimesheetArgs;
+
+#[derive(Debug, Parser)]
+#[command(name = "teamdeck", version, about = "Query the Teamdeck API")]
+struct Cli {
+    /// The Teamdeck API key.
+    #[arg(long, env = "TEAMDECK_API_KEY", hide_env_values = true, global = true)]
+    token: Option<String>,
+    /// How to print the results.
+    #[arg(long, short, value_enum, default_value_t, global = true)]
+    format: Format,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// People and other resources which can be booked.
+    #[command(subcommand)]
+    Resources(ResourcesCommand),
+    #[command(subcommand)]
+    Projects(ProjectsCommand),
+    #[command(subcommand)]
+    Bookings(BookingsCommand),
+    #[command(subcommand, alias = "vacation")]
+    Vacations(VacationsCommand),
+    /// Log time spent on a project.
+    Log(LogArgs),
+    /// Show your time entries of a week.
+    Week(WeekArgs),
+    /// Change a time entry.
+    Edit(EditArgs),
+    /// Delete a time entry.
+    #[command(alias = "delete")]
+    Rm { id: usize },
+    /// Write time entries, bookings or vacations as CSV, e.g. for finance.
+    Export(ExportArgs),
+    /// Create time entries from a CSV or JSON Lines file, after showing what would change.
+    Import(ImportArgs),
+    /// Write vacations, holidays and bookings as an iCalendar feed.
+    Calendar(CalendarArgs),
+    /// Enter the time of a week in a full-screen grid of projects and days.
+    #[cfg(feature = "tui")]
+    Timesheet(TimesheetArgs),
+}
+
+fn run(cli: Cli) -> Result<(), Box<dyn Error>> {
+    let token = cli
+        .token
+        .ok_or("missing API key: set TEAMDECK_API_KEY or pass --token")?;
+    let client = Teamdeck::new(token);
+
+    let Fetched {
+        columns,
+        records,
+        footer,
+    } = match &cli.command {
+        Command::Resources(command) => command.run(&client)?,
+        Command::Projects(command) => command.run(&client)?,
+        Command::Bookings(command) => command.run(&client)?,
+        Command::Vacations(command) => command.run(&client)?,
+        Command::Log(args) => args.run(&client)?,
+        Command::Week(args) => args.run(&client)?,
+        Command::Edit(args) => args.run(&client)?,
+        Command::Rm { id } => {
+            time::remove(&client, *id)?;
+            println!("Deleted time entry {}", id);
+            return Ok(());
+        }
+        Command::Export(args) => {
+            let count = args.run(&client)?;
+            eprintln!("Exported {} records", count);
+            return Ok(());
+        }
+        Command::Import(args) => return args.run(&client, cli.format),
+        Command::Calendar(args) => {
+            print!("{}", args.run(&client)?);
+            return Ok(());
+        }
+        #[cfg(feature = "tui")]
+        Command::Timesheet(args) => return args.run(&client),
+    };
+
+    let mut out = io::stdout().lock();
+    match records {
+        Records::List(records) => output::write_records(&mut out, cli.format, columns, &records)?,
+        Records::One(record) => output::write_record(&mut out, cli.format, columns, &record)?,
+    }
+    if let (Format::Table, Some(footer)) = (cli.format, footer) {
+        writeln!(out, "\n{}", footer)?;
+    }
+
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    match run(Cli::parse()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::CommandFactory;
+    use teamdeck::api::{resources::ResourcesSortBy, sort_by::SortBy};
+    use teamdeck::export::DurationFormat;
+
+    use super::*;
+
+    #[test]
+    fn verify_cli() {
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn parses_descending_sort() {
+        let cli = Cli::try_parse_from([
+            "teamdeck",
+            "resources",
+            "list",
+            "--active",
+            "--sort",
+            "-name",
+        ])
+        .unwrap();
+
+        if let Command::Resources(ResourcesCommand::List(args)) = cli.command {
+            assert_eq!(args.sort, Some(SortBy::Desc(ResourcesSortBy::Name)));
+        } else {
+            panic!("unexpected command: {:?}", cli.command);
+        }
+    }
+
+    #[test]
+    fn rejects_unknown_sort_field() {
+        let result = Cli::try_parse_from(["teamdeck", "resources", "list", "--sort", "-colour"]);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn vacation_is_an_alias() {
+        let cli = Cli::try_parse_from(["teamdeck", "vacation", "get", "42", "-f", "json"]).unwrap();
+
+        assert!(matches!(
+            cli.command,
+            Command::Vacations(VacationsCommand::Get { id: 42 })
+        ));
+        assert_eq!(cli.format, Format::Json);
+    }
+
+    #[test]
+    fn parses_export_command() {
+        let cli = Cli::try_parse_from([
+            "teamdeck",
+            "export",
+            "time-entries",
+            "--from",
+            "2026-01-01",
+            "--columns",
+            "start_date,resource,minutes",
+            "--durations",
+            "hours",
+        ])
+        .unwrap();
+
+        if let Command::Export(args) = cli.command {
+            assert_eq!(args.columns, vec!["start_date", "resource", "minutes"]);
+            assert_eq!(args.durations, DurationFormat::Hours);
+        } else {
+            panic!("unexpected command: {:?}", cli.command);
+        }
+    }
+
+    #[test]
+    fn parses_log_command() {
+        let cli = Cli::try_parse_from([
+            "teamdeck",
+            "log",
+            "1h30m",
+            "--project",
+            "api",
+            "--note",
+            "review",
+            "--email",
+            "ada@example.com",
+        ])
+        .unwrap();
+
+        if let Command::Log(args) = cli.command {
+            assert_eq!(args.duration, 90);
+            assert_eq!(args.project, "api");
+            assert_eq!(args.note.as_deref(), Some("review"));
+        } else {
+            panic!("unexpected command: {:?}", cli.command);
+        }
+    }
+
+    #[test]
+    fn edit_requires_a_change() {
+        let result = Cli::try_parse_from(["teamdeck", "edit", "7"]);
+        assert!(result.is_err());
+
+        let cli = Cli::try_parse_from(["teamdeck", "edit", "7", "--clear-note"]).unwrap();
+        if let Command::Edit(args) = cli.command {
+            assert_eq!(args.id, 7);
+            assert!(args.clear_note);
+        } else {
+            panic!("unexpected command: {:?}", cli.command);
+        }
+    }
+}
@@ -0,0 +1,167 @@
+use std::error::Error;
+use std::io::Write;
+
+use clap::ValueEnum;
+use serde_json::Value;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum Format {
+    /// Aligned columns for reading in a terminal.
+    #[default]
+    Table,
+    /// The records as Teamdeck returned them.
+    Json,
+    /// Comma separated values with a header row.
+    Csv,
+}
+
+/// The text of a field in a table or CSV cell.
+fn cell(record: &Value, column: &str) -> String {
+    match record.get(column) {
+        None | Some(Value::Null) => String::new(),
+        Some(Value::String(value)) => value.clone(),
+        Some(value) => value.to_string(),
+    }
+}
+
+fn write_table<W: Write>(out: &mut W, columns: &[&str], records: &[Value]) -> std::io::Result<()> {
+    let rows = records
+        .iter()
+        .map(|record| {
+            columns
+                .iter()
+                .map(|column| cell(record, column))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    let widths = columns
+        .iter()
+        .enumerate()
+        .map(|(index, column)| {
+            rows.iter()
+                .map(|row| row[index].chars().count())
+                .chain(Some(column.len()))
+                .max()
+                .unwrap_or_default()
+        })
+        .collect::<Vec<_>>();
+
+    let header = columns
+        .iter()
+        .map(|column| column.to_uppercase())
+        .collect::<Vec<_>>();
+
+    for row in Some(header).into_iter().chain(rows) {
+        let line = row
+            .iter()
+            .zip(&widths)
+            .map(|(value, width)| format!("{:width$}", value, width = width))
+            .collect::<Vec<_>>()
+            .join("  ");
+        writeln!(out, "{}", line.trim_end())?;
+    }
+
+    Ok(())
+}
+
+fn write_csv<W: Write>(out: W, columns: &[&str], records: &[Value]) -> csv::Result<()> {
+    let mut writer = csv::Writer::from_writer(out);
+    writer.write_record(columns)?;
+    for record in records {
+        writer.write_record(columns.iter().map(|column| cell(record, column)))?;
+    }
+    writer.flush()?;
+
+    Ok(())
+}
+
+/// Write records in a format. Tables and CSV only show `columns`, JSON shows every field.
+pub fn write_records<W: Write>(
+    mut out: W,
+    format: Format,
+    columns: &[&str],
+    records: &[Value],
+) -> Result<(), Box<dyn Error>> {
+    match format {
+        Format::Table => write_table(&mut out, columns, records)?,
+        Format::Json => {
+            serde_json::to_writer_pretty(&mut out, records)?;
+            writeln!(out)?;
+        }
+        Format::Csv => write_csv(out, columns, records)?,
+    }
+
+    Ok(())
+}
+
+/// Write a single record, as an object rather than an array for JSON.
+pub fn write_record<W: Write>(
+    mut out: W,
+    format: Format,
+    columns: &[&str],
+    record: &Value,
+) -> Result<(), Box<dyn Error>> {
+    match format {
+        Format::Json => {
+            serde_json::to_writer_pretty(&mut out, record)?;
+            writeln!(out)?;
+            Ok(())
+        }
+        _ => write_records(out, format, columns, std::slice::from_ref(record)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn records() -> Vec<Value> {
+        vec![
+            json!({ "id": 1, "name": "Ada Lovelace", "active": true }),
+            json!({ "id": 12, "name": "Grace, Hopper", "active": false, "role": null }),
+        ]
+    }
+
+    fn written(format: Format) -> String {
+        let mut out = Vec::new();
+        write_records(&mut out, format, &["id", "name", "role"], &records()).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn aligns_table_columns() {
+        assert_eq!(
+            written(Format::Table),
+            "ID  NAME           ROLE\n\
+             1   Ada Lovelace\n\
+             12  Grace, Hopper\n"
+        );
+    }
+
+    #[test]
+    fn quotes_csv_values() {
+        assert_eq!(
+            written(Format::Csv),
+            "id,name,role\n1,Ada Lovelace,\n12,\"Grace, Hopper\",\n"
+        );
+    }
+
+    #[test]
+    fn json_keeps_every_field() {
+        let written: Value = serde_json::from_str(&written(Format::Json)).unwrap();
+
+        assert_eq!(written, Value::Array(records()));
+    }
+
+    #[test]
+    fn single_record_json_is_an_object() {
+        let mut out = Vec::new();
+        write_record(&mut out, Format::Json, &["id"], &records()[0]).unwrap();
+
+        let written: Value = serde_json::from_slice(&out).unwrap();
+        assert_eq!(written, records()[0]);
+    }
+}
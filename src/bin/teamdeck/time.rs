@@ -0,0 +1,317 @@
+use std::collections::HashMap;
+use std::error::Error;
+
+use chrono::{Datelike, Duration, Local, NaiveDate};
+use clap::{ArgGroup, Args};
+use serde_json::Value;
+use teamdeck::api::{
+    self,
+    projects::Projects,
+    resources::Resources,
+    sort_by::SortBy,
+    time_entries::{
+        CreateTimeEntry, DeleteTimeEntry, PartialUpdateTimeEntry, TimeEntries, TimeEntriesSortBy,
+    },
+    Client, Pagination, Query,
+};
+
+use crate::entities::{Fetched, Records};
+
+const TIME_ENTRY_COLUMNS: &[&str] = &["id", "start_date", "project_id", "minutes", "description"];
+const WEEK_COLUMNS: &[&str] = &["id", "start_date", "project", "time", "description"];
+
+/// Parse a duration such as `2h`, `45m`, `1h30m` or `1.5h` into minutes. A number without a
+/// unit is minutes.
+pub fn parse_minutes(value: &str) -> Result<u64, String> {
+    let invalid = || {
+        format!(
+            "invalid duration `{}`, expected e.g. 2h, 45m or 1h30m",
+            value
+        )
+    };
+
+    let mut minutes = 0.0;
+    let mut number = String::new();
+    for c in value.trim().chars() {
+        match c {
+            '0'..='9' | '.' => number.push(c),
+            'h' | 'm' => {
+                let amount = number.parse::<f64>().map_err(|_| invalid())?;
+                minutes += if c == 'h' { amount * 60.0 } else { amount };
+                number.clear();
+            }
+            _ => return Err(invalid()),
+        }
+    }
+    if !number.is_empty() {
+        minutes += number.parse::<f64>().map_err(|_| invalid())?;
+    }
+
+    let minutes = minutes.round() as u64;
+    if minutes == 0 {
+        return Err(invalid());
+    }
+
+    Ok(minutes)
+}
+
+/// Format minutes as hours and minutes, e.g. `1h 30m`.
+pub fn format_minutes(minutes: u64) -> String {
+    match (minutes / 60, minutes % 60) {
+        (0, minutes) => format!("{}m", minutes),
+        (hours, 0) => format!("{}h", hours),
+        (hours, minutes) => format!("{}h {}m", hours, minutes),
+    }
+}
+
+/// The Monday and Sunday of the week of a date.
+pub fn week_of(date: NaiveDate) -> (NaiveDate, NaiveDate) {
+    let monday = date - Duration::days(date.weekday().num_days_from_monday().into());
+
+    (monday, monday + Duration::days(6))
+}
+
+/// The id of the resource with an email address.
+pub fn resource_id<C: Client>(client: &C, email: &str) -> Result<u64, Box<dyn Error>> {
+    let endpoint = Resources::builder().email(email).build()?;
+    let resources: Vec<Value> = api::paged(endpoint, Pagination::All).query(client)?;
+
+    resources
+        .iter()
+        .find(|resource| {
+            resource["email"]
+                .as_str()
+                .is_some_and(|found| found.eq_ignore_ascii_case(email))
+        })
+        .and_then(|resource| resource["id"].as_u64())
+        .ok_or_else(|| format!("no resource with the email `{}`", email).into())
+}
+
+/// The id of a project given by its id or (a unique part of) its name.
+fn project_id<C: Client>(client: &C, project: &str) -> Result<u64, Box<dyn Error>> {
+    if let Ok(id) = project.parse() {
+        return Ok(id);
+    }
+
+    let endpoint = Projects::builder().name(project).build()?;
+    let projects: Vec<Value> = api::paged(endpoint, Pagination::All).query(client)?;
+    let name = |project: &Value| project["name"].as_str().unwrap_or_default().to_owned();
+
+    let exact = projects
+        .iter()
+        .find(|found| name(found).eq_ignore_ascii_case(project));
+    let found = match (exact, projects.as_slice()) {
+        (Some(found), _) | (None, [found]) => found,
+        (None, []) => return Err(format!("no project named `{}`", project).into()),
+        (None, _) => {
+            let names = projects.iter().map(name).collect::<Vec<_>>();
+            return Err(format!(
+                "`{}` matches several projects: {}",
+                project,
+                names.join(", ")
+            )
+            .into());
+        }
+    };
+
+    found["id"]
+        .as_u64()
+        .ok_or_else(|| "the project has no id".into())
+}
+
+#[derive(Debug, Args)]
+pub struct Me {
+    /// Your email address in Teamdeck, to find your resource.
+    #[arg(long, env = "TEAMDECK_EMAIL")]
+    pub email: String,
+}
+
+#[derive(Debug, Args)]
+pub struct LogArgs {
+    /// The time spent, e.g. `2h`, `45m` or `1h30m`.
+    #[arg(value_parser = parse_minutes)]
+    pub duration: u64,
+    /// The project id or name.
+    #[arg(long, short)]
+    pub project: String,
+    /// What the time was spent on.
+    #[arg(long, short)]
+    pub note: Option<String>,
+    /// The day the time was spent, today by default.
+    #[arg(long, short)]
+    pub date: Option<NaiveDate>,
+    #[command(flatten)]
+    pub me: Me,
+}
+
+impl LogArgs {
+    pub fn run<C: Client>(&self, client: &C) -> Result<Fetched, Box<dyn Error>> {
+        let date = self.date.unwrap_or_else(|| Local::now().date_naive());
+
+        let mut builder = CreateTimeEntry::builder();
+        builder
+            .resource_id(resource_id(client, &self.me.email)?)
+            .project_id(project_id(client, &self.project)?)
+            .minutes(self.duration)
+            .start_date(date)
+            .end_date(date);
+        if let Some(note) = &self.note {
+            builder.description(Some(note.as_str().into()));
+        }
+
+        Ok(Fetched {
+            columns: TIME_ENTRY_COLUMNS,
+            records: Records::One(builder.build()?.query(client)?),
+            footer: None,
+        })
+    }
+}
+
+#[derive(Debug, Args)]
+pub struct WeekArgs {
+    /// Any day of the week to show, today by default.
+    #[arg(long, short)]
+    pub date: Option<NaiveDate>,
+    #[command(flatten)]
+    pub me: Me,
+}
+
+impl WeekArgs {
+    pub fn run<C: Client>(&self, client: &C) -> Result<Fetched, Box<dyn Error>> {
+        let (monday, sunday) = week_of(self.date.unwrap_or_else(|| Local::now().date_naive()));
+
+        let endpoint = TimeEntries::builder()
+            .resource_id(vec![resource_id(client, &self.me.email)?])
+            .start_date_from(monday)
+            .start_date_to(sunday)
+            .sort(SortBy::Asc(TimeEntriesSortBy::StartDate))
+            .build()?;
+        let mut entries: Vec<Value> = api::paged(endpoint, Pagination::All).query(client)?;
+
+        let projects: Vec<Value> =
+            api::paged(Projects::builder().build()?, Pagination::All).query(client)?;
+        let project_names = projects
+            .iter()
+            .filter_map(|project| Some((project["id"].as_u64()?, project["name"].clone())))
+            .collect::<HashMap<_, _>>();
+
+        let mut total = 0;
+        for entry in &mut entries {
+            let minutes = entry["minutes"].as_u64().unwrap_or_default();
+            total += minutes;
+
+            let project = entry["project_id"]
+                .as_u64()
+                .and_then(|id| project_names.get(&id))
+                .cloned()
+                .unwrap_or_else(|| entry["project_id"].clone());
+            entry["project"] = project;
+            entry["time"] = format_minutes(minutes).into();
+        }
+
+        Ok(Fetched {
+            columns: WEEK_COLUMNS,
+            records: Records::List(entries),
+            footer: Some(format!("Week of {}: {}", monday, format_minutes(total))),
+        })
+    }
+}
+
+#[derive(Debug, Args)]
+#[command(group(
+    ArgGroup::new("changes")
+        .args(["duration", "project", "note", "clear_note", "date"])
+        .required(true)
+        .multiple(true)
+))]
+pub struct EditArgs {
+    /// The id of the time entry.
+    pub id: u64,
+    /// The new time spent, e.g. `2h`, `45m` or `1h30m`.
+    #[arg(value_parser = parse_minutes)]
+    pub duration: Option<u64>,
+    /// The new project id or name.
+    #[arg(long, short)]
+    pub project: Option<String>,
+    /// The new note.
+    #[arg(long, short, conflicts_with = "clear_note")]
+    pub note: Option<String>,
+    /// Remove the note.
+    #[arg(long)]
+    pub clear_note: bool,
+    /// The new day the time was spent.
+    #[arg(long, short)]
+    pub date: Option<NaiveDate>,
+}
+
+impl EditArgs {
+    pub fn run<C: Client>(&self, client: &C) -> Result<Fetched, Box<dyn Error>> {
+        let mut builder = PartialUpdateTimeEntry::builder();
+        builder.id(self.id);
+        if let Some(minutes) = self.duration {
+            builder.minutes(minutes);
+        }
+        if let Some(project) = &self.project {
+            builder.project_id(project_id(client, project)?);
+        }
+        if let Some(note) = &self.note {
+            builder.description(note.as_str());
+        }
+        if self.clear_note {
+            builder.clear_description();
+        }
+        if let Some(date) = self.date {
+            builder.start_date(date).end_date(date);
+        }
+
+        Ok(Fetched {
+            columns: TIME_ENTRY_COLUMNS,
+            records: Records::One(builder.build()?.query(client)?),
+            footer: None,
+        })
+    }
+}
+
+/// Delete a time entry.
+pub fn remove<C: Client>(client: &C, id: usize) -> Result<(), Box<dyn Error>> {
+    api::ignore(DeleteTimeEntry::builder().id(id).build()?).query(client)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_durations() {
+        assert_eq!(parse_minutes("2h"), Ok(120));
+        assert_eq!(parse_minutes("45m"), Ok(45));
+        assert_eq!(parse_minutes("1h30m"), Ok(90));
+        assert_eq!(parse_minutes("1.5h"), Ok(90));
+        assert_eq!(parse_minutes("90"), Ok(90));
+    }
+
+    #[test]
+    fn rejects_invalid_durations() {
+        for duration in ["", "0h", "2 hours", "h", "1..5h"] {
+            assert!(parse_minutes(duration).is_err(), "{}", duration);
+        }
+    }
+
+    #[test]
+    fn formats_minutes() {
+        assert_eq!(format_minutes(45), "45m");
+        assert_eq!(format_minutes(120), "2h");
+        assert_eq!(format_minutes(570), "9h 30m");
+    }
+
+    #[test]
+    fn week_starts_on_monday() {
+        let date = |day| NaiveDate::from_ymd_opt(2026, 1, day).unwrap();
+
+        assert_eq!(week_of(date(7)), (date(5), date(11)));
+        assert_eq!(week_of(date(5)), (date(5), date(11)));
+        assert_eq!(week_of(date(11)), (date(5), date(11)));
+    }
+}
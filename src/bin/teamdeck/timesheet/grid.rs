@@ -0,0 +1,370 @@
+use std::collections::{HashMap, HashSet};
+
+use chrono::{Duration, NaiveDate};
+use teamdeck::api::time_entries::{
+    CreateTimeEntry, DeleteTimeEntry, PartialUpdateTimeEntry, TimeEntryOperation,
+};
+use teamdeck::models::TimeEntry;
+
+pub const DAYS: usize = 7;
+
+/// The time of one project on one day.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Cell {
+    /// The saved time entries, as ids and minutes.
+    entries: Vec<(u64, u64)>,
+    /// The minutes shown, which are saved when they differ from the entries.
+    pub minutes: u64,
+}
+
+impl Cell {
+    fn saved(&self) -> u64 {
+        self.entries.iter().map(|(_, minutes)| minutes).sum()
+    }
+
+    pub fn is_changed(&self) -> bool {
+        self.minutes != self.saved()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Row {
+    pub project_id: u64,
+    pub project: String,
+    pub cells: [Cell; DAYS],
+}
+
+/// A week of time entries of one resource, as projects by days.
+#[derive(Debug, Clone)]
+pub struct Grid {
+    monday: NaiveDate,
+    pub rows: Vec<Row>,
+    /// Holiday names by date.
+    pub holidays: HashMap<NaiveDate, String>,
+    /// The days the resource is on vacation.
+    pub vacations: HashSet<NaiveDate>,
+    /// The selected row and day.
+    pub cursor: (usize, usize),
+}
+
+impl Grid {
+    /// Arrange the entries of the week starting on `monday` by project, named with
+    /// `project_names`.
+    pub fn new(
+        monday: NaiveDate,
+        entries: &[TimeEntry],
+        project_names: &HashMap<u64, String>,
+    ) -> Self {
+        let mut grid = Self {
+            monday,
+            rows: Vec::new(),
+            holidays: HashMap::new(),
+            vacations: HashSet::new(),
+            cursor: (0, 0),
+        };
+
+        for entry in entries {
+            let day = (entry.start_date - monday).num_days();
+            if !(0..DAYS as i64).contains(&day) {
+                continue;
+            }
+
+            let name = project_names
+                .get(&entry.project_id)
+                .cloned()
+                .unwrap_or_else(|| format!("#{}", entry.project_id));
+            let row = grid.row_index(entry.project_id, name);
+            let cell = &mut grid.rows[row].cells[day as usize];
+            cell.entries.push((entry.id, entry.minutes));
+            cell.minutes += entry.minutes;
+        }
+
+        grid.rows.sort_by(|a, b| a.project.cmp(&b.project));
+
+        grid
+    }
+
+    fn row_index(&mut self, project_id: u64, project: String) -> usize {
+        match self
+            .rows
+            .iter()
+            .position(|row| row.project_id == project_id)
+        {
+            Some(index) => index,
+            None => {
+                self.rows.push(Row {
+                    project_id,
+                    project,
+                    cells: Default::default(),
+                });
+                self.rows.len() - 1
+            }
+        }
+    }
+
+    pub fn monday(&self) -> NaiveDate {
+        self.monday
+    }
+
+    pub fn day(&self, index: usize) -> NaiveDate {
+        self.monday + Duration::days(index as i64)
+    }
+
+    /// Add a row for a project, unless it has one, and select it.
+    pub fn add_project(&mut self, project_id: u64, project: String) {
+        let row = self.row_index(project_id, project);
+        self.cursor.0 = row;
+    }
+
+    pub fn move_cursor(&mut self, rows: isize, days: isize) {
+        let clamp = |value: usize, delta: isize, len: usize| {
+            value
+                .saturating_add_signed(delta)
+                .min(len.saturating_sub(1))
+        };
+
+        self.cursor = (
+            clamp(self.cursor.0, rows, self.rows.len()),
+            clamp(self.cursor.1, days, DAYS),
+        );
+    }
+
+    pub fn selected(&self) -> Option<&Cell> {
+        self.rows
+            .get(self.cursor.0)
+            .map(|row| &row.cells[self.cursor.1])
+    }
+
+    /// Set the minutes of the selected cell.
+    pub fn set_selected(&mut self, minutes: u64) {
+        let (row, day) = self.cursor;
+        if let Some(row) = self.rows.get_mut(row) {
+            row.cells[day].minutes = minutes;
+        }
+    }
+
+    pub fn day_total(&self, day: usize) -> u64 {
+        self.rows.iter().map(|row| row.cells[day].minutes).sum()
+    }
+
+    pub fn row_total(row: &Row) -> u64 {
+        row.cells.iter().map(|cell| cell.minutes).sum()
+    }
+
+    pub fn has_changes(&self) -> bool {
+        self.rows
+            .iter()
+            .flat_map(|row| &row.cells)
+            .any(Cell::is_changed)
+    }
+
+    /// Type the changed cells of `edited` into this grid again, e.g. after reloading the week
+    /// when some of them could not be saved. Cells saved since are no longer changed.
+    pub fn keep_changes(&mut self, edited: &Grid) {
+        for row in &edited.rows {
+            for (day, cell) in row.cells.iter().enumerate() {
+                if cell.is_changed() {
+                    let index = self.row_index(row.project_id, row.project.clone());
+                    self.rows[index].cells[day].minutes = cell.minutes;
+                }
+            }
+        }
+    }
+
+    /// The writes which save the changed cells.
+    ///
+    /// A cleared cell deletes its entries, and a new cell creates an entry. A changed cell
+    /// updates its first entry to the new time, deleting any other entries of the day.
+    pub fn changes(&self, resource_id: u64) -> Vec<TimeEntryOperation<'static>> {
+        let mut operations = Vec::new();
+
+        for row in &self.rows {
+            for (day, cell) in row.cells.iter().enumerate() {
+                if !cell.is_changed() {
+                    continue;
+                }
+
+                let mut entries = cell.entries.iter().map(|(id, _)| *id);
+                if cell.minutes > 0 {
+                    let date = self.day(day);
+                    let operation = match entries.next() {
+                        Some(id) => PartialUpdateTimeEntry::builder()
+                            .id(id)
+                            .minutes(cell.minutes)
+                            .build()
+                            .unwrap()
+                            .into(),
+                        None => CreateTimeEntry::builder()
+                            .resource_id(resource_id)
+                            .project_id(row.project_id)
+                            .minutes(cell.minutes)
+                            .start_date(date)
+                            .end_date(date)
+                            .build()
+                            .unwrap()
+                            .into(),
+                    };
+                    operations.push(operation);
+                }
+
+                operations.extend(entries.map(|id| {
+                    DeleteTimeEntry::builder()
+                        .id(id as usize)
+                        .build()
+                        .unwrap()
+                        .into()
+                }));
+            }
+        }
+
+        operations
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+    use teamdeck::api::Endpoint;
+
+    use super::*;
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 1, day).unwrap()
+    }
+
+    fn entry(id: u64, project_id: u64, day: u32, minutes: u64) -> TimeEntry {
+        TimeEntry {
+            id,
+            resource_id: 1,
+            project_id,
+            minutes,
+            start_date: date(day),
+            end_date: date(day),
+            weekend_booking: None,
+            holidays_booking: None,
+            vacations_booking: None,
+            description: None,
+            external_id: None,
+            creator_resource_id: None,
+            editor_resource_id: None,
+        }
+    }
+
+    fn grid() -> Grid {
+        let names = HashMap::from([(10, "Web".to_owned()), (20, "API".to_owned())]);
+        let entries = [
+            entry(1, 10, 5, 60),
+            entry(2, 20, 6, 120),
+            entry(3, 20, 6, 30),
+            entry(4, 10, 12, 60),
+        ];
+
+        Grid::new(date(5), &entries, &names)
+    }
+
+    fn summary(operations: &[TimeEntryOperation]) -> Vec<(Method, String)> {
+        operations
+            .iter()
+            .map(|operation| (operation.method(), operation.url().into_owned()))
+            .collect()
+    }
+
+    #[test]
+    fn arranges_entries_by_project_and_day() {
+        let grid = grid();
+
+        let projects = grid
+            .rows
+            .iter()
+            .map(|row| row.project.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(projects, vec!["API", "Web"]);
+        assert_eq!(grid.rows[0].cells[1].minutes, 150);
+        assert_eq!(grid.day_total(0), 60);
+        assert_eq!(Grid::row_total(&grid.rows[1]), 60);
+        assert!(!grid.has_changes());
+    }
+
+    #[test]
+    fn saves_only_changed_cells() {
+        let mut grid = grid();
+
+        grid.cursor = (0, 1);
+        grid.set_selected(90);
+        grid.cursor = (1, 0);
+        grid.set_selected(0);
+        grid.cursor = (1, 4);
+        grid.set_selected(45);
+
+        assert_eq!(
+            summary(&grid.changes(1)),
+            vec![
+                (Method::PUT, "time-entries/2".into()),
+                (Method::DELETE, "time-entries/3".into()),
+                (Method::DELETE, "time-entries/1".into()),
+                (Method::POST, "time-entries".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn restoring_a_cell_is_not_a_change() {
+        let mut grid = grid();
+
+        grid.cursor = (0, 1);
+        grid.set_selected(0);
+        grid.set_selected(150);
+
+        assert!(!grid.has_changes());
+    }
+
+    #[test]
+    fn cursor_stays_in_the_grid() {
+        let mut grid = grid();
+
+        grid.move_cursor(-1, -1);
+        assert_eq!(grid.cursor, (0, 0));
+
+        grid.move_cursor(5, 10);
+        assert_eq!(grid.cursor, (1, 6));
+    }
+
+    #[test]
+    fn adding_a_project_selects_its_row() {
+        let mut grid = grid();
+
+        grid.add_project(30, "Mobile".into());
+        assert_eq!(grid.cursor.0, 2);
+
+        grid.add_project(20, "API".into());
+        assert_eq!(grid.cursor.0, 0);
+        assert_eq!(grid.rows.len(), 3);
+    }
+
+    #[test]
+    fn keeps_the_changes_which_were_not_saved() {
+        let mut edited = grid();
+        edited.cursor = (0, 1);
+        edited.set_selected(90);
+        edited.add_project(30, "Mobile".into());
+        edited.cursor = (2, 2);
+        edited.set_selected(60);
+
+        // Only the change to API was saved.
+        let names = HashMap::from([(10, "Web".to_owned()), (20, "API".to_owned())]);
+        let entries = [
+            entry(2, 20, 6, 90),
+            entry(1, 10, 5, 60),
+            entry(4, 10, 12, 60),
+        ];
+        let mut reloaded = Grid::new(date(5), &entries, &names);
+        reloaded.keep_changes(&edited);
+
+        assert_eq!(
+            summary(&reloaded.changes(1)),
+            vec![(Method::POST, "time-entries".into())]
+        );
+        assert_eq!(reloaded.rows[2].project, "Mobile");
+        assert_eq!(reloaded.rows[2].cells[2].minutes, 60);
+    }
+}
@@ -0,0 +1,440 @@
+//! A full-screen grid of the time entries of a week, for entering time project by day.
+
+mod grid;
+mod ui;
+
+use std::collections::HashMap;
+use std::error::Error;
+
+use chrono::{Duration, Local, NaiveDate};
+use clap::Args;
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use ratatui::DefaultTerminal;
+use serde_json::Value;
+use teamdeck::api::{
+    self, holidays::Holidays, projects::Projects, time_entries::TimeEntries, vacations::Vacations,
+    Client, Pagination, Query,
+};
+use teamdeck::models::{TimeEntry, Vacation, VacationStatus};
+
+use self::grid::{Grid, DAYS};
+use crate::time::{self, Me};
+
+#[derive(Debug, Args)]
+pub struct TimesheetArgs {
+    /// Any day of the week to open, today by default.
+    #[arg(long, short)]
+    pub date: Option<NaiveDate>,
+    #[command(flatten)]
+    pub me: Me,
+}
+
+impl TimesheetArgs {
+    pub fn run<C: Client>(&self, client: &C) -> Result<(), Box<dyn Error>> {
+        let resource_id = time::resource_id(client, &self.me.email)?;
+
+        let projects: Vec<Value> =
+            api::paged(Projects::builder().build()?, Pagination::All).query(client)?;
+        let projects = projects
+            .iter()
+            .filter_map(|project| {
+                Some((
+                    project["id"].as_u64()?,
+                    project["name"].as_str()?.to_owned(),
+                ))
+            })
+            .collect::<Vec<_>>();
+
+        let (monday, _) = time::week_of(self.date.unwrap_or_else(|| Local::now().date_naive()));
+        let week = Week {
+            client,
+            resource_id,
+            project_names: projects.iter().cloned().collect(),
+        };
+        let mut app = App::new(week.load(monday)?, projects);
+
+        let mut terminal = ratatui::init();
+        let result = week.edit(&mut terminal, &mut app);
+        ratatui::restore();
+
+        result
+    }
+}
+
+/// Loads and saves the weeks of one resource.
+struct Week<'a, C> {
+    client: &'a C,
+    resource_id: u64,
+    project_names: HashMap<u64, String>,
+}
+
+impl<'a, C: Client> Week<'a, C> {
+    fn load(&self, monday: NaiveDate) -> Result<Grid, Box<dyn Error>> {
+        let sunday = monday + Duration::days(DAYS as i64 - 1);
+
+        let endpoint = TimeEntries::builder()
+            .resource_id(vec![self.resource_id])
+            .start_date_from(monday)
+            .start_date_to(sunday)
+            .build()?;
+        let entries: Vec<TimeEntry> = api::paged(endpoint, Pagination::All).query(self.client)?;
+        let mut grid = Grid::new(monday, &entries, &self.project_names);
+
+        let endpoint = Holidays::builder().from(monday).to(sunday).build()?;
+        let holidays: Vec<Value> = api::paged(endpoint, Pagination::All).query(self.client)?;
+        for holiday in &holidays {
+            let date = holiday["date"].as_str().and_then(|date| date.parse().ok());
+            if let Some(date) = date {
+                let name = holiday["name"].as_str().unwrap_or("Holiday");
+                grid.holidays.insert(date, name.to_owned());
+            }
+        }
+
+        let endpoint = Vacations::builder()
+            .resource_id(vec![self.resource_id])
+            .end_date_from(monday)
+            .start_date_to(sunday)
+            .build()?;
+        let vacations: Vec<Vacation> = api::paged(endpoint, Pagination::All).query(self.client)?;
+        for vacation in vacations
+            .iter()
+            .filter(|vacation| vacation.status != VacationStatus::Rejected)
+        {
+            let days = (0..DAYS).map(|day| grid.day(day));
+            let days = days.filter(|day| (vacation.start_date..=vacation.end_date).contains(day));
+            grid.vacations.extend(days.collect::<Vec<_>>());
+        }
+
+        Ok(grid)
+    }
+
+    /// Send the changes of the grid, returning a message about how it went.
+    fn save(&self, grid: &Grid) -> Result<String, Box<dyn Error>> {
+        let operations = grid.changes(self.resource_id);
+        let count = operations.len();
+        let report = api::batch(operations).query(self.client)?;
+
+        let status = match report.failed().next() {
+            None => format!("Saved {} changes", count),
+            Some(item) => format!(
+                "{} of {} changes failed: {}",
+                report.failed().count(),
+                count,
+                item.result.as_ref().unwrap_err()
+            ),
+        };
+
+        Ok(status)
+    }
+
+    /// Replace the grid with a week from Teamdeck, keeping the cursor where it was.
+    fn reload(&self, app: &mut App, monday: NaiveDate) -> Result<(), Box<dyn Error>> {
+        let cursor = app.grid.cursor;
+        app.grid = self.load(monday)?;
+        app.grid.cursor = cursor;
+        app.grid.move_cursor(0, 0);
+
+        Ok(())
+    }
+
+    fn edit(&self, terminal: &mut DefaultTerminal, app: &mut App) -> Result<(), Box<dyn Error>> {
+        loop {
+            terminal.draw(|frame| ui::draw(frame, app))?;
+
+            let key = match event::read()? {
+                Event::Key(key) if key.kind == KeyEventKind::Press => key,
+                _ => continue,
+            };
+
+            match app.handle_key(key) {
+                Action::Nothing => {}
+                Action::Quit => return Ok(()),
+                Action::Save => {
+                    app.status = "Saving…".into();
+                    terminal.draw(|frame| ui::draw(frame, app))?;
+
+                    app.status = match self.save(&app.grid) {
+                        Ok(status) => {
+                            // The cells which failed to save stay changed, to save them again.
+                            let edited = app.grid.clone();
+                            match self.reload(app, edited.monday()) {
+                                Ok(()) => {
+                                    app.grid.keep_changes(&edited);
+                                    status
+                                }
+                                Err(err) => {
+                                    format!("{}, but could not load the week: {}", status, err)
+                                }
+                            }
+                        }
+                        Err(err) => format!("Could not save: {}", err),
+                    };
+                }
+                Action::Week(weeks) => {
+                    let monday = app.grid.monday() + Duration::weeks(weeks);
+                    if let Err(err) = self.reload(app, monday) {
+                        app.status = format!("Could not load the week: {}", err);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// What the text typed at the bottom of the screen is for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Mode {
+    Normal,
+    /// The time of the selected cell.
+    Editing(String),
+    /// The name of a project to add a row for.
+    AddingProject(String),
+}
+
+/// What a key asks the event loop to do.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Action {
+    Nothing,
+    Save,
+    /// Open the week this many weeks away.
+    Week(i64),
+    Quit,
+}
+
+/// The state of the timesheet screen.
+struct App {
+    grid: Grid,
+    /// All the projects, as ids and names.
+    projects: Vec<(u64, String)>,
+    mode: Mode,
+    status: String,
+    /// Whether quitting was asked for with unsaved changes.
+    quitting: bool,
+}
+
+impl App {
+    fn new(grid: Grid, projects: Vec<(u64, String)>) -> Self {
+        Self {
+            grid,
+            projects,
+            mode: Mode::Normal,
+            status: String::new(),
+            quitting: false,
+        }
+    }
+
+    fn handle_key(&mut self, key: KeyEvent) -> Action {
+        if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c') {
+            return Action::Quit;
+        }
+
+        match &mut self.mode {
+            Mode::Normal => return self.handle_normal_key(key.code),
+            Mode::Editing(input) | Mode::AddingProject(input) => match key.code {
+                KeyCode::Char(c) => input.push(c),
+                KeyCode::Backspace => {
+                    input.pop();
+                }
+                KeyCode::Esc => self.mode = Mode::Normal,
+                KeyCode::Enter => self.submit(),
+                _ => {}
+            },
+        }
+
+        Action::Nothing
+    }
+
+    fn handle_normal_key(&mut self, key: KeyCode) -> Action {
+        let quitting = std::mem::take(&mut self.quitting);
+        self.status.clear();
+
+        match key {
+            KeyCode::Char('q') | KeyCode::Esc => {
+                if !self.grid.has_changes() || quitting {
+                    return Action::Quit;
+                }
+                self.quitting = true;
+                self.status = "Unsaved changes: press q again to quit without saving".into();
+            }
+            KeyCode::Left | KeyCode::Char('h') | KeyCode::BackTab => self.grid.move_cursor(0, -1),
+            KeyCode::Right | KeyCode::Char('l') | KeyCode::Tab => self.grid.move_cursor(0, 1),
+            KeyCode::Up | KeyCode::Char('k') => self.grid.move_cursor(-1, 0),
+            KeyCode::Down | KeyCode::Char('j') => self.grid.move_cursor(1, 0),
+            KeyCode::Enter => match self.grid.selected() {
+                Some(cell) if cell.minutes > 0 => {
+                    let input = time::format_minutes(cell.minutes).replace(' ', "");
+                    self.mode = Mode::Editing(input);
+                }
+                Some(_) => self.mode = Mode::Editing(String::new()),
+                None => self.status = "Press a to add a project".into(),
+            },
+            KeyCode::Char(c)
+                if (c.is_ascii_digit() || c == '.') && self.grid.selected().is_some() =>
+            {
+                self.mode = Mode::Editing(c.to_string());
+            }
+            KeyCode::Backspace | KeyCode::Delete => self.grid.set_selected(0),
+            KeyCode::Char('a') => self.mode = Mode::AddingProject(String::new()),
+            KeyCode::Char('s') => {
+                if self.grid.has_changes() {
+                    return Action::Save;
+                }
+                self.status = "Nothing to save".into();
+            }
+            KeyCode::Char(c @ ('n' | 'p')) => {
+                if !self.grid.has_changes() {
+                    return Action::Week(if c == 'n' { 1 } else { -1 });
+                }
+                self.status = "Save with s before changing the week".into();
+            }
+            _ => {}
+        }
+
+        Action::Nothing
+    }
+
+    /// Apply the text typed in the current mode.
+    fn submit(&mut self) {
+        match std::mem::replace(&mut self.mode, Mode::Normal) {
+            Mode::Normal => {}
+            Mode::Editing(input) => match parse_hours(&input) {
+                Ok(minutes) => self.grid.set_selected(minutes),
+                Err(err) => {
+                    self.status = err;
+                    self.mode = Mode::Editing(input);
+                }
+            },
+            Mode::AddingProject(input) => match self.find_project(&input) {
+                Ok((id, name)) => self.grid.add_project(id, name),
+                Err(err) => {
+                    self.status = err;
+                    self.mode = Mode::AddingProject(input);
+                }
+            },
+        }
+    }
+
+    /// The project named `name`, or the only one whose name contains it.
+    fn find_project(&self, name: &str) -> Result<(u64, String), String> {
+        let needle = name.trim().to_lowercase();
+        let matches = self
+            .projects
+            .iter()
+            .filter(|(_, project)| project.to_lowercase().contains(&needle))
+            .collect::<Vec<_>>();
+        let exact = matches
+            .iter()
+            .find(|(_, project)| project.to_lowercase() == needle);
+
+        match (exact, matches.as_slice()) {
+            (Some(found), _) | (None, [found]) => Ok((*found).clone()),
+            (None, []) => Err(format!("No project named `{}`", name)),
+            (None, _) => Err(format!("`{}` matches {} projects", name, matches.len())),
+        }
+    }
+}
+
+/// Parse the time typed in a cell: hours such as `1.5`, or a duration such as `1h30m`. Nothing
+/// clears the cell.
+fn parse_hours(input: &str) -> Result<u64, String> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Ok(0);
+    }
+    if input.contains(['h', 'm']) {
+        return time::parse_minutes(input);
+    }
+
+    match input.parse::<f64>() {
+        Ok(hours) if (0.0..=24.0).contains(&hours) => Ok((hours * 60.0).round() as u64),
+        _ => Err(format!(
+            "Invalid time `{}`, expected hours such as 1.5 or a duration such as 1h30m",
+            input
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn app() -> App {
+        let monday = NaiveDate::from_ymd_opt(2026, 1, 5).unwrap();
+        let projects = vec![
+            (10, "Website".to_owned()),
+            (20, "Web API".to_owned()),
+            (30, "Mobile".to_owned()),
+        ];
+
+        App::new(Grid::new(monday, &[], &HashMap::new()), projects)
+    }
+
+    fn press(app: &mut App, keys: &str) -> Action {
+        keys.chars()
+            .map(|c| {
+                let code = match c {
+                    '\n' => KeyCode::Enter,
+                    _ => KeyCode::Char(c),
+                };
+                app.handle_key(code.into())
+            })
+            .last()
+            .unwrap_or(Action::Nothing)
+    }
+
+    #[test]
+    fn parses_hours() {
+        assert_eq!(parse_hours("2"), Ok(120));
+        assert_eq!(parse_hours("1.25"), Ok(75));
+        assert_eq!(parse_hours("1h30m"), Ok(90));
+        assert_eq!(parse_hours(" "), Ok(0));
+        assert!(parse_hours("-1").is_err());
+        assert!(parse_hours("two").is_err());
+    }
+
+    #[test]
+    fn enters_time_for_an_added_project() {
+        let mut app = app();
+
+        press(&mut app, "amobile\n");
+        press(&mut app, "l1.5\n");
+
+        assert_eq!(app.mode, Mode::Normal);
+        assert_eq!(app.grid.rows[0].project, "Mobile");
+        assert_eq!(app.grid.rows[0].cells[1].minutes, 90);
+        assert_eq!(press(&mut app, "s"), Action::Save);
+    }
+
+    #[test]
+    fn exact_project_name_wins_over_partial_matches() {
+        let app = app();
+
+        assert_eq!(app.find_project("website"), Ok((10, "Website".into())));
+        assert!(app.find_project("web").is_err());
+        assert!(app.find_project("desktop").is_err());
+    }
+
+    #[test]
+    fn keeps_invalid_input_for_correction() {
+        let mut app = app();
+
+        press(&mut app, "amobile\n");
+        press(&mut app, "1x\n");
+
+        assert_eq!(app.mode, Mode::Editing("1x".into()));
+        assert!(!app.status.is_empty());
+    }
+
+    #[test]
+    fn unsaved_changes_need_confirming() {
+        let mut app = app();
+        assert_eq!(press(&mut app, "n"), Action::Week(1));
+
+        press(&mut app, "amobile\n");
+        press(&mut app, "8\n");
+
+        assert_eq!(press(&mut app, "p"), Action::Nothing);
+        assert_eq!(press(&mut app, "q"), Action::Nothing);
+        assert_eq!(press(&mut app, "q"), Action::Quit);
+    }
+}
@@ -0,0 +1,129 @@
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Color, Modifier, Style, Stylize};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, Cell, Paragraph, Row, Table};
+use ratatui::Frame;
+
+use super::grid::{Grid, DAYS};
+use super::{App, Mode};
+use crate::time::format_minutes;
+
+const HELP: &str =
+    "←↓↑→ move  enter edit  ⌫ clear  a add project  s save  n/p next/previous week  q quit";
+
+/// The style of the days of a column: holidays and vacations stand out.
+fn day_style(grid: &Grid, day: usize) -> Style {
+    let date = grid.day(day);
+    if grid.holidays.contains_key(&date) {
+        Style::new().fg(Color::Red)
+    } else if grid.vacations.contains(&date) {
+        Style::new().fg(Color::Magenta)
+    } else {
+        Style::new()
+    }
+}
+
+fn time(minutes: u64) -> String {
+    if minutes == 0 {
+        String::new()
+    } else {
+        format_minutes(minutes)
+    }
+}
+
+fn title(grid: &Grid) -> Line<'static> {
+    let mut title = format!(" Week of {} ", grid.monday());
+
+    let mut days_off = (0..DAYS)
+        .map(|day| grid.day(day))
+        .filter_map(|date| {
+            let name = grid
+                .holidays
+                .get(&date)
+                .map(String::as_str)
+                .or_else(|| grid.vacations.contains(&date).then_some("Vacation"))?;
+            Some(format!("{} {}", date.format("%a"), name))
+        })
+        .peekable();
+    if days_off.peek().is_some() {
+        title.push_str(&format!("· {} ", days_off.collect::<Vec<_>>().join(", ")));
+    }
+
+    Line::from(title).bold()
+}
+
+fn table(app: &App) -> Table<'_> {
+    let grid = &app.grid;
+
+    let header = Some(Cell::from("Project"))
+        .into_iter()
+        .chain((0..DAYS).map(|day| {
+            Cell::from(grid.day(day).format("%a %d").to_string()).style(day_style(grid, day))
+        }))
+        .chain(Some(Cell::from("Total")))
+        .collect::<Row>()
+        .bold();
+
+    let rows = grid.rows.iter().enumerate().map(|(index, row)| {
+        let cells = row.cells.iter().enumerate().map(|(day, cell)| {
+            let selected = grid.cursor == (index, day);
+            let text = match &app.mode {
+                Mode::Editing(input) if selected => format!("{}▏", input),
+                _ => time(cell.minutes),
+            };
+
+            let mut style = day_style(grid, day);
+            if cell.is_changed() {
+                style = style.fg(Color::Yellow).add_modifier(Modifier::BOLD);
+            }
+            if selected {
+                style = style.add_modifier(Modifier::REVERSED);
+            }
+
+            Cell::from(text).style(style)
+        });
+
+        Some(Cell::from(row.project.as_str()))
+            .into_iter()
+            .chain(cells)
+            .chain(Some(Cell::from(time(Grid::row_total(row)))))
+            .collect::<Row>()
+    });
+
+    let totals = Some(Cell::from("Total"))
+        .into_iter()
+        .chain((0..DAYS).map(|day| Cell::from(time(grid.day_total(day)))))
+        .chain(Some(Cell::from(time(
+            (0..DAYS).map(|day| grid.day_total(day)).sum(),
+        ))))
+        .collect::<Row>()
+        .bold()
+        .top_margin(1);
+
+    let widths = Some(Constraint::Min(16))
+        .into_iter()
+        .chain([Constraint::Length(9); DAYS + 1]);
+
+    Table::new(rows.chain(Some(totals)), widths)
+        .header(header)
+        .block(Block::bordered().title(title(grid)))
+}
+
+pub fn draw(frame: &mut Frame, app: &App) {
+    let [table_area, status_area, help_area] = Layout::vertical([
+        Constraint::Min(5),
+        Constraint::Length(1),
+        Constraint::Length(1),
+    ])
+    .areas(frame.area());
+
+    frame.render_widget(table(app), table_area);
+
+    let status = match &app.mode {
+        Mode::AddingProject(input) => format!("Project: {}▏  {}", input, app.status),
+        _ if app.grid.has_changes() && app.status.is_empty() => "Unsaved changes".into(),
+        _ => app.status.clone(),
+    };
+    frame.render_widget(Paragraph::new(status).yellow(), status_area);
+    frame.render_widget(Paragraph::new(HELP).dark_gray(), help_area);
+}
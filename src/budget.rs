@@ -0,0 +1,362 @@
+//! Project budgets: the amount spent on projects from their time entries, the amount booked ahead
+//! of them, and what remains, priced with hourly rates.
+
+use std::collections::{BTreeSet, HashMap};
+
+use chrono::{Duration, NaiveDate};
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::api::{self, resources::Resources, ApiError, Client, Pagination, Query};
+use crate::capacity::{bookings, time_entries, BookingsOf, Capacity, DayCapacity, TimeEntriesOf};
+use crate::models::{Booking, TimeEntry};
+
+/// Hourly rates, from the most specific to the least: of a resource on a project, of a project,
+/// of a resource, of a role, and the default rate.
+#[derive(Debug, Clone, Default)]
+pub struct Rates {
+    default: Option<f64>,
+    roles: HashMap<String, f64>,
+    resources: HashMap<u64, f64>,
+    projects: HashMap<u64, f64>,
+    project_resources: HashMap<(u64, u64), f64>,
+}
+
+impl Rates {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The rate of time no other rate applies to.
+    pub fn default_rate(mut self, rate: f64) -> Self {
+        self.default = Some(rate);
+        self
+    }
+
+    /// The rate of the resources with a role, ignoring case.
+    pub fn role(mut self, role: &str, rate: f64) -> Self {
+        self.roles.insert(role.to_lowercase(), rate);
+        self
+    }
+
+    pub fn resource(mut self, resource_id: u64, rate: f64) -> Self {
+        self.resources.insert(resource_id, rate);
+        self
+    }
+
+    pub fn project(mut self, project_id: u64, rate: f64) -> Self {
+        self.projects.insert(project_id, rate);
+        self
+    }
+
+    /// The rate of a resource on a project.
+    pub fn project_resource(mut self, project_id: u64, resource_id: u64, rate: f64) -> Self {
+        self.project_resources
+            .insert((project_id, resource_id), rate);
+        self
+    }
+
+    /// The hourly rate of a resource with a role on a project, if any applies.
+    pub fn rate(&self, project_id: u64, resource_id: u64, role: Option<&str>) -> Option<f64> {
+        self.project_resources
+            .get(&(project_id, resource_id))
+            .or_else(|| self.projects.get(&project_id))
+            .or_else(|| self.resources.get(&resource_id))
+            .or_else(|| role.and_then(|role| self.roles.get(&role.to_lowercase())))
+            .copied()
+            .or(self.default)
+    }
+}
+
+/// What an alert compares with the budget.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AlertKind {
+    /// The amount spent.
+    Spent,
+    /// The amount spent and booked ahead.
+    Forecast,
+}
+
+/// A threshold of a budget which is crossed.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Alert {
+    pub kind: AlertKind,
+    /// The share of the budget, e.g. 0.8 for 80%.
+    pub threshold: f64,
+    /// The share of the budget used.
+    pub used: f64,
+}
+
+/// The state of the budget of a project. Amounts are in the currency of the rates.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ProjectBudget {
+    pub project_id: u64,
+    pub budget: f64,
+    /// The amount of the time logged up to the date of the report.
+    pub spent: f64,
+    /// The amount of the time booked after the date of the report.
+    pub booked: f64,
+    /// The budget less the amounts spent and booked.
+    pub remaining: f64,
+    /// The minutes logged or booked which no rate applies to, and which are not in the amounts.
+    pub unpriced_minutes: u64,
+    /// The thresholds crossed by the amount spent, then by the forecast, from the lowest.
+    pub alerts: Vec<Alert>,
+}
+
+/// Tracks project budgets from time entries and bookings, priced with hourly rates.
+///
+/// Bookings count ahead from the day after the date of the report, on the days their resource is
+/// available.
+#[derive(Debug, Clone)]
+pub struct BudgetTracker {
+    rates: Rates,
+    capacity: Capacity,
+    budgets: HashMap<u64, f64>,
+    thresholds: Vec<f64>,
+}
+
+impl BudgetTracker {
+    pub fn new(rates: Rates) -> Self {
+        Self {
+            rates,
+            capacity: Capacity::default(),
+            budgets: HashMap::new(),
+            thresholds: vec![0.8, 1.0],
+        }
+    }
+
+    /// The capacity the bookings count on, Monday to Friday by default.
+    pub fn capacity(mut self, capacity: Capacity) -> Self {
+        self.capacity = capacity;
+        self
+    }
+
+    /// The budget of a project.
+    pub fn budget(mut self, project_id: u64, amount: f64) -> Self {
+        self.budgets.insert(project_id, amount);
+        self
+    }
+
+    /// The shares of the budgets to alert at, 80% and 100% by default.
+    pub fn thresholds<I>(mut self, thresholds: I) -> Self
+    where
+        I: IntoIterator<Item = f64>,
+    {
+        self.thresholds = thresholds.into_iter().collect();
+        self.thresholds.sort_by(f64::total_cmp);
+        self
+    }
+
+    fn alerts(&self, budget: f64, spent: f64, booked: f64) -> Vec<Alert> {
+        if budget <= 0.0 {
+            return vec![];
+        }
+
+        let mut alerts = Vec::new();
+        for (kind, amount) in [
+            (AlertKind::Spent, spent),
+            (AlertKind::Forecast, spent + booked),
+        ] {
+            let used = amount / budget;
+            alerts.extend(
+                self.thresholds
+                    .iter()
+                    .filter(|threshold| used >= **threshold)
+                    .map(|&threshold| Alert {
+                        kind,
+                        threshold,
+                        used,
+                    }),
+            );
+        }
+
+        alerts
+    }
+
+    /// The budgets of the projects on `today`, from the time entries up to it and the bookings
+    /// after it, given the roles of resources by id and their capacity on the booked days.
+    pub fn calculate(
+        &self,
+        today: NaiveDate,
+        time_entries: &[TimeEntry],
+        bookings: &[Booking],
+        roles: &HashMap<u64, String>,
+        capacity: &[DayCapacity],
+    ) -> Vec<ProjectBudget> {
+        let rate = |project_id, resource_id| {
+            let role = roles.get(&resource_id).map(String::as_str);
+            self.rates.rate(project_id, resource_id, role)
+        };
+
+        // (spent, booked, unpriced minutes) by project.
+        let mut amounts: HashMap<u64, (f64, f64, u64)> = HashMap::new();
+        for entry in time_entries
+            .iter()
+            .filter(|entry| entry.start_date <= today)
+        {
+            let amounts = amounts.entry(entry.project_id).or_default();
+            match rate(entry.project_id, entry.resource_id) {
+                Some(rate) => amounts.0 += rate * entry.minutes as f64 / 60.0,
+                None => amounts.2 += entry.minutes,
+            }
+        }
+        for booking in bookings {
+            let days = capacity
+                .iter()
+                .filter(|day| today < day.date && day.books(booking))
+                .count() as u64;
+            let minutes = booking.minutes * days;
+            let amounts = amounts.entry(booking.project_id).or_default();
+            match rate(booking.project_id, booking.resource_id) {
+                Some(rate) => amounts.1 += rate * minutes as f64 / 60.0,
+                None => amounts.2 += minutes,
+            }
+        }
+
+        let projects = self.budgets.keys().copied().collect::<BTreeSet<_>>();
+        projects
+            .into_iter()
+            .map(|project_id| {
+                let budget = self.budgets[&project_id];
+                let (spent, booked, unpriced_minutes) =
+                    amounts.get(&project_id).copied().unwrap_or_default();
+                ProjectBudget {
+                    project_id,
+                    budget,
+                    spent,
+                    booked,
+                    remaining: budget - spent - booked,
+                    unpriced_minutes,
+                    alerts: self.alerts(budget, spent, booked),
+                }
+            })
+            .collect()
+    }
+
+    /// Fetch the time entries and bookings of the projects with a budget, and the resources for
+    /// their roles, and calculate the budgets on `today`.
+    pub fn fetch<C: Client>(
+        &self,
+        today: NaiveDate,
+        client: &C,
+    ) -> Result<Vec<ProjectBudget>, ApiError<C::Error>> {
+        let projects = self.budgets.keys().copied().collect::<Vec<_>>();
+        if projects.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let time_entries = time_entries(TimeEntriesOf::Projects(&projects), None, today, client)?;
+
+        let tomorrow = today + Duration::days(1);
+        let bookings = bookings(BookingsOf::Projects(&projects), tomorrow, None, client)?;
+
+        let endpoint = Resources::builder().build().unwrap();
+        let resources: Vec<Value> = api::paged(endpoint, Pagination::All).query(client)?;
+        let roles = resources
+            .iter()
+            .filter_map(|resource| {
+                Some((
+                    resource["id"].as_u64()?,
+                    resource["role"].as_str()?.to_owned(),
+                ))
+            })
+            .collect();
+
+        let booked = bookings
+            .iter()
+            .map(|booking| booking.resource_id)
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect::<Vec<_>>();
+        let capacity = match bookings.iter().map(|booking| booking.end_date).max() {
+            Some(to) => self.capacity.fetch(&booked, tomorrow, to, client)?,
+            None => vec![],
+        };
+
+        Ok(self.calculate(today, &time_entries, &bookings, &roles, &capacity))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::testing::fixtures::{booking, date, time_entry};
+    use crate::testing::FakeTeamdeck;
+
+    #[test]
+    fn picks_the_most_specific_rate() {
+        let rates = Rates::new()
+            .default_rate(50.0)
+            .role("Developer", 80.0)
+            .resource(1, 90.0)
+            .project(10, 100.0)
+            .project_resource(10, 1, 120.0);
+
+        assert_eq!(rates.rate(10, 1, Some("developer")), Some(120.0));
+        assert_eq!(rates.rate(10, 2, Some("developer")), Some(100.0));
+        assert_eq!(rates.rate(11, 1, Some("developer")), Some(90.0));
+        assert_eq!(rates.rate(11, 2, Some("developer")), Some(80.0));
+        assert_eq!(rates.rate(11, 2, None), Some(50.0));
+        assert_eq!(Rates::new().rate(11, 2, None), None);
+    }
+
+    #[test]
+    fn tracks_spent_booked_and_remaining_amounts() {
+        let teamdeck = FakeTeamdeck::new();
+        let ada = teamdeck.insert("resources", json!({ "name": "Ada", "role": "Developer" }));
+        let grace = teamdeck.insert("resources", json!({ "name": "Grace", "role": "Designer" }));
+        teamdeck.insert("time-entries", time_entry(ada, 10, "2026-06-01", 600));
+        teamdeck.insert("time-entries", time_entry(grace, 10, "2026-06-02", 120));
+        teamdeck.insert("time-entries", time_entry(ada, 11, "2026-06-02", 60));
+        // After the date of the report.
+        teamdeck.insert("time-entries", time_entry(ada, 10, "2026-06-05", 480));
+        teamdeck.insert(
+            "bookings",
+            booking(ada, 10, "2026-06-01", "2026-06-09", 240),
+        );
+
+        let tracker = BudgetTracker::new(Rates::new().role("developer", 60.0))
+            .budget(10, 1000.0)
+            .budget(12, 500.0)
+            .thresholds([1.0, 0.5]);
+        let budgets = tracker.fetch(date(3), &teamdeck).unwrap();
+
+        assert_eq!(budgets.len(), 2);
+        let budget = &budgets[0];
+        assert_eq!(budget.project_id, 10);
+        // 10 hours logged by Ada, Grace has no rate.
+        assert_eq!(budget.spent, 600.0);
+        assert_eq!(budget.unpriced_minutes, 120);
+        // 4 hours a day from Thursday to the next Tuesday, but the weekend.
+        assert_eq!(budget.booked, 4.0 * 4.0 * 60.0);
+        assert_eq!(budget.remaining, -560.0);
+        assert_eq!(
+            budget.alerts,
+            vec![
+                Alert {
+                    kind: AlertKind::Spent,
+                    threshold: 0.5,
+                    used: 0.6,
+                },
+                Alert {
+                    kind: AlertKind::Forecast,
+                    threshold: 0.5,
+                    used: 1.56,
+                },
+                Alert {
+                    kind: AlertKind::Forecast,
+                    threshold: 1.0,
+                    used: 1.56,
+                },
+            ]
+        );
+
+        assert_eq!(budgets[1].spent, 0.0);
+        assert_eq!(budgets[1].remaining, 500.0);
+        assert!(budgets[1].alerts.is_empty());
+    }
+}
@@ -0,0 +1,520 @@
+//! The time resources have for work: their working days, less holidays and approved vacations.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+use serde_json::Value;
+
+use crate::api::{
+    self,
+    booking::Bookings,
+    holidays::Holidays,
+    time_entries::TimeEntries,
+    vacations::{VacationPeriods, Vacations},
+    ApiError, Client, Pagination, Query,
+};
+use crate::models::{Booking, Holiday, TimeEntry, Vacation, VacationStatus};
+
+/// The days someone works and for how long.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkWeek {
+    days: HashSet<Weekday>,
+    daily_minutes: u64,
+}
+
+impl Default for WorkWeek {
+    /// Monday to Friday, 8 hours a day.
+    fn default() -> Self {
+        Self::new(
+            [
+                Weekday::Mon,
+                Weekday::Tue,
+                Weekday::Wed,
+                Weekday::Thu,
+                Weekday::Fri,
+            ],
+            8.0,
+        )
+    }
+}
+
+impl WorkWeek {
+    pub fn new<I>(days: I, daily_hours: f64) -> Self
+    where
+        I: IntoIterator<Item = Weekday>,
+    {
+        Self {
+            days: days.into_iter().collect(),
+            daily_minutes: (daily_hours * 60.0).round().max(0.0) as u64,
+        }
+    }
+
+    /// The minutes of work on a day, before holidays and vacations.
+    pub fn minutes(&self, date: NaiveDate) -> u64 {
+        if self.days.contains(&date.weekday()) {
+            self.daily_minutes
+        } else {
+            0
+        }
+    }
+}
+
+/// The capacity of a resource on a day, in minutes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DayCapacity {
+    pub resource_id: u64,
+    pub date: NaiveDate,
+    /// The minutes of the work week.
+    pub scheduled: u64,
+    /// The minutes lost to holidays.
+    pub holidays: u64,
+    /// The minutes lost to approved vacations.
+    pub vacations: u64,
+    pub available: u64,
+}
+
+impl DayCapacity {
+    /// Whether a booking of the resource spans the day.
+    pub fn spans(&self, booking: &Booking) -> bool {
+        booking.resource_id == self.resource_id
+            && booking.start_date <= self.date
+            && self.date <= booking.end_date
+    }
+
+    /// Whether a booking takes its minutes on the day. Bookings only take time on the days their
+    /// resource is available, leaving out days off, holidays and vacations as Teamdeck does by
+    /// default.
+    pub fn books(&self, booking: &Booking) -> bool {
+        self.available > 0 && self.spans(booking)
+    }
+}
+
+/// The capacity of a resource over a week, in minutes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WeekCapacity {
+    pub resource_id: u64,
+    /// The Monday of the week.
+    pub week: NaiveDate,
+    pub scheduled: u64,
+    pub holidays: u64,
+    pub vacations: u64,
+    pub available: u64,
+}
+
+/// Sum daily capacities by resource and week, the weeks starting on Monday.
+pub fn weeks(days: &[DayCapacity]) -> Vec<WeekCapacity> {
+    let mut weeks: BTreeMap<(u64, NaiveDate), WeekCapacity> = BTreeMap::new();
+    for day in days {
+        let monday = day.date - Duration::days(day.date.weekday().num_days_from_monday().into());
+        let week = weeks
+            .entry((day.resource_id, monday))
+            .or_insert(WeekCapacity {
+                resource_id: day.resource_id,
+                week: monday,
+                scheduled: 0,
+                holidays: 0,
+                vacations: 0,
+                available: 0,
+            });
+        week.scheduled += day.scheduled;
+        week.holidays += day.holidays;
+        week.vacations += day.vacations;
+        week.available += day.available;
+    }
+
+    weeks.into_values().collect()
+}
+
+/// Calculates the capacity of resources from their work week, the holidays and their approved
+/// vacations.
+///
+/// Holidays of an organization unit only apply to the resources known to be in it, resources
+/// whose unit is unknown get every holiday. Vacations with a period such as a half day take the
+/// hours of the period, other vacations take whole days.
+#[derive(Debug, Clone, Default)]
+pub struct Capacity {
+    work_week: WorkWeek,
+    work_weeks: HashMap<u64, WorkWeek>,
+    organization_units: HashMap<u64, u64>,
+}
+
+impl Capacity {
+    /// The work week of everyone, unless set for a resource.
+    pub fn new(work_week: WorkWeek) -> Self {
+        Self {
+            work_week,
+            ..Self::default()
+        }
+    }
+
+    /// The work week of a resource, e.g. someone working part time.
+    pub fn resource_work_week(mut self, resource_id: u64, work_week: WorkWeek) -> Self {
+        self.work_weeks.insert(resource_id, work_week);
+        self
+    }
+
+    /// The organization unit of resources by resource id.
+    pub fn organization_units(mut self, units: HashMap<u64, u64>) -> Self {
+        self.organization_units = units;
+        self
+    }
+
+    fn work_week(&self, resource_id: u64) -> &WorkWeek {
+        self.work_weeks.get(&resource_id).unwrap_or(&self.work_week)
+    }
+
+    /// Whether a holiday applies to a resource.
+    pub(crate) fn has_holiday(&self, resource_id: u64, holiday: &Holiday) -> bool {
+        match (
+            holiday.organization_unit_id,
+            self.organization_units.get(&resource_id),
+        ) {
+            (Some(unit), Some(resource_unit)) => unit == *resource_unit,
+            _ => true,
+        }
+    }
+
+    /// The capacity of each resource on each day from `from` to `to` inclusive, ordered by
+    /// resource and date.
+    ///
+    /// `period_minutes` are the minutes a day of vacation takes by vacation period id.
+    pub fn calculate(
+        &self,
+        resources: &[u64],
+        from: NaiveDate,
+        to: NaiveDate,
+        holidays: &[Holiday],
+        vacations: &[Vacation],
+        period_minutes: &HashMap<u64, u64>,
+    ) -> Vec<DayCapacity> {
+        let mut days = Vec::new();
+        for &resource_id in resources {
+            let work_week = self.work_week(resource_id);
+            let holidays = holidays
+                .iter()
+                .filter(|holiday| self.has_holiday(resource_id, holiday))
+                .map(|holiday| holiday.date)
+                .collect::<HashSet<_>>();
+            let vacations = vacations
+                .iter()
+                .filter(|vacation| {
+                    vacation.resource_id == resource_id
+                        && vacation.status == VacationStatus::Approved
+                })
+                .collect::<Vec<_>>();
+
+            for date in from.iter_days().take_while(|date| *date <= to) {
+                let scheduled = work_week.minutes(date);
+                let holiday = if holidays.contains(&date) {
+                    scheduled
+                } else {
+                    0
+                };
+                let vacation = vacations
+                    .iter()
+                    .filter(|vacation| vacation.start_date <= date && date <= vacation.end_date)
+                    .map(|vacation| {
+                        vacation
+                            .period_id
+                            .and_then(|period| period_minutes.get(&period).copied())
+                            .unwrap_or(scheduled)
+                    })
+                    .sum::<u64>()
+                    .min(scheduled - holiday);
+
+                days.push(DayCapacity {
+                    resource_id,
+                    date,
+                    scheduled,
+                    holidays: holiday,
+                    vacations: vacation,
+                    available: scheduled - holiday - vacation,
+                });
+            }
+        }
+
+        days
+    }
+
+    /// Fetch the holidays, vacations and vacation periods over the dates and calculate the
+    /// capacity of each resource on each day.
+    pub fn fetch<C: Client>(
+        &self,
+        resources: &[u64],
+        from: NaiveDate,
+        to: NaiveDate,
+        client: &C,
+    ) -> Result<Vec<DayCapacity>, ApiError<C::Error>> {
+        if resources.is_empty() || to < from {
+            return Ok(vec![]);
+        }
+
+        let absences = Absences::fetch(resources, from, to, client)?;
+
+        Ok(absences.capacity(self, resources, from, to))
+    }
+}
+
+/// The holidays and vacations of resources over some dates, with the minutes of the vacation
+/// periods.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Absences {
+    pub(crate) holidays: Vec<Holiday>,
+    pub(crate) vacations: Vec<Vacation>,
+    pub(crate) period_minutes: HashMap<u64, u64>,
+}
+
+impl Absences {
+    pub(crate) fn fetch<C: Client>(
+        resources: &[u64],
+        from: NaiveDate,
+        to: NaiveDate,
+        client: &C,
+    ) -> Result<Self, ApiError<C::Error>> {
+        let endpoint = Holidays::builder().from(from).to(to).build().unwrap();
+        let holidays: Vec<Holiday> = api::paged(endpoint, Pagination::All).query(client)?;
+
+        let endpoint = Vacations::builder()
+            .resource_id(resources.to_vec())
+            .end_date_from(from)
+            .start_date_to(to)
+            .build()
+            .unwrap();
+        let vacations: Vec<Vacation> = api::paged(endpoint, Pagination::All).query(client)?;
+
+        let endpoint = VacationPeriods::builder().build().unwrap();
+        let periods: Vec<Value> = api::paged(endpoint, Pagination::All).query(client)?;
+        let period_minutes = periods
+            .iter()
+            .filter_map(|period| {
+                let hours = period["hours_per_day"].as_f64()?;
+                Some((period["id"].as_u64()?, (hours * 60.0).round() as u64))
+            })
+            .collect();
+
+        Ok(Self {
+            holidays,
+            vacations,
+            period_minutes,
+        })
+    }
+
+    pub(crate) fn capacity(
+        &self,
+        capacity: &Capacity,
+        resources: &[u64],
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Vec<DayCapacity> {
+        capacity.calculate(
+            resources,
+            from,
+            to,
+            &self.holidays,
+            &self.vacations,
+            &self.period_minutes,
+        )
+    }
+}
+
+/// Whose bookings to fetch, of some resources or of some projects. Without ids, everyone's.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum BookingsOf<'a> {
+    Resources(&'a [u64]),
+    Projects(&'a [u64]),
+}
+
+impl BookingsOf<'_> {
+    fn matches(self, booking: &Booking) -> bool {
+        match self {
+            Self::Resources(ids) => ids.is_empty() || ids.contains(&booking.resource_id),
+            Self::Projects(ids) => ids.is_empty() || ids.contains(&booking.project_id),
+        }
+    }
+}
+
+/// Fetch the bookings overlapping `from` to `to` inclusive, or from `from` on without `to`.
+pub(crate) fn bookings<C: Client>(
+    of: BookingsOf<'_>,
+    from: NaiveDate,
+    to: Option<NaiveDate>,
+    client: &C,
+) -> Result<Vec<Booking>, ApiError<C::Error>> {
+    let mut builder = Bookings::builder();
+    builder.end_date_from(from);
+    if let Some(to) = to {
+        builder.start_date_to(to);
+    }
+    // Bookings can only be filtered by a single resource or project, the rest is filtered here.
+    match of {
+        BookingsOf::Resources(&[resource_id]) => {
+            builder.resource_id(resource_id);
+        }
+        BookingsOf::Projects(&[project_id]) => {
+            builder.project_id(project_id);
+        }
+        _ => {}
+    }
+    let bookings: Vec<Booking> =
+        api::paged(builder.build().unwrap(), Pagination::All).query(client)?;
+
+    Ok(bookings
+        .into_iter()
+        .filter(|booking| of.matches(booking))
+        .collect())
+}
+
+/// Whose time entries to fetch, of some resources or of some projects. Without ids, everyone's.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum TimeEntriesOf<'a> {
+    Resources(&'a [u64]),
+    Projects(&'a [u64]),
+}
+
+/// Fetch the time entries starting from `from` to `to` inclusive, or up to `to` without `from`.
+pub(crate) fn time_entries<C: Client>(
+    of: TimeEntriesOf<'_>,
+    from: Option<NaiveDate>,
+    to: NaiveDate,
+    client: &C,
+) -> Result<Vec<TimeEntry>, ApiError<C::Error>> {
+    let mut builder = TimeEntries::builder();
+    builder.start_date_to(to);
+    if let Some(from) = from {
+        builder.start_date_from(from);
+    }
+    match of {
+        TimeEntriesOf::Resources(ids) if !ids.is_empty() => {
+            builder.resource_id(ids.to_vec());
+        }
+        TimeEntriesOf::Projects(ids) if !ids.is_empty() => {
+            builder.project_id(ids.to_vec());
+        }
+        _ => {}
+    }
+
+    api::paged(builder.build().unwrap(), Pagination::All).query(client)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::testing::fixtures::{booking, date, insert_holiday, vacation};
+    use crate::testing::FakeTeamdeck;
+
+    fn available(days: &[DayCapacity], resource_id: u64) -> Vec<u64> {
+        days.iter()
+            .filter(|day| day.resource_id == resource_id)
+            .map(|day| day.available / 60)
+            .collect()
+    }
+
+    #[test]
+    fn work_weeks() {
+        let week = WorkWeek::default();
+        // 2026-06-05 is a Friday.
+        assert_eq!(week.minutes(date(5)), 480);
+        assert_eq!(week.minutes(date(6)), 0);
+
+        let part_time = WorkWeek::new([Weekday::Mon, Weekday::Tue], 7.5);
+        assert_eq!(part_time.minutes(date(1)), 450);
+        assert_eq!(part_time.minutes(date(3)), 0);
+    }
+
+    #[test]
+    fn removes_holidays_and_approved_vacations() {
+        let teamdeck = FakeTeamdeck::new();
+        insert_holiday(&teamdeck, "2026-06-01");
+        teamdeck.insert(
+            "holidays",
+            json!({ "name": "Founders' day", "date": "2026-06-03", "organization_unit_id": 7 }),
+        );
+        let half_day = teamdeck.insert(
+            "vacation-periods",
+            json!({ "name": "Half day", "hours_per_day": 4 }),
+        );
+        teamdeck.insert(
+            "vacations",
+            vacation(1, "2026-05-29", "2026-06-02", "approved"),
+        );
+        let mut half_day_off = vacation(1, "2026-06-04", "2026-06-04", "approved");
+        half_day_off["period_id"] = json!(half_day);
+        teamdeck.insert("vacations", half_day_off);
+        teamdeck.insert(
+            "vacations",
+            vacation(1, "2026-06-05", "2026-06-05", "pending"),
+        );
+
+        let capacity = Capacity::new(WorkWeek::default())
+            .resource_work_week(2, WorkWeek::new([Weekday::Mon, Weekday::Wed], 6.0))
+            .organization_units(HashMap::from([(1, 5), (2, 7)]));
+        let days = capacity
+            .fetch(&[1, 2], date(1), date(7), &teamdeck)
+            .unwrap();
+
+        assert_eq!(available(&days, 1), vec![0, 0, 8, 4, 8, 0, 0]);
+        assert_eq!(available(&days, 2), vec![0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(days[0].holidays, 480);
+        assert_eq!(days[1].vacations, 480);
+
+        assert_eq!(
+            weeks(&days),
+            vec![
+                WeekCapacity {
+                    resource_id: 1,
+                    week: date(1),
+                    scheduled: 2400,
+                    holidays: 480,
+                    vacations: 720,
+                    available: 1200,
+                },
+                WeekCapacity {
+                    resource_id: 2,
+                    week: date(1),
+                    scheduled: 720,
+                    holidays: 720,
+                    vacations: 0,
+                    available: 0,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn filters_bookings_of_several_resources_or_projects() {
+        let teamdeck = FakeTeamdeck::new();
+        for (resource_id, project_id, start, end) in [
+            (1, 10, "2026-06-01", "2026-06-05"),
+            (2, 11, "2026-06-03", "2026-06-12"),
+            (3, 10, "2026-06-08", "2026-06-12"),
+            (1, 12, "2026-05-25", "2026-05-29"),
+        ] {
+            teamdeck.insert(
+                "bookings",
+                booking(resource_id, project_id, start, end, 240),
+            );
+        }
+        let fetch = |of, to| {
+            bookings(of, date(1), to, &teamdeck)
+                .unwrap()
+                .iter()
+                .map(|booking| (booking.resource_id, booking.project_id))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            fetch(BookingsOf::Resources(&[1, 2]), Some(date(5))),
+            vec![(1, 10), (2, 11)]
+        );
+        assert_eq!(
+            fetch(BookingsOf::Projects(&[10]), None),
+            vec![(1, 10), (3, 10)]
+        );
+        assert_eq!(
+            fetch(BookingsOf::Projects(&[]), Some(date(5))),
+            vec![(1, 10), (2, 11)]
+        );
+    }
+}
@@ -0,0 +1,404 @@
+//! Checks of bookings against vacations, holidays and other bookings, before creating a booking
+//! or as an audit of existing ones.
+
+use chrono::NaiveDate;
+use serde::Serialize;
+
+use crate::api::{ApiError, Client};
+use crate::capacity::{bookings, Absences, BookingsOf, Capacity, DayCapacity};
+use crate::models::{Booking, Holiday, Vacation, VacationStatus};
+
+/// A booking to check before creating it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProposedBooking {
+    pub resource_id: u64,
+    pub project_id: u64,
+    pub start_date: NaiveDate,
+    /// The last day of the booking, inclusive.
+    pub end_date: NaiveDate,
+    /// The minutes booked per day.
+    pub minutes: u64,
+}
+
+impl From<&Booking> for ProposedBooking {
+    fn from(booking: &Booking) -> Self {
+        Self {
+            resource_id: booking.resource_id,
+            project_id: booking.project_id,
+            start_date: booking.start_date,
+            end_date: booking.end_date,
+            minutes: booking.minutes,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ConflictKind {
+    /// The resource has a holiday.
+    Holiday { holiday_id: u64 },
+    /// The resource is on a pending or approved vacation.
+    Vacation {
+        vacation_id: u64,
+        status: VacationStatus,
+    },
+    /// The bookings of the day exceed the time the resource has, in minutes.
+    OverAllocated { booked: u64, available: u64 },
+}
+
+/// A day on which bookings of a resource conflict with something.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Conflict {
+    pub resource_id: u64,
+    pub date: NaiveDate,
+    /// The existing bookings of the day.
+    pub bookings: Vec<u64>,
+    /// Whether the proposed booking is on the day.
+    pub proposed: bool,
+    #[serde(flatten)]
+    pub kind: ConflictKind,
+}
+
+/// A booking on a day, existing (with an id) or proposed.
+struct Booked {
+    id: Option<u64>,
+    resource_id: u64,
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+    minutes: u64,
+}
+
+/// Finds the days on which bookings overlap holidays or vacations of their resource, or add up to
+/// more than the time the resource has.
+///
+/// Only working days of the capacity's work weeks are checked. Rejected vacations are ignored.
+#[derive(Debug, Clone, Default)]
+pub struct ConflictCheck {
+    capacity: Capacity,
+}
+
+impl ConflictCheck {
+    pub fn new(capacity: Capacity) -> Self {
+        Self { capacity }
+    }
+
+    fn conflicts(
+        &self,
+        booked: &[Booked],
+        capacity: &[DayCapacity],
+        holidays: &[Holiday],
+        vacations: &[Vacation],
+    ) -> Vec<Conflict> {
+        let mut conflicts = Vec::new();
+        for day in capacity.iter().filter(|day| day.scheduled > 0) {
+            let on_day = booked
+                .iter()
+                .filter(|booked| {
+                    booked.resource_id == day.resource_id
+                        && booked.start_date <= day.date
+                        && day.date <= booked.end_date
+                })
+                .collect::<Vec<_>>();
+            if on_day.is_empty() {
+                continue;
+            }
+            let bookings = on_day
+                .iter()
+                .filter_map(|booked| booked.id)
+                .collect::<Vec<_>>();
+            let proposed = on_day.iter().any(|booked| booked.id.is_none());
+            let conflict = |kind| Conflict {
+                resource_id: day.resource_id,
+                date: day.date,
+                bookings: bookings.clone(),
+                proposed,
+                kind,
+            };
+
+            for holiday in holidays {
+                if holiday.date == day.date && self.capacity.has_holiday(day.resource_id, holiday) {
+                    conflicts.push(conflict(ConflictKind::Holiday {
+                        holiday_id: holiday.id,
+                    }));
+                }
+            }
+            for vacation in vacations {
+                if vacation.resource_id == day.resource_id
+                    && vacation.start_date <= day.date
+                    && day.date <= vacation.end_date
+                    && vacation.status != VacationStatus::Rejected
+                {
+                    conflicts.push(conflict(ConflictKind::Vacation {
+                        vacation_id: vacation.id,
+                        status: vacation.status,
+                    }));
+                }
+            }
+            // Days off entirely are already conflicts of their own.
+            let booked = on_day.iter().map(|booked| booked.minutes).sum::<u64>();
+            if day.available > 0 && booked > day.available {
+                conflicts.push(conflict(ConflictKind::OverAllocated {
+                    booked,
+                    available: day.available,
+                }));
+            }
+        }
+
+        conflicts
+    }
+
+    /// The conflicts of bookings, with a proposed booking or not, given the capacity of their
+    /// resources on the days to check. Conflicts are ordered by resource and date.
+    pub fn calculate(
+        &self,
+        bookings: &[Booking],
+        proposed: Option<&ProposedBooking>,
+        capacity: &[DayCapacity],
+        holidays: &[Holiday],
+        vacations: &[Vacation],
+    ) -> Vec<Conflict> {
+        let booked = bookings
+            .iter()
+            .map(|booking| Booked {
+                id: Some(booking.id),
+                resource_id: booking.resource_id,
+                start_date: booking.start_date,
+                end_date: booking.end_date,
+                minutes: booking.minutes,
+            })
+            .chain(proposed.map(|proposed| Booked {
+                id: None,
+                resource_id: proposed.resource_id,
+                start_date: proposed.start_date,
+                end_date: proposed.end_date,
+                minutes: proposed.minutes,
+            }))
+            .collect::<Vec<_>>();
+
+        let mut conflicts = self.conflicts(&booked, capacity, holidays, vacations);
+        if proposed.is_some() {
+            conflicts.retain(|conflict| conflict.proposed);
+        }
+
+        conflicts
+    }
+
+    /// Check a booking before creating it, returning the conflicts it would take part in.
+    pub fn check<C: Client>(
+        &self,
+        proposed: &ProposedBooking,
+        client: &C,
+    ) -> Result<Vec<Conflict>, ApiError<C::Error>> {
+        let (from, to) = (proposed.start_date, proposed.end_date);
+        let resources = [proposed.resource_id];
+        let absences = Absences::fetch(&resources, from, to, client)?;
+        let capacity = absences.capacity(&self.capacity, &resources, from, to);
+
+        let bookings = bookings(BookingsOf::Resources(&resources), from, Some(to), client)?;
+
+        Ok(self.calculate(
+            &bookings,
+            Some(proposed),
+            &capacity,
+            &absences.holidays,
+            &absences.vacations,
+        ))
+    }
+
+    /// Check the existing bookings of resources from `from` to `to` inclusive.
+    pub fn audit<C: Client>(
+        &self,
+        resources: &[u64],
+        from: NaiveDate,
+        to: NaiveDate,
+        client: &C,
+    ) -> Result<Vec<Conflict>, ApiError<C::Error>> {
+        if resources.is_empty() || to < from {
+            return Ok(vec![]);
+        }
+
+        let absences = Absences::fetch(resources, from, to, client)?;
+        let capacity = absences.capacity(&self.capacity, resources, from, to);
+
+        let bookings = bookings(BookingsOf::Resources(resources), from, Some(to), client)?;
+
+        Ok(self.calculate(
+            &bookings,
+            None,
+            &capacity,
+            &absences.holidays,
+            &absences.vacations,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::capacity::WorkWeek;
+    use crate::testing::fixtures::{booking, date, insert_holiday, vacation};
+    use crate::testing::FakeTeamdeck;
+
+    /// A fake Teamdeck with the ids of its holiday, pending vacation and booking.
+    struct Fixture {
+        teamdeck: FakeTeamdeck,
+        holiday: u64,
+        pending: u64,
+        booked: u64,
+    }
+
+    fn fixture() -> Fixture {
+        let teamdeck = FakeTeamdeck::new();
+        let holiday = insert_holiday(&teamdeck, "2026-06-01");
+        let pending = teamdeck.insert(
+            "vacations",
+            vacation(1, "2026-06-03", "2026-06-03", "pending"),
+        );
+        teamdeck.insert(
+            "vacations",
+            vacation(1, "2026-06-04", "2026-06-04", "rejected"),
+        );
+        let booked = teamdeck.insert("bookings", booking(1, 5, "2026-06-02", "2026-06-05", 300));
+
+        Fixture {
+            teamdeck,
+            holiday,
+            pending,
+            booked,
+        }
+    }
+
+    fn kinds(conflicts: &[Conflict]) -> Vec<(NaiveDate, ConflictKind)> {
+        conflicts
+            .iter()
+            .map(|conflict| (conflict.date, conflict.kind))
+            .collect()
+    }
+
+    #[test]
+    fn checks_a_proposed_booking() {
+        let Fixture {
+            teamdeck,
+            holiday,
+            pending,
+            booked,
+        } = fixture();
+        let check = ConflictCheck::new(Capacity::new(WorkWeek::default()));
+        let proposed = ProposedBooking {
+            resource_id: 1,
+            project_id: 6,
+            start_date: date(1),
+            end_date: date(4),
+            minutes: 240,
+        };
+
+        let conflicts = check.check(&proposed, &teamdeck).unwrap();
+
+        assert_eq!(
+            kinds(&conflicts),
+            vec![
+                (
+                    date(1),
+                    ConflictKind::Holiday {
+                        holiday_id: holiday
+                    }
+                ),
+                (
+                    date(2),
+                    ConflictKind::OverAllocated {
+                        booked: 540,
+                        available: 480,
+                    }
+                ),
+                (
+                    date(3),
+                    ConflictKind::Vacation {
+                        vacation_id: pending,
+                        status: VacationStatus::Pending,
+                    }
+                ),
+                (
+                    date(3),
+                    ConflictKind::OverAllocated {
+                        booked: 540,
+                        available: 480,
+                    }
+                ),
+                (
+                    date(4),
+                    ConflictKind::OverAllocated {
+                        booked: 540,
+                        available: 480,
+                    }
+                ),
+            ]
+        );
+        assert!(conflicts.iter().all(|conflict| conflict.proposed));
+        assert_eq!(conflicts[0].bookings, Vec::<u64>::new());
+        assert_eq!(conflicts[1].bookings, vec![booked]);
+
+        let weekend = ProposedBooking {
+            start_date: date(6),
+            end_date: date(7),
+            ..proposed
+        };
+        assert_eq!(check.check(&weekend, &teamdeck).unwrap(), vec![]);
+    }
+
+    #[test]
+    fn audits_existing_bookings() {
+        let Fixture {
+            teamdeck, booked, ..
+        } = fixture();
+        let on_holiday =
+            teamdeck.insert("bookings", booking(2, 5, "2026-06-01", "2026-06-01", 480));
+
+        let conflicts = ConflictCheck::default()
+            .audit(&[1, 2], date(1), date(7), &teamdeck)
+            .unwrap();
+
+        let conflicts = conflicts
+            .iter()
+            .map(|conflict| {
+                (
+                    conflict.resource_id,
+                    conflict.date,
+                    conflict.bookings.clone(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            conflicts,
+            vec![(1, date(3), vec![booked]), (2, date(1), vec![on_holiday])]
+        );
+    }
+
+    #[test]
+    fn serializes_conflicts() {
+        let conflict = Conflict {
+            resource_id: 1,
+            date: date(2),
+            bookings: vec![70],
+            proposed: false,
+            kind: ConflictKind::OverAllocated {
+                booked: 540,
+                available: 480,
+            },
+        };
+
+        assert_eq!(
+            serde_json::to_value(conflict).unwrap(),
+            json!({
+                "resource_id": 1,
+                "date": "2026-06-02",
+                "bookings": [70],
+                "proposed": false,
+                "kind": "over_allocated",
+                "booked": 540,
+                "available": 480,
+            })
+        );
+    }
+}
@@ -0,0 +1,307 @@
+use std::error::Error;
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use http::{Method, Response, StatusCode};
+use log::info;
+use serde_json::{Map, Value};
+use thiserror::Error;
+use url::Url;
+
+use crate::api::{self, ApiError};
+
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum DryRunError<E>
+where
+    E: Error + Send + Sync + 'static,
+{
+    #[error("client error: {}", source)]
+    Client { source: E },
+    #[error("`http` error: {}", source)]
+    Http {
+        #[from]
+        source: http::Error,
+    },
+    #[error("invalid JSON body: {}", source)]
+    Body {
+        #[from]
+        source: serde_json::Error,
+    },
+}
+
+/// A write which a [`DryRunClient`] did not send.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlannedRequest {
+    pub method: Method,
+    pub url: String,
+    /// The JSON body, `None` for requests without a body.
+    pub body: Option<Value>,
+}
+
+/// A client which sends reads through another client, but only records writes.
+///
+/// `GET`, `HEAD` and `OPTIONS` requests are passed through. Any other request has its body
+/// checked to be JSON and is recorded and logged instead of being sent. `POST` and `PUT` get a
+/// response echoing the body with an `id` (the one from the URL, or `0` for new records), and
+/// `DELETE` gets an empty `204 No Content`.
+#[derive(Debug)]
+pub struct DryRunClient<C> {
+    client: C,
+    planned: Mutex<Vec<PlannedRequest>>,
+}
+
+impl<C> DryRunClient<C> {
+    pub fn new(client: C) -> Self {
+        Self {
+            client,
+            planned: Mutex::default(),
+        }
+    }
+
+    /// The writes which would have been sent, in order.
+    pub fn planned(&self) -> Vec<PlannedRequest> {
+        self.planned.lock().unwrap().clone()
+    }
+
+    pub fn into_inner(self) -> C {
+        self.client
+    }
+}
+
+impl<C> DryRunClient<C>
+where
+    C: api::RestClient,
+{
+    fn is_read(request: &http::request::Builder) -> bool {
+        matches!(
+            request.method_ref(),
+            None | Some(&Method::GET) | Some(&Method::HEAD) | Some(&Method::OPTIONS)
+        )
+    }
+
+    fn intercept(
+        &self,
+        request: http::request::Builder,
+        body: Vec<u8>,
+    ) -> Result<Response<Bytes>, DryRunError<C::Error>> {
+        let request = request.body(body)?;
+        let body = if request.body().is_empty() {
+            None
+        } else {
+            Some(serde_json::from_slice::<Value>(request.body())?)
+        };
+
+        let planned = PlannedRequest {
+            method: request.method().clone(),
+            url: request.uri().to_string().trim_end_matches('?').into(),
+            body,
+        };
+        info!(target: "teamdeck", "dry run: {} {}", planned.method, planned.url);
+
+        let response = synthetic_response(&planned)?;
+        self.planned.lock().unwrap().push(planned);
+
+        Ok(response)
+    }
+}
+
+fn synthetic_response(planned: &PlannedRequest) -> Result<Response<Bytes>, http::Error> {
+    if planned.method == Method::DELETE {
+        return Response::builder()
+            .status(StatusCode::NO_CONTENT)
+            .body(Bytes::new());
+    }
+
+    let id = Url::parse(&planned.url)
+        .ok()
+        .and_then(|url| {
+            url.path_segments()
+                .and_then(|mut segments| segments.next_back().map(str::to_owned))
+        })
+        .and_then(|segment| segment.parse::<u64>().ok());
+
+    let mut record = match &planned.body {
+        Some(Value::Object(fields)) => fields.clone(),
+        _ => Map::new(),
+    };
+    record.insert("id".into(), id.unwrap_or(0).into());
+
+    let status = if id.is_some() {
+        StatusCode::OK
+    } else {
+        StatusCode::CREATED
+    };
+
+    Response::builder()
+        .status(status)
+        .header("Content-Type", "application/json")
+        .body(Value::Object(record).to_string().into())
+}
+
+impl<C> api::RestClient for DryRunClient<C>
+where
+    C: api::RestClient,
+{
+    type Error = DryRunError<C::Error>;
+
+    fn rest_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>> {
+        self.client
+            .rest_endpoint(endpoint)
+            .map_err(|err| err.map_client(|source| DryRunError::Client { source }))
+    }
+}
+
+impl<C> api::Client for DryRunClient<C>
+where
+    C: api::Client,
+{
+    fn rest(
+        &self,
+        request: http::request::Builder,
+        body: Vec<u8>,
+    ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+        if Self::is_read(&request) {
+            return self
+                .client
+                .rest(request, body)
+                .map_err(|err| err.map_client(|source| DryRunError::Client { source }));
+        }
+
+        self.intercept(request, body).map_err(ApiError::client)
+    }
+}
+
+#[async_trait]
+impl<C> api::AsyncClient for DryRunClient<C>
+where
+    C: api::AsyncClient + Sync,
+{
+    async fn rest_async(
+        &self,
+        request: http::request::Builder,
+        body: Vec<u8>,
+    ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+        if Self::is_read(&request) {
+            return self
+                .client
+                .rest_async(request, body)
+                .await
+                .map_err(|err| err.map_client(|source| DryRunError::Client { source }));
+        }
+
+        self.intercept(request, body).map_err(ApiError::client)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use serde_json::json;
+
+    use super::*;
+    use crate::api::{
+        time_entries::{CreateTimeEntry, DeleteTimeEntry, TimeEntries, UpdateTimeEntry},
+        AsyncQuery, Query,
+    };
+    use crate::models;
+    use crate::testing::FakeTeamdeck;
+
+    fn date() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 1, 5).unwrap()
+    }
+
+    #[test]
+    fn passes_reads_through() {
+        let fake = FakeTeamdeck::new();
+        fake.insert("time-entries", json!({ "minutes": 30 }));
+        let client = DryRunClient::new(fake);
+
+        let entries: Vec<Value> = TimeEntries::builder()
+            .build()
+            .unwrap()
+            .query(&client)
+            .unwrap();
+
+        assert_eq!(entries.len(), 1);
+        assert!(client.planned().is_empty());
+    }
+
+    #[test]
+    fn records_writes_without_sending_them() {
+        let client = DryRunClient::new(FakeTeamdeck::new());
+
+        let created: models::TimeEntry = CreateTimeEntry::builder()
+            .resource_id(1)
+            .project_id(2)
+            .minutes(60)
+            .start_date(date())
+            .end_date(date())
+            .build()
+            .unwrap()
+            .query(&client)
+            .unwrap();
+        assert_eq!(created.id, 0);
+        assert_eq!(created.minutes, 60);
+
+        let updated: models::TimeEntry = UpdateTimeEntry::builder()
+            .id(7)
+            .resource_id(1)
+            .project_id(2)
+            .minutes(90)
+            .start_date(date())
+            .end_date(date())
+            .build()
+            .unwrap()
+            .query(&client)
+            .unwrap();
+        assert_eq!(updated.id, 7);
+
+        api::ignore(DeleteTimeEntry::builder().id(7).build().unwrap())
+            .query(&client)
+            .unwrap();
+
+        let planned = client.planned();
+        let methods = planned
+            .iter()
+            .map(|request| request.method.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(methods, vec![Method::POST, Method::PUT, Method::DELETE]);
+        assert_eq!(planned[1].url, "http://teamdeck.test/v1/time-entries/7");
+        assert_eq!(planned[1].body.as_ref().unwrap()["minutes"], 90);
+        assert_eq!(planned[2].body, None);
+
+        assert!(client.into_inner().records("time-entries").is_empty());
+    }
+
+    #[test]
+    fn rejects_invalid_bodies() {
+        let client = DryRunClient::new(FakeTeamdeck::new());
+        let request = http::Request::builder()
+            .method(Method::POST)
+            .uri("http://teamdeck.test/v1/time-entries");
+
+        let err = api::Client::rest(&client, request, b"minutes=60".to_vec()).unwrap_err();
+
+        assert!(matches!(
+            err,
+            ApiError::Client {
+                source: DryRunError::Body { .. }
+            }
+        ));
+        assert!(client.planned().is_empty());
+    }
+
+    #[tokio::test]
+    async fn records_async_writes() {
+        let client = DryRunClient::new(FakeTeamdeck::new());
+
+        api::ignore(DeleteTimeEntry::builder().id(3).build().unwrap())
+            .query_async(&client)
+            .await
+            .unwrap();
+
+        assert_eq!(client.planned().len(), 1);
+    }
+}
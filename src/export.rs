@@ -0,0 +1,357 @@
+//! CSV exports of paged lists such as time entries, bookings and vacations.
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::Write as _;
+use std::io;
+use std::str::FromStr;
+
+use chrono::NaiveDate;
+use serde_json::Value;
+use thiserror::Error;
+
+use crate::api::paged::Pageable;
+use crate::api::{
+    self, projects::Projects, resources::Resources, ApiError, Client, Endpoint, Pagination, Query,
+};
+
+/// Columns for exporting time entries.
+pub const TIME_ENTRY_COLUMNS: &[&str] = &[
+    "id",
+    "start_date",
+    "resource",
+    "project",
+    "minutes",
+    "description",
+];
+/// Columns for exporting bookings.
+pub const BOOKING_COLUMNS: &[&str] = &[
+    "id",
+    "resource",
+    "project",
+    "start_date",
+    "end_date",
+    "minutes",
+    "description",
+];
+/// Columns for exporting vacations.
+pub const VACATION_COLUMNS: &[&str] = &[
+    "id",
+    "resource",
+    "start_date",
+    "end_date",
+    "status",
+    "description",
+];
+
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum ExportError<E>
+where
+    E: Error + Send + Sync + 'static,
+{
+    #[error("failed to fetch the records: {}", source)]
+    Api {
+        #[from]
+        source: ApiError<E>,
+    },
+    #[error("failed to write CSV: {}", source)]
+    Csv {
+        #[from]
+        source: csv::Error,
+    },
+}
+
+#[derive(Debug, Error)]
+#[error("invalid date format `{}`", format)]
+pub struct InvalidDateFormat {
+    format: String,
+}
+
+#[derive(Debug, Error)]
+#[error(
+    "unknown duration format `{}`, expected minutes, hours or hours-minutes",
+    format
+)]
+pub struct UnknownDurationFormat {
+    format: String,
+}
+
+/// How the `minutes` field is written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DurationFormat {
+    /// Whole minutes, e.g. `90`.
+    #[default]
+    Minutes,
+    /// Decimal hours, e.g. `1.50`.
+    Hours,
+    /// Hours and minutes, e.g. `1:30`.
+    HoursMinutes,
+}
+
+impl DurationFormat {
+    pub fn format(self, minutes: u64) -> String {
+        match self {
+            DurationFormat::Minutes => minutes.to_string(),
+            DurationFormat::Hours => format!("{:.2}", minutes as f64 / 60.0),
+            DurationFormat::HoursMinutes => format!("{}:{:02}", minutes / 60, minutes % 60),
+        }
+    }
+}
+
+impl FromStr for DurationFormat {
+    type Err = UnknownDurationFormat;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "minutes" => Ok(DurationFormat::Minutes),
+            "hours" => Ok(DurationFormat::Hours),
+            "hours-minutes" => Ok(DurationFormat::HoursMinutes),
+            _ => Err(UnknownDurationFormat { format: s.into() }),
+        }
+    }
+}
+
+/// The names of resources and projects, by id.
+#[derive(Debug, Clone, Default)]
+pub struct Names {
+    resources: HashMap<u64, String>,
+    projects: HashMap<u64, String>,
+}
+
+impl Names {
+    pub fn new(resources: HashMap<u64, String>, projects: HashMap<u64, String>) -> Self {
+        Self {
+            resources,
+            projects,
+        }
+    }
+
+    /// Fetch the names of all the resources and projects.
+    pub fn fetch<C: Client>(client: &C) -> Result<Self, ApiError<C::Error>> {
+        fn names(records: Vec<Value>) -> HashMap<u64, String> {
+            records
+                .into_iter()
+                .filter_map(|record| {
+                    Some((record["id"].as_u64()?, record["name"].as_str()?.to_owned()))
+                })
+                .collect()
+        }
+
+        let resources =
+            api::paged(Resources::builder().build().unwrap(), Pagination::All).query(client)?;
+        let projects =
+            api::paged(Projects::builder().build().unwrap(), Pagination::All).query(client)?;
+
+        Ok(Self::new(names(resources), names(projects)))
+    }
+}
+
+/// Writes the records of a paged endpoint as CSV, one page at a time.
+///
+/// Columns are record fields, except `resource` and `project`, which are the names of the
+/// `resource_id` and `project_id` of the record. Dates are written in the date format, and the
+/// `minutes` field in the duration format.
+#[derive(Debug, Clone)]
+pub struct CsvExport {
+    columns: Vec<String>,
+    date_format: Option<String>,
+    durations: DurationFormat,
+    names: Names,
+}
+
+impl CsvExport {
+    pub fn new<I, S>(columns: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            columns: columns.into_iter().map(Into::into).collect(),
+            date_format: None,
+            durations: DurationFormat::default(),
+            names: Names::default(),
+        }
+    }
+
+    /// Write dates with a `strftime` format, such as `%d.%m.%Y`, instead of `YYYY-MM-DD`.
+    pub fn date_format(mut self, format: &str) -> Result<Self, InvalidDateFormat> {
+        let sample = NaiveDate::from_ymd_opt(2000, 1, 1).unwrap();
+        if write!(String::new(), "{}", sample.format(format)).is_err() {
+            return Err(InvalidDateFormat {
+                format: format.into(),
+            });
+        }
+
+        self.date_format = Some(format.into());
+        Ok(self)
+    }
+
+    pub fn durations(mut self, durations: DurationFormat) -> Self {
+        self.durations = durations;
+        self
+    }
+
+    /// The names for the `resource` and `project` columns.
+    pub fn names(mut self, names: Names) -> Self {
+        self.names = names;
+        self
+    }
+
+    fn name(names: &HashMap<u64, String>, id: &Value) -> String {
+        id.as_u64()
+            .and_then(|id| names.get(&id).cloned())
+            .unwrap_or_else(|| field(id))
+    }
+
+    fn cell(&self, record: &Value, column: &str) -> String {
+        match column {
+            "resource" => return Self::name(&self.names.resources, &record["resource_id"]),
+            "project" => return Self::name(&self.names.projects, &record["project_id"]),
+            _ => {}
+        }
+
+        let value = &record[column];
+        if let (Some(format), Some(date)) = (&self.date_format, value.as_str()) {
+            if let Ok(date) = date.parse::<NaiveDate>() {
+                return date.format(format).to_string();
+            }
+        }
+        if let (Some(minutes), "minutes") = (value.as_u64(), column) {
+            return self.durations.format(minutes);
+        }
+
+        field(value)
+    }
+
+    /// Write the records of `endpoint` to `out`, returning how many were written.
+    pub fn write<E, C, W>(
+        &self,
+        endpoint: E,
+        client: &C,
+        out: W,
+    ) -> Result<u64, ExportError<C::Error>>
+    where
+        E: Endpoint + Pageable,
+        C: Client,
+        W: io::Write,
+    {
+        let mut writer = csv::Writer::from_writer(out);
+        writer.write_record(&self.columns)?;
+
+        let mut count = 0;
+        let paged = api::paged(endpoint, Pagination::All);
+        for record in paged.iter::<Value, _>(client) {
+            let record = record?;
+            writer.write_record(self.columns.iter().map(|column| self.cell(&record, column)))?;
+            count += 1;
+        }
+        writer.flush().map_err(csv::Error::from)?;
+
+        Ok(count)
+    }
+}
+
+fn field(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(value) => value.clone(),
+        value => value.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::api::time_entries::TimeEntries;
+    use crate::testing::FakeTeamdeck;
+
+    use super::*;
+
+    fn teamdeck() -> FakeTeamdeck {
+        let teamdeck = FakeTeamdeck::new();
+        let resource_id = teamdeck.insert("resources", json!({ "name": "Ada Lovelace" }));
+        let project_id = teamdeck.insert("projects", json!({ "name": "Engine" }));
+        for day in 1..=12 {
+            let description = (day == 1).then_some("Notes, with a comma");
+            teamdeck.insert(
+                "time-entries",
+                json!({
+                    "resource_id": resource_id,
+                    "project_id": if day == 12 { 99 } else { project_id },
+                    "minutes": 90,
+                    "start_date": format!("2026-01-{:02}", day),
+                    "description": description,
+                }),
+            );
+        }
+
+        teamdeck
+    }
+
+    fn exported(export: CsvExport, teamdeck: &FakeTeamdeck) -> (u64, Vec<String>) {
+        let mut out = Vec::new();
+        let endpoint = TimeEntries::builder().build().unwrap();
+        let count = export.write(endpoint, teamdeck, &mut out).unwrap();
+        let lines = String::from_utf8(out)
+            .unwrap()
+            .lines()
+            .map(Into::into)
+            .collect();
+
+        (count, lines)
+    }
+
+    #[test]
+    fn writes_every_page_with_names() {
+        let teamdeck = teamdeck();
+        let export = CsvExport::new(TIME_ENTRY_COLUMNS.iter().copied())
+            .names(Names::fetch(&teamdeck).unwrap());
+
+        let (count, lines) = exported(export, &teamdeck);
+
+        assert_eq!(count, 12);
+        assert_eq!(
+            lines[0],
+            "id,start_date,resource,project,minutes,description"
+        );
+        assert_eq!(
+            lines[1],
+            "3,2026-01-01,Ada Lovelace,Engine,90,\"Notes, with a comma\""
+        );
+        // Unknown ids are written as they are.
+        assert_eq!(lines[12], "14,2026-01-12,Ada Lovelace,99,90,");
+    }
+
+    #[test]
+    fn formats_dates_and_durations() {
+        let teamdeck = teamdeck();
+        let export = CsvExport::new(["start_date", "minutes"])
+            .date_format("%d.%m.%Y")
+            .unwrap()
+            .durations(DurationFormat::HoursMinutes);
+
+        let (_, lines) = exported(export, &teamdeck);
+
+        assert_eq!(lines[1], "01.01.2026,1:30");
+    }
+
+    #[test]
+    fn formats_durations() {
+        assert_eq!(DurationFormat::Minutes.format(90), "90");
+        assert_eq!(DurationFormat::Hours.format(90), "1.50");
+        assert_eq!(DurationFormat::HoursMinutes.format(605), "10:05");
+        assert_eq!(
+            "hours-minutes".parse::<DurationFormat>().unwrap(),
+            DurationFormat::HoursMinutes
+        );
+        assert!("days".parse::<DurationFormat>().is_err());
+    }
+
+    #[test]
+    fn rejects_invalid_date_formats() {
+        assert!(CsvExport::new(["start_date"]).date_format("%Q").is_err());
+        assert!(CsvExport::new(["start_date"]).date_format("%H:%M").is_err());
+    }
+}
@@ -0,0 +1,350 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+
+use super::{escape, write_line};
+use crate::models::{Booking, Holiday, Vacation, VacationStatus};
+
+const PRODUCT: &str = "-//teamdeck-rs//Teamdeck calendar//EN";
+/// The domain of the event UIDs, which makes them unique across calendars.
+const UID_DOMAIN: &str = "teamdeck.io";
+
+/// An all-day event.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Event {
+    /// An identifier which stays the same across exports, derived from the Teamdeck id.
+    pub uid: String,
+    pub summary: String,
+    pub description: Option<String>,
+    pub category: &'static str,
+    pub start_date: NaiveDate,
+    /// The last day of the event, inclusive.
+    pub end_date: NaiveDate,
+    /// Whether the event is not confirmed yet, e.g. a pending vacation.
+    pub tentative: bool,
+    /// Whether the event makes the people it concerns unavailable.
+    pub busy: bool,
+}
+
+impl Event {
+    fn write(&self, out: &mut String, stamp: &str) {
+        write_line(out, "BEGIN:VEVENT");
+        write_line(out, &format!("UID:{}", self.uid));
+        write_line(out, &format!("DTSTAMP:{}", stamp));
+        write_line(
+            out,
+            &format!("DTSTART;VALUE=DATE:{}", self.start_date.format("%Y%m%d")),
+        );
+        // The end of all-day events is exclusive.
+        let end = self.end_date + Duration::days(1);
+        write_line(out, &format!("DTEND;VALUE=DATE:{}", end.format("%Y%m%d")));
+        write_line(out, &format!("SUMMARY:{}", escape(&self.summary)));
+        if let Some(description) = &self.description {
+            write_line(out, &format!("DESCRIPTION:{}", escape(description)));
+        }
+        write_line(out, &format!("CATEGORIES:{}", escape(self.category)));
+        let status = if self.tentative {
+            "TENTATIVE"
+        } else {
+            "CONFIRMED"
+        };
+        write_line(out, &format!("STATUS:{}", status));
+        let transparency = if self.busy { "OPAQUE" } else { "TRANSPARENT" };
+        write_line(out, &format!("TRANSP:{}", transparency));
+        write_line(out, "END:VEVENT");
+    }
+}
+
+/// A feed of vacations, holidays and bookings, written as iCalendar by its `Display`
+/// implementation.
+///
+/// Vacations and bookings are named after their resource and project when the names are known.
+/// Rejected vacations are left out, and pending ones are tentative.
+#[derive(Debug, Clone)]
+pub struct Calendar {
+    name: String,
+    stamp: DateTime<Utc>,
+    resources: Option<HashSet<u64>>,
+    resource_names: HashMap<u64, String>,
+    project_names: HashMap<u64, String>,
+    events: Vec<Event>,
+}
+
+impl Calendar {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            stamp: Utc::now(),
+            resources: None,
+            resource_names: HashMap::new(),
+            project_names: HashMap::new(),
+            events: Vec::new(),
+        }
+    }
+
+    /// Only include the vacations and bookings of these resources, for the feed of a person or a
+    /// team. Holidays are always included.
+    pub fn resources<I>(mut self, resources: I) -> Self
+    where
+        I: IntoIterator<Item = u64>,
+    {
+        self.resources = Some(resources.into_iter().collect());
+        self
+    }
+
+    pub fn resource_names(mut self, names: HashMap<u64, String>) -> Self {
+        self.resource_names = names;
+        self
+    }
+
+    pub fn project_names(mut self, names: HashMap<u64, String>) -> Self {
+        self.project_names = names;
+        self
+    }
+
+    /// The time the calendar was created, the current time by default.
+    pub fn stamp(mut self, stamp: DateTime<Utc>) -> Self {
+        self.stamp = stamp;
+        self
+    }
+
+    pub fn events(&self) -> &[Event] {
+        &self.events
+    }
+
+    fn includes(&self, resource_id: u64) -> bool {
+        self.resources
+            .as_ref()
+            .is_none_or(|resources| resources.contains(&resource_id))
+    }
+
+    fn name(names: &HashMap<u64, String>, id: u64) -> String {
+        names
+            .get(&id)
+            .cloned()
+            .unwrap_or_else(|| format!("#{}", id))
+    }
+
+    pub fn add_vacations<'v, I>(&mut self, vacations: I) -> &mut Self
+    where
+        I: IntoIterator<Item = &'v Vacation>,
+    {
+        for vacation in vacations {
+            if vacation.status == VacationStatus::Rejected || !self.includes(vacation.resource_id) {
+                continue;
+            }
+
+            let summary = format!(
+                "{}: Vacation",
+                Self::name(&self.resource_names, vacation.resource_id)
+            );
+            self.events.push(Event {
+                uid: format!("vacation-{}@{}", vacation.id, UID_DOMAIN),
+                summary,
+                description: vacation.description.clone(),
+                category: "Vacation",
+                start_date: vacation.start_date,
+                end_date: vacation.end_date,
+                tentative: vacation.status != VacationStatus::Approved,
+                busy: true,
+            });
+        }
+
+        self
+    }
+
+    pub fn add_holidays<'h, I>(&mut self, holidays: I) -> &mut Self
+    where
+        I: IntoIterator<Item = &'h Holiday>,
+    {
+        self.events
+            .extend(holidays.into_iter().map(|holiday| Event {
+                uid: format!("holiday-{}@{}", holiday.id, UID_DOMAIN),
+                summary: holiday.name.clone(),
+                description: None,
+                category: "Holiday",
+                start_date: holiday.date,
+                end_date: holiday.date,
+                tentative: false,
+                busy: true,
+            }));
+
+        self
+    }
+
+    pub fn add_bookings<'b, I>(&mut self, bookings: I) -> &mut Self
+    where
+        I: IntoIterator<Item = &'b Booking>,
+    {
+        for booking in bookings {
+            if !self.includes(booking.resource_id) {
+                continue;
+            }
+
+            let summary = format!(
+                "{}: {}",
+                Self::name(&self.resource_names, booking.resource_id),
+                Self::name(&self.project_names, booking.project_id)
+            );
+            let mut description = format!(
+                "{}h {:02}m per day",
+                booking.minutes / 60,
+                booking.minutes % 60
+            );
+            if let Some(notes) = &booking.description {
+                description = format!("{}\n{}", description, notes);
+            }
+
+            self.events.push(Event {
+                uid: format!("booking-{}@{}", booking.id, UID_DOMAIN),
+                summary,
+                description: Some(description),
+                category: "Booking",
+                start_date: booking.start_date,
+                end_date: booking.end_date,
+                tentative: false,
+                // Bookings are a plan, which leaves room for other events.
+                busy: false,
+            });
+        }
+
+        self
+    }
+}
+
+impl fmt::Display for Calendar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let stamp = self.stamp.format("%Y%m%dT%H%M%SZ").to_string();
+
+        let mut out = String::new();
+        write_line(&mut out, "BEGIN:VCALENDAR");
+        write_line(&mut out, "VERSION:2.0");
+        write_line(&mut out, &format!("PRODID:{}", PRODUCT));
+        write_line(&mut out, "CALSCALE:GREGORIAN");
+        write_line(&mut out, &format!("X-WR-CALNAME:{}", escape(&self.name)));
+
+        let mut events = self.events.iter().collect::<Vec<_>>();
+        events.sort_by(|a, b| (a.start_date, &a.uid).cmp(&(b.start_date, &b.uid)));
+        for event in events {
+            event.write(&mut out, &stamp);
+        }
+        write_line(&mut out, "END:VCALENDAR");
+
+        f.write_str(&out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 1, day).unwrap()
+    }
+
+    fn vacation(id: u64, resource_id: u64, status: VacationStatus) -> Vacation {
+        Vacation {
+            id,
+            resource_id,
+            start_date: date(5),
+            end_date: date(9),
+            status,
+            period_id: None,
+            reason_id: None,
+            description: None,
+            external_id: None,
+            approver_resource_id: None,
+        }
+    }
+
+    fn calendar() -> Calendar {
+        Calendar::new("Team")
+            .stamp(Utc.with_ymd_and_hms(2026, 1, 1, 8, 30, 0).unwrap())
+            .resource_names(HashMap::from([(1, "Ada".to_owned())]))
+            .project_names(HashMap::from([(7, "Engine".to_owned())]))
+    }
+
+    #[test]
+    fn writes_all_day_events() {
+        let mut calendar = calendar();
+        calendar
+            .add_vacations(&[vacation(3, 1, VacationStatus::Approved)])
+            .add_holidays(&[Holiday {
+                id: 4,
+                name: "Epiphany".into(),
+                date: date(6),
+                organization_unit_id: None,
+            }]);
+
+        assert_eq!(
+            calendar.to_string(),
+            "BEGIN:VCALENDAR\r\n\
+             VERSION:2.0\r\n\
+             PRODID:-//teamdeck-rs//Teamdeck calendar//EN\r\n\
+             CALSCALE:GREGORIAN\r\n\
+             X-WR-CALNAME:Team\r\n\
+             BEGIN:VEVENT\r\n\
+             UID:vacation-3@teamdeck.io\r\n\
+             DTSTAMP:20260101T083000Z\r\n\
+             DTSTART;VALUE=DATE:20260105\r\n\
+             DTEND;VALUE=DATE:20260110\r\n\
+             SUMMARY:Ada: Vacation\r\n\
+             CATEGORIES:Vacation\r\n\
+             STATUS:CONFIRMED\r\n\
+             TRANSP:OPAQUE\r\n\
+             END:VEVENT\r\n\
+             BEGIN:VEVENT\r\n\
+             UID:holiday-4@teamdeck.io\r\n\
+             DTSTAMP:20260101T083000Z\r\n\
+             DTSTART;VALUE=DATE:20260106\r\n\
+             DTEND;VALUE=DATE:20260107\r\n\
+             SUMMARY:Epiphany\r\n\
+             CATEGORIES:Holiday\r\n\
+             STATUS:CONFIRMED\r\n\
+             TRANSP:OPAQUE\r\n\
+             END:VEVENT\r\n\
+             END:VCALENDAR\r\n"
+        );
+    }
+
+    #[test]
+    fn feeds_only_include_their_resources() {
+        let mut calendar = calendar().resources([1]);
+        calendar
+            .add_vacations(&[
+                vacation(1, 1, VacationStatus::Pending),
+                vacation(2, 1, VacationStatus::Rejected),
+                vacation(3, 2, VacationStatus::Approved),
+            ])
+            .add_bookings(&[Booking {
+                id: 5,
+                resource_id: 1,
+                project_id: 7,
+                start_date: date(12),
+                end_date: date(16),
+                minutes: 270,
+                description: Some("Kick-off".into()),
+                external_id: None,
+            }]);
+
+        let events = calendar
+            .events()
+            .iter()
+            .map(|event| (event.uid.as_str(), event.summary.as_str(), event.tentative))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            events,
+            vec![
+                ("vacation-1@teamdeck.io", "Ada: Vacation", true),
+                ("booking-5@teamdeck.io", "Ada: Engine", false),
+            ]
+        );
+        assert_eq!(
+            calendar.events()[1].description.as_deref(),
+            Some("4h 30m per day\nKick-off")
+        );
+    }
+}
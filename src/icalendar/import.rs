@@ -0,0 +1,660 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use chrono::NaiveDate;
+use serde_json::Value;
+
+use super::parse::ParsedEvent;
+use crate::api::{
+    self,
+    holidays::{CreateHoliday, Holidays},
+    vacations::{CreateVacation, Vacations},
+    ApiError, Client, Pagination, Query,
+};
+use crate::models::{Holiday, Vacation, VacationStatus};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Target {
+    Holiday,
+    Vacation,
+    Skip,
+}
+
+/// What to turn the events it matches into. A rule without conditions matches every event.
+#[derive(Debug, Clone)]
+pub struct Rule {
+    target: Target,
+    category: Option<String>,
+    summary_contains: Option<String>,
+    organization_unit_id: Option<u64>,
+    reason_id: Option<u64>,
+    period_id: Option<u64>,
+    resource_id: Option<u64>,
+}
+
+impl Rule {
+    fn new(target: Target) -> Self {
+        Self {
+            target,
+            category: None,
+            summary_contains: None,
+            organization_unit_id: None,
+            reason_id: None,
+            period_id: None,
+            resource_id: None,
+        }
+    }
+
+    /// Create holidays, named after the summary.
+    pub fn holiday() -> Self {
+        Self::new(Target::Holiday)
+    }
+
+    /// Create vacations, for the resource of the rule or of the attendees.
+    pub fn vacation() -> Self {
+        Self::new(Target::Vacation)
+    }
+
+    /// Leave the events out.
+    pub fn skip() -> Self {
+        Self::new(Target::Skip)
+    }
+
+    /// Only match events with this category, ignoring case.
+    pub fn category(mut self, category: impl Into<String>) -> Self {
+        self.category = Some(category.into().to_lowercase());
+        self
+    }
+
+    /// Only match events whose summary contains this text, ignoring case.
+    pub fn summary_contains(mut self, text: impl Into<String>) -> Self {
+        self.summary_contains = Some(text.into().to_lowercase());
+        self
+    }
+
+    /// The organization unit of the holidays, which apply to everyone otherwise.
+    pub fn organization_unit(mut self, id: u64) -> Self {
+        self.organization_unit_id = Some(id);
+        self
+    }
+
+    /// The reason of the vacations.
+    pub fn reason(mut self, id: u64) -> Self {
+        self.reason_id = Some(id);
+        self
+    }
+
+    /// The period of the vacations.
+    pub fn period(mut self, id: u64) -> Self {
+        self.period_id = Some(id);
+        self
+    }
+
+    /// The resource of the vacations, for a feed of a single person.
+    pub fn resource(mut self, id: u64) -> Self {
+        self.resource_id = Some(id);
+        self
+    }
+
+    fn matches(&self, event: &ParsedEvent) -> bool {
+        let category = self.category.as_ref().is_none_or(|category| {
+            event
+                .categories
+                .iter()
+                .any(|candidate| candidate.to_lowercase() == *category)
+        });
+        let summary = self
+            .summary_contains
+            .as_ref()
+            .is_none_or(|text| event.summary.to_lowercase().contains(text));
+
+        category && summary
+    }
+}
+
+/// Why an event is not imported.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SkipReason {
+    /// No rule matches the event, or a skip rule does.
+    NoRule,
+    Cancelled,
+    /// None of the attendees is a known resource.
+    UnknownResource,
+    /// The holiday or vacation is already in Teamdeck.
+    AlreadyExists {
+        id: u64,
+    },
+    /// An earlier event of the file creates the same holiday or vacation.
+    Duplicate,
+}
+
+impl fmt::Display for SkipReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoRule => write!(f, "no rule matches"),
+            Self::Cancelled => write!(f, "cancelled"),
+            Self::UnknownResource => write!(f, "no attendee is a known resource"),
+            Self::AlreadyExists { id } => write!(f, "already exists as #{}", id),
+            Self::Duplicate => write!(f, "duplicate of an earlier event"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Planned {
+    Holiday(CreateHoliday<'static>),
+    Vacation(CreateVacation<'static>),
+    Skip(SkipReason),
+}
+
+/// What importing an event does, or one day of it for a holiday over several days.
+#[derive(Debug, Clone)]
+pub struct PlannedEvent {
+    pub event: ParsedEvent,
+    pub planned: Planned,
+}
+
+/// The key holidays are matched on.
+type HolidayKey = (NaiveDate, Option<u64>);
+
+/// The holidays and vacations already in Teamdeck, and the ones planned so far.
+#[derive(Default)]
+struct Known {
+    holidays: HashMap<HolidayKey, Option<u64>>,
+    external_ids: HashMap<String, Option<u64>>,
+    vacations: HashMap<(u64, NaiveDate, NaiveDate), Option<u64>>,
+}
+
+impl Known {
+    /// Whether the holiday or vacation exists, recording it otherwise.
+    fn check<K>(known: &mut HashMap<K, Option<u64>>, key: K) -> Option<SkipReason>
+    where
+        K: std::hash::Hash + Eq,
+    {
+        match known.get(&key) {
+            Some(Some(id)) => Some(SkipReason::AlreadyExists { id: *id }),
+            Some(None) => Some(SkipReason::Duplicate),
+            None => {
+                known.insert(key, None);
+                None
+            }
+        }
+    }
+}
+
+/// Plans the holidays and vacations to create from the events of iCalendar files.
+///
+/// The first rule matching an event decides what it becomes. Vacations keep the UID of their
+/// event as external id, which is how they are recognized on the next import; holidays are
+/// recognized by their date and organization unit. A holiday event over several days becomes a
+/// holiday on each day. Tentative events become pending vacations and cancelled events are
+/// skipped.
+#[derive(Debug, Clone)]
+pub struct IcsImport {
+    rules: Vec<Rule>,
+    resource_emails: HashMap<String, u64>,
+}
+
+impl IcsImport {
+    pub fn new(rules: Vec<Rule>) -> Self {
+        Self {
+            rules,
+            resource_emails: HashMap::new(),
+        }
+    }
+
+    /// The ids of resources by email, to find the resource of a vacation from the attendees.
+    pub fn resource_emails(mut self, emails: HashMap<String, u64>) -> Self {
+        self.resource_emails = emails
+            .into_iter()
+            .map(|(email, id)| (email.to_lowercase(), id))
+            .collect();
+        self
+    }
+
+    fn existing<C: Client>(
+        events: &[ParsedEvent],
+        client: &C,
+    ) -> Result<Known, ApiError<C::Error>> {
+        let mut known = Known::default();
+        let from = events.iter().map(|event| event.start_date).min();
+        let to = events.iter().map(|event| event.end_date).max();
+        let (from, to) = match (from, to) {
+            (Some(from), Some(to)) => (from, to),
+            _ => return Ok(known),
+        };
+
+        let endpoint = Holidays::builder().from(from).to(to).build().unwrap();
+        let holidays: Vec<Holiday> = api::paged(endpoint, Pagination::All).query(client)?;
+        for holiday in holidays {
+            known.holidays.insert(
+                (holiday.date, holiday.organization_unit_id),
+                Some(holiday.id),
+            );
+        }
+
+        let endpoint = Vacations::builder()
+            .end_date_from(from)
+            .start_date_to(to)
+            .build()
+            .unwrap();
+        let vacations: Vec<Vacation> = api::paged(endpoint, Pagination::All).query(client)?;
+        for vacation in vacations {
+            known.vacations.insert(
+                (vacation.resource_id, vacation.start_date, vacation.end_date),
+                Some(vacation.id),
+            );
+        }
+
+        // An event moved to other days keeps its uid, so imported vacations are looked up by
+        // their external id whatever their dates.
+        let mut uids = events
+            .iter()
+            .map(|event| event.uid.clone())
+            .collect::<Vec<_>>();
+        uids.sort();
+        uids.dedup();
+        let endpoint = Vacations::builder().external_id(uids).build().unwrap();
+        let vacations: Vec<Vacation> = api::paged(endpoint, Pagination::All).query(client)?;
+        for vacation in vacations {
+            if let Some(external_id) = vacation.external_id {
+                known.external_ids.insert(external_id, Some(vacation.id));
+            }
+        }
+
+        Ok(known)
+    }
+
+    fn resource(&self, rule: &Rule, event: &ParsedEvent) -> Option<u64> {
+        rule.resource_id.or_else(|| {
+            event
+                .emails
+                .iter()
+                .find_map(|email| self.resource_emails.get(email).copied())
+        })
+    }
+
+    fn plan_holiday(
+        rule: &Rule,
+        event: &ParsedEvent,
+        date: NaiveDate,
+        known: &mut Known,
+    ) -> Planned {
+        if let Some(reason) = Known::check(&mut known.holidays, (date, rule.organization_unit_id)) {
+            return Planned::Skip(reason);
+        }
+
+        let mut builder = CreateHoliday::builder();
+        builder
+            .name(event.summary.clone())
+            .date(date)
+            .organization_unit_id(rule.organization_unit_id);
+        Planned::Holiday(builder.build().unwrap())
+    }
+
+    fn plan_vacation(&self, rule: &Rule, event: &ParsedEvent, known: &mut Known) -> Planned {
+        let resource_id = match self.resource(rule, event) {
+            Some(resource_id) => resource_id,
+            None => return Planned::Skip(SkipReason::UnknownResource),
+        };
+        if let Some(reason) = Known::check(&mut known.external_ids, event.uid.clone()) {
+            return Planned::Skip(reason);
+        }
+        let key = (resource_id, event.start_date, event.end_date);
+        if let Some(reason) = Known::check(&mut known.vacations, key) {
+            return Planned::Skip(reason);
+        }
+
+        let status = if event.status.as_deref() == Some("TENTATIVE") {
+            VacationStatus::Pending
+        } else {
+            VacationStatus::Approved
+        };
+        let mut builder = CreateVacation::builder();
+        builder
+            .resource_id(resource_id)
+            .start_date(event.start_date)
+            .end_date(event.end_date)
+            .status(Some(status))
+            .period_id(rule.period_id)
+            .reason_id(rule.reason_id)
+            .external_id(Some(event.uid.clone().into()));
+        if !event.summary.is_empty() {
+            builder.description(Some(event.summary.clone().into()));
+        }
+        Planned::Vacation(builder.build().unwrap())
+    }
+
+    fn plan_event(&self, event: &ParsedEvent, known: &mut Known) -> Vec<Planned> {
+        let rule = match self.rules.iter().find(|rule| rule.matches(event)) {
+            Some(rule) if rule.target != Target::Skip => rule,
+            _ => return vec![Planned::Skip(SkipReason::NoRule)],
+        };
+        if event.status.as_deref() == Some("CANCELLED") {
+            return vec![Planned::Skip(SkipReason::Cancelled)];
+        }
+
+        if rule.target == Target::Holiday {
+            // Holidays are single days, an event over several days is one holiday per day.
+            return event
+                .start_date
+                .iter_days()
+                .take_while(|date| *date <= event.end_date)
+                .map(|date| Self::plan_holiday(rule, event, date, known))
+                .collect();
+        }
+
+        vec![self.plan_vacation(rule, event, known)]
+    }
+
+    /// Decide what to do with each event, looking up the holidays and vacations already over the
+    /// dates of the events.
+    pub fn plan<C: Client>(
+        &self,
+        events: Vec<ParsedEvent>,
+        client: &C,
+    ) -> Result<Vec<PlannedEvent>, ApiError<C::Error>> {
+        let mut known = Self::existing(&events, client)?;
+
+        Ok(events
+            .iter()
+            .flat_map(|event| {
+                self.plan_event(event, &mut known)
+                    .into_iter()
+                    .map(|planned| PlannedEvent {
+                        event: event.clone(),
+                        planned,
+                    })
+            })
+            .collect())
+    }
+}
+
+/// Create the planned holidays and vacations, with one result per event and none for skipped
+/// events. Every event is sent, even after a failure.
+pub fn apply<C: Client>(
+    plan: &[PlannedEvent],
+    client: &C,
+) -> Vec<Option<Result<Value, ApiError<C::Error>>>> {
+    plan.iter()
+        .map(|planned| match &planned.planned {
+            Planned::Holiday(endpoint) => Some(endpoint.query(client)),
+            Planned::Vacation(endpoint) => Some(endpoint.query(client)),
+            Planned::Skip(_) => None,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::super::parse::parse;
+    use super::*;
+    use crate::testing::FakeTeamdeck;
+
+    const FEED: &str = "\
+BEGIN:VCALENDAR
+BEGIN:VEVENT
+UID:new-year-2027
+DTSTART;VALUE=DATE:20270101
+DTEND;VALUE=DATE:20270102
+SUMMARY:New Year's Day
+CATEGORIES:Public holiday
+END:VEVENT
+BEGIN:VEVENT
+UID:christmas-2026
+DTSTART;VALUE=DATE:20261225
+SUMMARY:Christmas Day
+CATEGORIES:Public holiday
+END:VEVENT
+BEGIN:VEVENT
+UID:leave-1
+DTSTART;VALUE=DATE:20261228
+DTEND;VALUE=DATE:20261231
+SUMMARY:Winter leave
+CATEGORIES:Leave
+ATTENDEE:mailto:ada@example.com
+STATUS:TENTATIVE
+END:VEVENT
+BEGIN:VEVENT
+UID:leave-2
+DTSTART;VALUE=DATE:20261221
+DTEND;VALUE=DATE:20261223
+SUMMARY:Sick leave
+CATEGORIES:Leave
+ATTENDEE:mailto:grace@example.com
+END:VEVENT
+BEGIN:VEVENT
+UID:leave-3
+DTSTART;VALUE=DATE:20261221
+SUMMARY:Dentist
+CATEGORIES:Leave
+ATTENDEE:mailto:ada@example.com
+STATUS:CANCELLED
+END:VEVENT
+BEGIN:VEVENT
+UID:leave-1
+DTSTART;VALUE=DATE:20261228
+DTEND;VALUE=DATE:20261231
+SUMMARY:Winter leave
+CATEGORIES:Leave
+ATTENDEE:mailto:ada@example.com
+END:VEVENT
+BEGIN:VEVENT
+UID:standup
+DTSTART:20261221T090000Z
+SUMMARY:Standup
+END:VEVENT
+END:VCALENDAR
+";
+
+    fn import() -> IcsImport {
+        IcsImport::new(vec![
+            Rule::holiday()
+                .category("public holiday")
+                .organization_unit(4),
+            Rule::vacation()
+                .category("leave")
+                .summary_contains("sick")
+                .reason(9),
+            Rule::vacation().category("leave").reason(8).period(2),
+        ])
+        .resource_emails(HashMap::from([
+            ("Ada@Example.com".to_owned(), 1),
+            ("grace@example.com".to_owned(), 2),
+        ]))
+    }
+
+    fn describe(plan: &[PlannedEvent]) -> Vec<(&str, String)> {
+        plan.iter()
+            .map(|planned| {
+                let planned_as = match &planned.planned {
+                    Planned::Holiday(_) => "holiday".to_owned(),
+                    Planned::Vacation(_) => "vacation".to_owned(),
+                    Planned::Skip(reason) => reason.to_string(),
+                };
+                (planned.event.uid.as_str(), planned_as)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn maps_events_with_the_first_matching_rule() {
+        let teamdeck = FakeTeamdeck::new();
+
+        let plan = import().plan(parse(FEED).unwrap(), &teamdeck).unwrap();
+
+        assert_eq!(
+            describe(&plan),
+            vec![
+                ("new-year-2027", "holiday".to_owned()),
+                ("christmas-2026", "holiday".to_owned()),
+                ("leave-1", "vacation".to_owned()),
+                ("leave-2", "vacation".to_owned()),
+                ("leave-3", "cancelled".to_owned()),
+                ("leave-1", "duplicate of an earlier event".to_owned()),
+                ("standup", "no rule matches".to_owned()),
+            ]
+        );
+
+        let results = apply(&plan, &teamdeck);
+        assert!(results.iter().flatten().all(Result::is_ok));
+        assert_eq!(
+            teamdeck.records("holidays")[0],
+            json!({
+                "id": 1,
+                "name": "New Year's Day",
+                "date": "2027-01-01",
+                "organization_unit_id": 4,
+            })
+        );
+        let vacations = teamdeck.records("vacations");
+        assert_eq!(vacations[0]["resource_id"], 1);
+        assert_eq!(vacations[0]["end_date"], "2026-12-30");
+        assert_eq!(vacations[0]["status"], "pending");
+        assert_eq!(vacations[0]["external_id"], "leave-1");
+        assert_eq!(vacations[0]["reason_id"], 8);
+        assert_eq!(vacations[0]["period_id"], 2);
+        assert_eq!(vacations[1]["status"], "approved");
+        assert_eq!(vacations[1]["reason_id"], 9);
+    }
+
+    #[test]
+    fn skips_what_is_already_imported() {
+        let teamdeck = FakeTeamdeck::new();
+        let christmas = teamdeck.insert(
+            "holidays",
+            json!({ "name": "Christmas", "date": "2026-12-25", "organization_unit_id": 4 }),
+        );
+        teamdeck.insert(
+            "holidays",
+            json!({ "name": "New Year's Day", "date": "2027-01-01", "organization_unit_id": 5 }),
+        );
+        let leave = teamdeck.insert(
+            "vacations",
+            json!({
+                "resource_id": 1,
+                "start_date": "2026-12-28",
+                "end_date": "2026-12-30",
+                "status": "approved",
+                "external_id": "leave-1",
+            }),
+        );
+        let sick = teamdeck.insert(
+            "vacations",
+            json!({
+                "resource_id": 2,
+                "start_date": "2026-12-21",
+                "end_date": "2026-12-22",
+                "status": "approved",
+            }),
+        );
+
+        let plan = import().plan(parse(FEED).unwrap(), &teamdeck).unwrap();
+
+        let describe = describe(&plan);
+        assert_eq!(describe[0], ("new-year-2027", "holiday".to_owned()));
+        assert_eq!(
+            describe[1..4],
+            [
+                (
+                    "christmas-2026",
+                    SkipReason::AlreadyExists { id: christmas }.to_string()
+                ),
+                (
+                    "leave-1",
+                    SkipReason::AlreadyExists { id: leave }.to_string()
+                ),
+                (
+                    "leave-2",
+                    SkipReason::AlreadyExists { id: sick }.to_string()
+                ),
+            ]
+        );
+        assert_eq!(
+            describe[5],
+            (
+                "leave-1",
+                SkipReason::AlreadyExists { id: leave }.to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn skips_an_event_imported_on_other_days() {
+        let teamdeck = FakeTeamdeck::new();
+        let leave = teamdeck.insert(
+            "vacations",
+            json!({
+                "resource_id": 1,
+                "start_date": "2026-11-02",
+                "end_date": "2026-11-04",
+                "status": "approved",
+                "external_id": "leave-1",
+            }),
+        );
+        let feed = "BEGIN:VEVENT\nUID:leave-1\nDTSTART;VALUE=DATE:20261228\n\
+                    CATEGORIES:Leave\nATTENDEE:mailto:ada@example.com\nEND:VEVENT\n";
+
+        let plan = import().plan(parse(feed).unwrap(), &teamdeck).unwrap();
+
+        assert_eq!(
+            describe(&plan),
+            vec![(
+                "leave-1",
+                SkipReason::AlreadyExists { id: leave }.to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn plans_a_holiday_on_each_day_of_an_event() {
+        let teamdeck = FakeTeamdeck::new();
+        let christmas = teamdeck.insert(
+            "holidays",
+            json!({ "name": "Christmas", "date": "2026-12-25", "organization_unit_id": 4 }),
+        );
+        let feed = "BEGIN:VEVENT\nUID:break\nDTSTART;VALUE=DATE:20261224\n\
+                    DTEND;VALUE=DATE:20261227\nSUMMARY:Christmas break\n\
+                    CATEGORIES:Public holiday\nEND:VEVENT\n";
+
+        let plan = import().plan(parse(feed).unwrap(), &teamdeck).unwrap();
+        assert_eq!(
+            describe(&plan),
+            vec![
+                ("break", "holiday".to_owned()),
+                (
+                    "break",
+                    SkipReason::AlreadyExists { id: christmas }.to_string()
+                ),
+                ("break", "holiday".to_owned()),
+            ]
+        );
+
+        apply(&plan, &teamdeck);
+        let dates = teamdeck
+            .records("holidays")
+            .iter()
+            .map(|holiday| holiday["date"].as_str().unwrap().to_owned())
+            .collect::<Vec<_>>();
+        assert_eq!(dates, vec!["2026-12-25", "2026-12-24", "2026-12-26"]);
+    }
+
+    #[test]
+    fn vacations_need_a_resource() {
+        let teamdeck = FakeTeamdeck::new();
+        let feed = "BEGIN:VEVENT\nUID:x\nDTSTART:20260105\nCATEGORIES:Leave\n\
+                    ATTENDEE:mailto:someone@example.com\nEND:VEVENT\n";
+
+        let plan = import().plan(parse(feed).unwrap(), &teamdeck).unwrap();
+        assert_eq!(
+            describe(&plan),
+            vec![("x", "no attendee is a known resource".to_owned())]
+        );
+
+        let import = IcsImport::new(vec![Rule::vacation().resource(3)]);
+        let plan = import.plan(parse(feed).unwrap(), &teamdeck).unwrap();
+        assert!(matches!(plan[0].planned, Planned::Vacation(_)));
+    }
+}
@@ -0,0 +1,72 @@
+//! iCalendar ([RFC 5545](https://www.rfc-editor.org/rfc/rfc5545)) feeds of vacations, holidays
+//! and bookings, and imports of holidays and vacations from other calendars.
+
+mod calendar;
+mod import;
+mod parse;
+
+pub use self::calendar::{Calendar, Event};
+pub use self::import::{apply, IcsImport, Planned, PlannedEvent, Rule, SkipReason};
+pub use self::parse::{parse, ParseError, ParsedEvent};
+
+/// The longest a content line may be, in octets, before it is folded.
+const LINE_LENGTH: usize = 75;
+
+/// Escape a text value.
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' | ';' | ',' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {}
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+/// Write a content line, folding it into lines of at most 75 octets without splitting a
+/// character.
+fn write_line(out: &mut String, line: &str) {
+    let mut length = 0;
+    for c in line.chars() {
+        if length + c.len_utf8() > LINE_LENGTH {
+            out.push_str("\r\n ");
+            // The leading space counts towards the length of the continuation line.
+            length = 1;
+        }
+        out.push(c);
+        length += c.len_utf8();
+    }
+    out.push_str("\r\n");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escapes_text() {
+        assert_eq!(
+            escape("Trip; Paris, London\\\r\nback"),
+            "Trip\\; Paris\\, London\\\\\\nback"
+        );
+    }
+
+    #[test]
+    fn folds_long_lines() {
+        let mut out = String::new();
+        write_line(&mut out, &format!("SUMMARY:{}", "é".repeat(40)));
+
+        let lines = out.split("\r\n").collect::<Vec<_>>();
+        assert_eq!(lines.len(), 3);
+        assert!(lines.iter().all(|line| line.len() <= LINE_LENGTH));
+        assert!(lines[1].starts_with(' '));
+        assert_eq!(lines[2], "");
+    }
+}
@@ -0,0 +1,381 @@
+use chrono::{Duration, NaiveDate};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum ParseError {
+    #[error("line {}: expected `NAME:value`", line)]
+    InvalidLine { line: usize },
+    #[error("line {}: invalid date `{}`", line, value)]
+    InvalidDate { line: usize, value: String },
+    #[error("line {}: the event has no {}", line, property)]
+    MissingProperty { line: usize, property: &'static str },
+}
+
+/// An event read from an iCalendar file, as whole days.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsedEvent {
+    /// The line the event starts on.
+    pub line: usize,
+    pub uid: String,
+    pub summary: String,
+    pub description: Option<String>,
+    pub categories: Vec<String>,
+    pub start_date: NaiveDate,
+    /// The last day of the event, inclusive.
+    pub end_date: NaiveDate,
+    /// The `STATUS`, e.g. `TENTATIVE` or `CANCELLED`.
+    pub status: Option<String>,
+    /// The email addresses of the attendees and organizer.
+    pub emails: Vec<String>,
+}
+
+/// A content line, unfolded.
+struct Property<'a> {
+    line: usize,
+    name: String,
+    params: Vec<&'a str>,
+    value: &'a str,
+}
+
+/// Join folded lines, keeping the number of the line each content line starts on.
+fn unfold(text: &str) -> Vec<(usize, String)> {
+    let mut lines: Vec<(usize, String)> = Vec::new();
+
+    for (index, line) in text.lines().enumerate() {
+        let line = line.strip_suffix('\r').unwrap_or(line);
+        match (line.strip_prefix([' ', '\t']), lines.last_mut()) {
+            (Some(continuation), Some((_, previous))) => previous.push_str(continuation),
+            _ if line.is_empty() => {}
+            _ => lines.push((index + 1, line.to_owned())),
+        }
+    }
+
+    lines
+}
+
+fn property(line: usize, text: &str) -> Result<Property<'_>, ParseError> {
+    // Parameter values may be quoted and contain colons, the value starts at the first colon
+    // outside quotes.
+    let mut quoted = false;
+    let colon = text
+        .char_indices()
+        .find(|&(_, c)| {
+            if c == '"' {
+                quoted = !quoted;
+            }
+            c == ':' && !quoted
+        })
+        .map(|(index, _)| index)
+        .ok_or(ParseError::InvalidLine { line })?;
+
+    let (name, value) = (&text[..colon], &text[colon + 1..]);
+    let mut params = name.split(';');
+    let name = params.next().unwrap_or_default().to_uppercase();
+
+    Ok(Property {
+        line,
+        name,
+        params: params.collect(),
+        value,
+    })
+}
+
+fn unescape(text: &str) -> String {
+    let mut unescaped = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        match (c, chars.clone().next()) {
+            ('\\', Some('n' | 'N')) => {
+                unescaped.push('\n');
+                chars.next();
+            }
+            ('\\', Some(escaped @ ('\\' | ';' | ',' | ':'))) => {
+                unescaped.push(escaped);
+                chars.next();
+            }
+            (c, _) => unescaped.push(c),
+        }
+    }
+
+    unescaped
+}
+
+/// The values of a comma separated list, leaving escaped commas in the values.
+fn split_list(text: &str) -> Vec<&str> {
+    let mut values = Vec::new();
+    let mut start = 0;
+    let mut escaped = false;
+    for (index, c) in text.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            ',' => {
+                values.push(&text[start..index]);
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+    values.push(&text[start..]);
+
+    values
+}
+
+/// The day of a `DATE` or `DATE-TIME` value, and whether it is a date-time.
+fn day(property: &Property) -> Result<(NaiveDate, bool), ParseError> {
+    let invalid = || ParseError::InvalidDate {
+        line: property.line,
+        value: property.value.into(),
+    };
+
+    let date = property.value.get(..8).ok_or_else(invalid)?;
+    let date = NaiveDate::parse_from_str(date, "%Y%m%d").map_err(|_| invalid())?;
+
+    Ok((date, property.value.len() > 8))
+}
+
+/// Whether a `DATE-TIME` value is at midnight, which ends an event on the day before.
+fn is_midnight(value: &str) -> bool {
+    value.get(9..15) == Some("000000")
+}
+
+/// The number of days of a `DURATION` such as `P1D` or `P2W`, rounded up.
+fn duration_days(property: &Property) -> Option<i64> {
+    let value = property.value.strip_prefix('P')?;
+    let (days, rest) = match value.split_once('W') {
+        Some((weeks, rest)) => (weeks.parse::<i64>().ok()? * 7, rest),
+        None => match value.split_once('D') {
+            Some((days, rest)) => (days.parse::<i64>().ok()?, rest),
+            None => (0, value),
+        },
+    };
+
+    // Any time part makes for part of another day.
+    Some(if rest.starts_with('T') {
+        days + 1
+    } else {
+        days
+    })
+}
+
+#[derive(Default)]
+struct EventBuilder {
+    line: usize,
+    uid: Option<String>,
+    summary: Option<String>,
+    description: Option<String>,
+    categories: Vec<String>,
+    start: Option<NaiveDate>,
+    end: Option<NaiveDate>,
+    duration: Option<i64>,
+    status: Option<String>,
+    emails: Vec<String>,
+}
+
+impl EventBuilder {
+    fn set(&mut self, property: &Property) -> Result<(), ParseError> {
+        match property.name.as_str() {
+            "UID" => self.uid = Some(property.value.into()),
+            "SUMMARY" => self.summary = Some(unescape(property.value)),
+            "DESCRIPTION" => self.description = Some(unescape(property.value)),
+            "CATEGORIES" => self.categories.extend(
+                split_list(property.value)
+                    .into_iter()
+                    .map(|category| unescape(category.trim())),
+            ),
+            "DTSTART" => self.start = Some(day(property)?.0),
+            "DTEND" => {
+                let (date, is_date_time) = day(property)?;
+                // All-day events end on the day after, and so do events until midnight.
+                let exclusive = !is_date_time || is_midnight(property.value);
+                self.end = Some(if exclusive {
+                    date - Duration::days(1)
+                } else {
+                    date
+                });
+            }
+            "DURATION" => self.duration = duration_days(property),
+            "STATUS" => self.status = Some(property.value.to_uppercase()),
+            "ATTENDEE" | "ORGANIZER" => {
+                let address = property.value.trim();
+                let email = address
+                    .get(..7)
+                    .filter(|scheme| scheme.eq_ignore_ascii_case("mailto:"))
+                    .map(|_| &address[7..])
+                    .or_else(|| {
+                        property
+                            .params
+                            .iter()
+                            .find_map(|param| param.strip_prefix("EMAIL="))
+                    });
+                if let Some(email) = email {
+                    self.emails.push(email.trim_matches('"').to_lowercase());
+                }
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    fn build(self) -> Result<ParsedEvent, ParseError> {
+        let missing = |property| ParseError::MissingProperty {
+            line: self.line,
+            property,
+        };
+
+        let start_date = self.start.ok_or_else(|| missing("DTSTART"))?;
+        let end_date = match (self.end, self.duration) {
+            (Some(end), _) => end,
+            (None, Some(days)) => start_date + Duration::days(days - 1),
+            // Without an end, dates last the day and date-times are instants.
+            (None, None) => start_date,
+        }
+        .max(start_date);
+
+        Ok(ParsedEvent {
+            line: self.line,
+            uid: self.uid.ok_or_else(|| missing("UID"))?,
+            summary: self.summary.unwrap_or_default(),
+            description: self.description,
+            categories: self.categories,
+            start_date,
+            end_date,
+            status: self.status,
+            emails: self.emails,
+        })
+    }
+}
+
+/// Read the events of an iCalendar file. Times are dropped: events last the days they touch.
+pub fn parse(text: &str) -> Result<Vec<ParsedEvent>, ParseError> {
+    let mut events = Vec::new();
+    let mut event: Option<EventBuilder> = None;
+    // Components nested in events, such as alarms, whose properties are not the event's.
+    let mut depth = 0;
+
+    for (line, text) in unfold(text) {
+        let property = property(line, &text)?;
+        let value = property.value.to_uppercase();
+
+        match (property.name.as_str(), value.as_str(), &mut event) {
+            ("BEGIN", "VEVENT", None) => {
+                event = Some(EventBuilder {
+                    line,
+                    ..EventBuilder::default()
+                })
+            }
+            ("END", "VEVENT", Some(_)) if depth == 0 => {
+                events.push(event.take().unwrap().build()?);
+            }
+            ("BEGIN", _, Some(_)) => depth += 1,
+            ("END", _, Some(_)) => depth -= 1,
+            (_, _, Some(event)) if depth == 0 => event.set(&property)?,
+            _ => {}
+        }
+    }
+
+    Ok(events)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, month, day).unwrap()
+    }
+
+    const FEED: &str = "BEGIN:VCALENDAR\r\n\
+VERSION:2.0\r\n\
+BEGIN:VEVENT\r\n\
+UID:leave-1@hr.example.com\r\n\
+DTSTART;VALUE=DATE:20260803\r\n\
+DTEND;VALUE=DATE:20260815\r\n\
+SUMMARY:Summer leave\\, Italy\r\n\
+DESCRIPTION:Back on\\nMonday\r\n\
+CATEGORIES:Leave,Approved\r\n\
+ATTENDEE;CN=\"Lovelace, Ada\":mailto:Ada@Example.com\r\n\
+BEGIN:VALARM\r\n\
+DESCRIPTION:Reminder\r\n\
+END:VALARM\r\n\
+END:VEVENT\r\n\
+BEGIN:VEVENT\r\n\
+UID:holiday-2026-12-25\r\n\
+DTSTART:20261225T000000\r\n\
+DTEND:20261226T000000\r\n\
+SUMMARY:Christmas \r\n Day\r\n\
+STATUS:confirmed\r\n\
+END:VEVENT\r\n\
+BEGIN:VEVENT\r\n\
+UID:offsite\r\n\
+DTSTART:20260310T090000Z\r\n\
+DURATION:P2DT4H\r\n\
+END:VEVENT\r\n\
+END:VCALENDAR\r\n";
+
+    #[test]
+    fn reads_events_as_days() {
+        let events = parse(FEED).unwrap();
+
+        let days = events
+            .iter()
+            .map(|event| (event.uid.as_str(), event.start_date, event.end_date))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            days,
+            vec![
+                ("leave-1@hr.example.com", date(8, 3), date(8, 14)),
+                ("holiday-2026-12-25", date(12, 25), date(12, 25)),
+                ("offsite", date(3, 10), date(3, 12)),
+            ]
+        );
+    }
+
+    #[test]
+    fn reads_text_properties() {
+        let events = parse(FEED).unwrap();
+
+        assert_eq!(events[0].line, 3);
+        assert_eq!(events[0].summary, "Summer leave, Italy");
+        assert_eq!(events[0].description.as_deref(), Some("Back on\nMonday"));
+        assert_eq!(events[0].categories, vec!["Leave", "Approved"]);
+        assert_eq!(events[0].emails, vec!["ada@example.com"]);
+        assert_eq!(events[1].summary, "Christmas Day");
+        assert_eq!(events[1].status.as_deref(), Some("CONFIRMED"));
+    }
+
+    #[test]
+    fn keeps_escaped_commas_in_categories() {
+        let feed = "BEGIN:VEVENT\nUID:1\nDTSTART;VALUE=DATE:20260101\n\
+                    CATEGORIES:Leave\\, paid,Approved\nEND:VEVENT\n";
+
+        let events = parse(feed).unwrap();
+
+        assert_eq!(events[0].categories, vec!["Leave, paid", "Approved"]);
+    }
+
+    #[test]
+    fn reports_invalid_events() {
+        let feed = "BEGIN:VEVENT\nUID:1\nDTSTART:2026\nEND:VEVENT\n";
+        assert!(matches!(
+            parse(feed),
+            Err(ParseError::InvalidDate { line: 3, .. })
+        ));
+
+        let feed = "BEGIN:VEVENT\nDTSTART:20260101\nEND:VEVENT\n";
+        assert!(matches!(
+            parse(feed),
+            Err(ParseError::MissingProperty {
+                line: 1,
+                property: "UID"
+            })
+        ));
+
+        assert!(matches!(
+            parse("BEGIN:VEVENT\nnonsense\n"),
+            Err(ParseError::InvalidLine { line: 2 })
+        ));
+    }
+}
@@ -0,0 +1,714 @@
+//! Imports of time entries from CSV or JSON Lines, validated and previewed before anything is
+//! sent.
+
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::io::{self, BufRead};
+use std::str::FromStr;
+
+use chrono::NaiveDate;
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+
+use crate::api::{
+    self, projects::Projects, resources::Resources, time_entries::CreateTimeEntry, ApiError,
+    Client, FieldChange, Pagination, Query, Upserted,
+};
+
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum ImportError {
+    #[error("failed to read the file: {}", source)]
+    Io {
+        #[from]
+        source: io::Error,
+    },
+    #[error("invalid CSV: {}", source)]
+    Csv {
+        #[from]
+        source: csv::Error,
+    },
+    #[error("line {}: invalid JSON: {}", line, source)]
+    Json {
+        line: usize,
+        source: serde_json::Error,
+    },
+    #[error("line {}: expected a JSON object", line)]
+    NotAnObject { line: usize },
+}
+
+#[derive(Debug, Error)]
+#[error("unknown field `{}`", field)]
+pub struct UnknownField {
+    field: String,
+}
+
+/// A row read from a file, as text values by column.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceRow {
+    /// The line of the row in the file, starting at 1.
+    pub line: usize,
+    pub values: HashMap<String, String>,
+}
+
+/// Read rows from CSV with a header row.
+pub fn read_csv<R: io::Read>(reader: R) -> Result<Vec<SourceRow>, ImportError> {
+    let mut reader = csv::Reader::from_reader(reader);
+    let headers = reader.headers()?.clone();
+
+    reader
+        .records()
+        .map(|record| {
+            let record = record?;
+            let line = record
+                .position()
+                .map_or(0, |position| position.line() as usize);
+            let values = headers
+                .iter()
+                .zip(record.iter())
+                .map(|(header, value)| (header.to_owned(), value.to_owned()))
+                .collect();
+
+            Ok(SourceRow { line, values })
+        })
+        .collect()
+}
+
+/// Read rows from JSON Lines, one object per line. Blank lines are skipped.
+pub fn read_json_lines<R: BufRead>(reader: R) -> Result<Vec<SourceRow>, ImportError> {
+    let mut rows = Vec::new();
+
+    for (index, text) in reader.lines().enumerate() {
+        let line = index + 1;
+        let text = text?;
+        if text.trim().is_empty() {
+            continue;
+        }
+
+        let object = match serde_json::from_str(&text) {
+            Ok(Value::Object(object)) => object,
+            Ok(_) => return Err(ImportError::NotAnObject { line }),
+            Err(source) => return Err(ImportError::Json { line, source }),
+        };
+        let values = object
+            .into_iter()
+            .filter_map(|(key, value)| {
+                let value = match value {
+                    Value::Null => return None,
+                    Value::String(value) => value,
+                    value => value.to_string(),
+                };
+                Some((key, value))
+            })
+            .collect();
+
+        rows.push(SourceRow { line, values });
+    }
+
+    Ok(rows)
+}
+
+/// A field of a time entry which can be read from a column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Field {
+    /// The email of the resource.
+    Email,
+    ResourceId,
+    /// The name of the project.
+    Project,
+    ProjectId,
+    StartDate,
+    /// The end date, the start date when missing.
+    EndDate,
+    Minutes,
+    /// The time in decimal hours, used when there are no minutes.
+    Hours,
+    Description,
+    ExternalId,
+}
+
+impl Field {
+    const ALL: &'static [Field] = &[
+        Field::Email,
+        Field::ResourceId,
+        Field::Project,
+        Field::ProjectId,
+        Field::StartDate,
+        Field::EndDate,
+        Field::Minutes,
+        Field::Hours,
+        Field::Description,
+        Field::ExternalId,
+    ];
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Field::Email => "email",
+            Field::ResourceId => "resource_id",
+            Field::Project => "project",
+            Field::ProjectId => "project_id",
+            Field::StartDate => "start_date",
+            Field::EndDate => "end_date",
+            Field::Minutes => "minutes",
+            Field::Hours => "hours",
+            Field::Description => "description",
+            Field::ExternalId => "external_id",
+        }
+    }
+}
+
+impl fmt::Display for Field {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Field {
+    type Err = UnknownField;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Field::ALL
+            .iter()
+            .copied()
+            .find(|field| field.as_str() == s)
+            .ok_or_else(|| UnknownField { field: s.into() })
+    }
+}
+
+/// The columns the fields are read from. By default every field is read from the column with
+/// its name, e.g. `start_date`.
+#[derive(Debug, Clone, Default)]
+pub struct Mapping {
+    columns: HashMap<Field, String>,
+}
+
+impl Mapping {
+    /// Read a field from a column.
+    pub fn column(mut self, field: Field, column: impl Into<String>) -> Self {
+        self.columns.insert(field, column.into());
+        self
+    }
+
+    fn value<'r>(&self, row: &'r SourceRow, field: Field) -> Option<&'r str> {
+        let column = self
+            .columns
+            .get(&field)
+            .map_or(field.as_str(), String::as_str);
+
+        row.values
+            .get(column)
+            .map(|value| value.trim())
+            .filter(|value| !value.is_empty())
+    }
+}
+
+/// The ids of resources by email and of projects by name.
+#[derive(Debug, Clone, Default)]
+pub struct Directory {
+    resources: HashMap<String, u64>,
+    projects: HashMap<String, Vec<u64>>,
+}
+
+impl Directory {
+    /// Fetch all the resources and projects.
+    pub fn fetch<C: Client>(client: &C) -> Result<Self, ApiError<C::Error>> {
+        let resources: Vec<Value> =
+            api::paged(Resources::builder().build().unwrap(), Pagination::All).query(client)?;
+        let projects: Vec<Value> =
+            api::paged(Projects::builder().build().unwrap(), Pagination::All).query(client)?;
+
+        let mut directory = Self::default();
+        for resource in &resources {
+            if let (Some(email), Some(id)) = (resource["email"].as_str(), resource["id"].as_u64()) {
+                directory.resources.insert(email.to_lowercase(), id);
+            }
+        }
+        for project in &projects {
+            if let (Some(name), Some(id)) = (project["name"].as_str(), project["id"].as_u64()) {
+                directory
+                    .projects
+                    .entry(name.to_lowercase())
+                    .or_default()
+                    .push(id);
+            }
+        }
+
+        Ok(directory)
+    }
+}
+
+/// A problem with a row, found before anything was sent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RowError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for RowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+/// A valid row, ready to be sent as a time entry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlannedEntry {
+    pub line: usize,
+    pub resource_id: u64,
+    pub project_id: u64,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    pub minutes: u64,
+    pub description: Option<String>,
+    /// The external id from the file, or one derived from the other fields.
+    pub external_id: String,
+}
+
+impl PlannedEntry {
+    pub fn endpoint(&self) -> CreateTimeEntry<'_> {
+        let mut builder = CreateTimeEntry::builder();
+        builder
+            .resource_id(self.resource_id)
+            .project_id(self.project_id)
+            .minutes(self.minutes)
+            .start_date(self.start_date)
+            .end_date(self.end_date)
+            .external_id(Some(self.external_id.as_str().into()));
+        if let Some(description) = &self.description {
+            builder.description(Some(description.as_str().into()));
+        }
+
+        builder.build().expect("all required fields are set")
+    }
+
+    /// An external id which stays the same when the same row is imported again. Identical rows
+    /// are told apart by their `occurrence`, the number of identical rows before them.
+    fn derived_external_id(&self, occurrence: usize) -> String {
+        let mut content = format!(
+            "{}|{}|{}|{}|{}|{}",
+            self.resource_id,
+            self.project_id,
+            self.start_date,
+            self.end_date,
+            self.minutes,
+            self.description.as_deref().unwrap_or_default(),
+        );
+        // The first occurrence keeps the id of imports before identical rows were told apart.
+        if occurrence > 0 {
+            content.push_str(&format!("|{}", occurrence));
+        }
+
+        format!("import-{}", &hex::encode(Sha256::digest(content))[..16])
+    }
+}
+
+/// The rows of a file, split into the valid entries and the problems found in the others.
+#[derive(Debug, Clone, Default)]
+pub struct Validated {
+    pub entries: Vec<PlannedEntry>,
+    pub errors: Vec<RowError>,
+}
+
+/// Turns rows into time entries.
+#[derive(Debug, Clone)]
+pub struct Importer {
+    mapping: Mapping,
+    directory: Directory,
+}
+
+impl Importer {
+    pub fn new(mapping: Mapping, directory: Directory) -> Self {
+        Self { mapping, directory }
+    }
+
+    fn resource_id(&self, row: &SourceRow) -> Result<u64, String> {
+        if let Some(id) = self.mapping.value(row, Field::ResourceId) {
+            return id
+                .parse()
+                .map_err(|_| format!("invalid resource id `{}`", id));
+        }
+
+        let email = self
+            .mapping
+            .value(row, Field::Email)
+            .ok_or("missing the resource email or id")?;
+        self.directory
+            .resources
+            .get(&email.to_lowercase())
+            .copied()
+            .ok_or_else(|| format!("no resource with the email `{}`", email))
+    }
+
+    fn project_id(&self, row: &SourceRow) -> Result<u64, String> {
+        if let Some(id) = self.mapping.value(row, Field::ProjectId) {
+            return id
+                .parse()
+                .map_err(|_| format!("invalid project id `{}`", id));
+        }
+
+        let name = self
+            .mapping
+            .value(row, Field::Project)
+            .ok_or("missing the project name or id")?;
+        match self
+            .directory
+            .projects
+            .get(&name.to_lowercase())
+            .map(Vec::as_slice)
+        {
+            Some([id]) => Ok(*id),
+            Some([_, ..]) => Err(format!("several projects are named `{}`", name)),
+            _ => Err(format!("no project named `{}`", name)),
+        }
+    }
+
+    fn date(&self, row: &SourceRow, field: Field) -> Result<Option<NaiveDate>, String> {
+        self.mapping
+            .value(row, field)
+            .map(|date| {
+                date.parse()
+                    .map_err(|_| format!("invalid {} `{}`, expected YYYY-MM-DD", field, date))
+            })
+            .transpose()
+    }
+
+    fn minutes(&self, row: &SourceRow) -> Result<u64, String> {
+        let minutes = match (
+            self.mapping.value(row, Field::Minutes),
+            self.mapping.value(row, Field::Hours),
+        ) {
+            (Some(minutes), _) => minutes
+                .parse::<u64>()
+                .map_err(|_| format!("invalid minutes `{}`", minutes))?,
+            (None, Some(hours)) => match hours.parse::<f64>() {
+                Ok(value) if value >= 0.0 => (value * 60.0).round() as u64,
+                _ => return Err(format!("invalid hours `{}`", hours)),
+            },
+            (None, None) => return Err("missing the minutes or hours".into()),
+        };
+
+        if minutes == 0 {
+            return Err("the time must be more than zero".into());
+        }
+
+        Ok(minutes)
+    }
+
+    fn entry(&self, row: &SourceRow) -> Result<PlannedEntry, Vec<String>> {
+        let resource_id = self.resource_id(row);
+        let project_id = self.project_id(row);
+        let start_date = self
+            .date(row, Field::StartDate)
+            .and_then(|date| date.ok_or_else(|| "missing the start_date".into()));
+        let end_date = self.date(row, Field::EndDate);
+        let minutes = self.minutes(row);
+
+        match (resource_id, project_id, start_date, end_date, minutes) {
+            (Ok(resource_id), Ok(project_id), Ok(start_date), Ok(end_date), Ok(minutes)) => {
+                let end_date = end_date.unwrap_or(start_date);
+                if end_date < start_date {
+                    return Err(vec!["the end_date is before the start_date".into()]);
+                }
+
+                let mut entry = PlannedEntry {
+                    line: row.line,
+                    resource_id,
+                    project_id,
+                    start_date,
+                    end_date,
+                    minutes,
+                    description: self.mapping.value(row, Field::Description).map(Into::into),
+                    external_id: String::new(),
+                };
+                entry.external_id = match self.mapping.value(row, Field::ExternalId) {
+                    Some(external_id) => external_id.into(),
+                    None => entry.derived_external_id(0),
+                };
+
+                Ok(entry)
+            }
+            (resource_id, project_id, start_date, end_date, minutes) => Err([
+                resource_id.err(),
+                project_id.err(),
+                start_date.err(),
+                end_date.err(),
+                minutes.err(),
+            ]
+            .into_iter()
+            .flatten()
+            .collect()),
+        }
+    }
+
+    /// Check every row, collecting all the problems rather than stopping at the first one.
+    pub fn validate(&self, rows: &[SourceRow]) -> Validated {
+        let mut validated = Validated::default();
+        let mut external_ids = HashSet::new();
+        // The number of rows seen by derived external id.
+        let mut occurrences: HashMap<String, usize> = HashMap::new();
+
+        for row in rows {
+            let entry = self.entry(row).map(|mut entry| {
+                if self.mapping.value(row, Field::ExternalId).is_none() {
+                    let occurrence = occurrences.entry(entry.external_id.clone()).or_default();
+                    if *occurrence > 0 {
+                        entry.external_id = entry.derived_external_id(*occurrence);
+                    }
+                    *occurrence += 1;
+                }
+                entry
+            });
+            match entry {
+                Ok(entry) if !external_ids.insert(entry.external_id.clone()) => {
+                    validated.errors.push(RowError {
+                        line: row.line,
+                        message: format!(
+                            "the external id `{}` is already used by another row",
+                            entry.external_id
+                        ),
+                    });
+                }
+                Ok(entry) => validated.entries.push(entry),
+                Err(messages) => {
+                    validated
+                        .errors
+                        .extend(messages.into_iter().map(|message| RowError {
+                            line: row.line,
+                            message,
+                        }))
+                }
+            }
+        }
+
+        validated
+    }
+}
+
+/// What importing an entry does.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Change {
+    Create,
+    /// Update the time entry with the same external id.
+    Update {
+        id: u64,
+        fields: Vec<FieldChange>,
+    },
+    /// The time entry with the same external id is already as imported.
+    Unchanged {
+        id: u64,
+    },
+}
+
+/// Find what importing each entry would do, without changing anything.
+pub fn preview<C: Client>(
+    entries: &[PlannedEntry],
+    client: &C,
+) -> Result<Vec<Change>, ApiError<C::Error>> {
+    entries
+        .iter()
+        .map(|entry| {
+            Ok(match api::upsert(entry.endpoint()).matched(client)? {
+                None => Change::Create,
+                Some(matched) if matched.changes.is_empty() => Change::Unchanged { id: matched.id },
+                Some(matched) => Change::Update {
+                    id: matched.id,
+                    fields: matched.changes,
+                },
+            })
+        })
+        .collect()
+}
+
+/// Create or update each entry, matching existing time entries by external id. Every entry is
+/// sent, even after a failure.
+pub fn apply<C: Client>(
+    entries: &[PlannedEntry],
+    client: &C,
+) -> Vec<Result<Upserted, ApiError<C::Error>>> {
+    entries
+        .iter()
+        .map(|entry| api::upsert(entry.endpoint()).query(client))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::api::UpsertOutcome;
+    use crate::testing::FakeTeamdeck;
+
+    use super::*;
+
+    const CSV: &str = "\
+Email,Project,Day,hours,description
+ada@example.com,Engine,2026-01-05,1.5,Design
+ADA@example.com,engine,2026-01-06,2,
+grace@example.com,Engine,2026-01-07,1,
+ada@example.com,Loom,someday,0,
+";
+
+    fn teamdeck() -> FakeTeamdeck {
+        let teamdeck = FakeTeamdeck::new();
+        teamdeck.insert(
+            "resources",
+            json!({ "name": "Ada Lovelace", "email": "ada@example.com" }),
+        );
+        teamdeck.insert("projects", json!({ "name": "Engine" }));
+
+        teamdeck
+    }
+
+    fn importer(teamdeck: &FakeTeamdeck, mapping: Mapping) -> Importer {
+        Importer::new(mapping, Directory::fetch(teamdeck).unwrap())
+    }
+
+    fn sheet_mapping() -> Mapping {
+        Mapping::default()
+            .column(Field::Email, "Email")
+            .column(Field::Project, "Project")
+            .column(Field::StartDate, "Day")
+    }
+
+    #[test]
+    fn reports_every_problem_of_every_row() {
+        let teamdeck = teamdeck();
+        let rows = read_csv(CSV.as_bytes()).unwrap();
+
+        let validated = importer(&teamdeck, sheet_mapping()).validate(&rows);
+
+        assert_eq!(validated.entries.len(), 2);
+        assert_eq!(validated.entries[0].minutes, 90);
+        assert_eq!(
+            validated.entries[0].end_date,
+            validated.entries[0].start_date
+        );
+        assert_eq!(validated.entries[1].description, None);
+
+        let errors = validated
+            .errors
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            errors,
+            vec![
+                "line 4: no resource with the email `grace@example.com`",
+                "line 5: no project named `Loom`",
+                "line 5: invalid start_date `someday`, expected YYYY-MM-DD",
+                "line 5: the time must be more than zero",
+            ]
+        );
+    }
+
+    #[test]
+    fn reads_json_lines() {
+        let input = "{\"email\": \"ada@example.com\", \"project_id\": 7, \"minutes\": 30}\n\n[1]\n";
+
+        let err = read_json_lines(input.as_bytes()).unwrap_err();
+        assert!(matches!(err, ImportError::NotAnObject { line: 3 }));
+
+        let rows = read_json_lines(input.lines().next().unwrap().as_bytes()).unwrap();
+        assert_eq!(rows[0].values["project_id"], "7");
+        assert_eq!(rows[0].values["minutes"], "30");
+    }
+
+    #[test]
+    fn rejects_repeated_external_ids() {
+        let teamdeck = teamdeck();
+        let csv = "email,project,start_date,minutes,external_id\n\
+                   ada@example.com,Engine,2026-01-05,60,a-1\n\
+                   ada@example.com,Engine,2026-01-06,30,a-1\n";
+
+        let validated =
+            importer(&teamdeck, Mapping::default()).validate(&read_csv(csv.as_bytes()).unwrap());
+
+        assert_eq!(validated.entries.len(), 1);
+        assert_eq!(validated.errors[0].line, 3);
+    }
+
+    #[test]
+    fn imports_identical_rows_once_each() {
+        let teamdeck = teamdeck();
+        let csv = "email,project,start_date,minutes\n\
+                   ada@example.com,Engine,2026-01-05,60\n\
+                   ada@example.com,Engine,2026-01-05,60\n";
+        let rows = read_csv(csv.as_bytes()).unwrap();
+        let entries = importer(&teamdeck, Mapping::default())
+            .validate(&rows)
+            .entries;
+
+        assert_eq!(entries.len(), 2);
+        assert_ne!(entries[0].external_id, entries[1].external_id);
+        let outcomes = apply(&entries, &teamdeck)
+            .into_iter()
+            .map(|result| result.unwrap().outcome)
+            .collect::<Vec<_>>();
+        assert_eq!(outcomes, vec![UpsertOutcome::Created; 2]);
+
+        let entries = importer(&teamdeck, Mapping::default())
+            .validate(&rows)
+            .entries;
+        let outcomes = apply(&entries, &teamdeck)
+            .into_iter()
+            .map(|result| result.unwrap().outcome)
+            .collect::<Vec<_>>();
+        assert_eq!(outcomes, vec![UpsertOutcome::Unchanged; 2]);
+        assert_eq!(teamdeck.records("time-entries").len(), 2);
+    }
+
+    #[test]
+    fn importing_twice_creates_nothing_new() {
+        let teamdeck = teamdeck();
+        let rows = read_csv(CSV.as_bytes()).unwrap();
+        let entries = importer(&teamdeck, sheet_mapping()).validate(&rows).entries;
+
+        assert_eq!(
+            preview(&entries, &teamdeck).unwrap(),
+            vec![Change::Create, Change::Create]
+        );
+        let outcomes = apply(&entries, &teamdeck)
+            .into_iter()
+            .map(|result| result.unwrap().outcome)
+            .collect::<Vec<_>>();
+        assert_eq!(outcomes, vec![UpsertOutcome::Created; 2]);
+
+        let changes = preview(&entries, &teamdeck).unwrap();
+        assert!(matches!(changes[0], Change::Unchanged { .. }));
+        let outcomes = apply(&entries, &teamdeck)
+            .into_iter()
+            .map(|result| result.unwrap().outcome)
+            .collect::<Vec<_>>();
+        assert_eq!(outcomes, vec![UpsertOutcome::Unchanged; 2]);
+        assert_eq!(teamdeck.records("time-entries").len(), 2);
+    }
+
+    #[test]
+    fn previews_changed_fields() {
+        let teamdeck = teamdeck();
+        let csv = "email,project,start_date,minutes,external_id\n\
+                   ada@example.com,Engine,2026-01-05,60,sheet-1\n";
+        let entries = importer(&teamdeck, Mapping::default())
+            .validate(&read_csv(csv.as_bytes()).unwrap())
+            .entries;
+        apply(&entries, &teamdeck);
+
+        let csv = csv.replace(",60,", ",90,");
+        let entries = importer(&teamdeck, Mapping::default())
+            .validate(&read_csv(csv.as_bytes()).unwrap())
+            .entries;
+
+        match &preview(&entries, &teamdeck).unwrap()[0] {
+            Change::Update { fields, .. } => assert_eq!(
+                fields,
+                &vec![FieldChange {
+                    field: "minutes".into(),
+                    old: json!(60),
+                    new: json!(90),
+                }]
+            ),
+            change => panic!("unexpected change: {:?}", change),
+        }
+    }
+}
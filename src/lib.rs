@@ -1,8 +1,25 @@
 pub mod api;
+pub mod availability;
+pub mod budget;
+pub mod capacity;
+pub mod conflicts;
+pub mod dry_run;
+#[cfg(feature = "export")]
+pub mod export;
+pub mod icalendar;
+#[cfg(feature = "import")]
+pub mod import;
+pub mod models;
+pub mod reports;
 pub mod teamdeck;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
+pub mod vacation_balance;
+pub mod webhooks;
 
 #[cfg(test)]
 mod test;
 
+pub use crate::dry_run::DryRunClient;
 pub use crate::teamdeck::AsyncTeamdeck;
 pub use crate::teamdeck::Teamdeck;
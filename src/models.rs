@@ -0,0 +1,87 @@
+//! Typed representations of the entities returned by the Teamdeck API.
+//!
+//! Endpoints are generic over their output type, so these are optional: any `Deserialize`
+//! type can be used instead. Fields that Teamdeck does not always send are `Option`s.
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+/// A booking of a resource on a project. `minutes` is the booked time per day.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Booking {
+    pub id: u64,
+    pub resource_id: u64,
+    pub project_id: u64,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    pub minutes: u64,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub external_id: Option<String>,
+}
+
+/// Time logged by a resource on a project.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct TimeEntry {
+    pub id: u64,
+    pub resource_id: u64,
+    pub project_id: u64,
+    pub minutes: u64,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    #[serde(default)]
+    pub weekend_booking: Option<bool>,
+    #[serde(default)]
+    pub holidays_booking: Option<bool>,
+    #[serde(default)]
+    pub vacations_booking: Option<bool>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub external_id: Option<String>,
+    #[serde(default)]
+    pub creator_resource_id: Option<u64>,
+    #[serde(default)]
+    pub editor_resource_id: Option<u64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VacationStatus {
+    Pending,
+    Approved,
+    Rejected,
+    #[serde(other)]
+    Unknown,
+}
+
+/// A vacation (leave) of a resource.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Vacation {
+    pub id: u64,
+    pub resource_id: u64,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    pub status: VacationStatus,
+    #[serde(default)]
+    pub period_id: Option<u64>,
+    #[serde(default)]
+    pub reason_id: Option<u64>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub external_id: Option<String>,
+    #[serde(default)]
+    pub approver_resource_id: Option<u64>,
+}
+
+/// A public holiday, for the whole organization or one organization unit.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Holiday {
+    pub id: u64,
+    pub name: String,
+    pub date: NaiveDate,
+    #[serde(default)]
+    pub organization_unit_id: Option<u64>,
+}
@@ -0,0 +1,354 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use chrono::NaiveDate;
+use serde::Serialize;
+
+use crate::api::{ApiError, Client};
+use crate::capacity::{time_entries, Absences, Capacity, DayCapacity, TimeEntriesOf};
+use crate::models::{Holiday, TimeEntry, Vacation, VacationStatus};
+
+/// Something unusual about the time logged by a resource on a day.
+///
+/// `acknowledged` tells whether the time entry was logged with the matching flag, e.g.
+/// `weekend_booking`, so the resource meant to log time on that day.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Anomaly {
+    /// More time logged on the day than the daily limit, in minutes.
+    Overtime { logged: u64, limit: u64 },
+    /// Time logged on a day outside the work week.
+    Weekend { entry_id: u64, acknowledged: bool },
+    /// Time logged on a holiday.
+    Holiday {
+        entry_id: u64,
+        holiday_id: u64,
+        acknowledged: bool,
+    },
+    /// Time logged during an approved vacation.
+    Vacation {
+        entry_id: u64,
+        vacation_id: u64,
+        acknowledged: bool,
+    },
+    /// A single time entry longer than the entry limit, in minutes.
+    LongEntry { entry_id: u64, minutes: u64 },
+    /// Time entries with the same project, minutes and description.
+    Duplicate { entry_ids: Vec<u64> },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Finding {
+    pub resource_id: u64,
+    pub date: NaiveDate,
+    #[serde(flatten)]
+    pub anomaly: Anomaly,
+}
+
+/// Finds overtime, time logged on days off, very long time entries and duplicate time entries.
+#[derive(Debug, Clone)]
+pub struct AnomalyCheck {
+    capacity: Capacity,
+    daily_limit: u64,
+    entry_limit: u64,
+}
+
+impl AnomalyCheck {
+    pub fn new(capacity: Capacity) -> Self {
+        Self {
+            capacity,
+            daily_limit: 10 * 60,
+            entry_limit: 8 * 60,
+        }
+    }
+
+    /// The minutes which may be logged on a day before it is overtime, 10 hours by default.
+    pub fn daily_limit(mut self, minutes: u64) -> Self {
+        self.daily_limit = minutes;
+        self
+    }
+
+    /// The minutes a single time entry may last, 8 hours by default.
+    pub fn entry_limit(mut self, minutes: u64) -> Self {
+        self.entry_limit = minutes;
+        self
+    }
+
+    fn day_anomalies(
+        &self,
+        entries: &[&TimeEntry],
+        day: Option<&DayCapacity>,
+        holidays: &[Holiday],
+        vacations: &[Vacation],
+    ) -> Vec<Anomaly> {
+        let mut anomalies = Vec::new();
+        let (resource_id, date) = (entries[0].resource_id, entries[0].start_date);
+
+        let logged = entries.iter().map(|entry| entry.minutes).sum::<u64>();
+        if logged > self.daily_limit {
+            anomalies.push(Anomaly::Overtime {
+                logged,
+                limit: self.daily_limit,
+            });
+        }
+
+        let holidays = holidays
+            .iter()
+            .filter(|holiday| {
+                holiday.date == date && self.capacity.has_holiday(resource_id, holiday)
+            })
+            .collect::<Vec<_>>();
+        let vacations = vacations
+            .iter()
+            .filter(|vacation| {
+                vacation.resource_id == resource_id
+                    && vacation.status == VacationStatus::Approved
+                    && vacation.start_date <= date
+                    && date <= vacation.end_date
+            })
+            .collect::<Vec<_>>();
+        for entry in entries {
+            if day.is_some_and(|day| day.scheduled == 0) {
+                anomalies.push(Anomaly::Weekend {
+                    entry_id: entry.id,
+                    acknowledged: entry.weekend_booking == Some(true),
+                });
+            }
+            for holiday in &holidays {
+                anomalies.push(Anomaly::Holiday {
+                    entry_id: entry.id,
+                    holiday_id: holiday.id,
+                    acknowledged: entry.holidays_booking == Some(true),
+                });
+            }
+            for vacation in &vacations {
+                anomalies.push(Anomaly::Vacation {
+                    entry_id: entry.id,
+                    vacation_id: vacation.id,
+                    acknowledged: entry.vacations_booking == Some(true),
+                });
+            }
+            if entry.minutes > self.entry_limit {
+                anomalies.push(Anomaly::LongEntry {
+                    entry_id: entry.id,
+                    minutes: entry.minutes,
+                });
+            }
+        }
+
+        let mut same: BTreeMap<(u64, u64, Option<&str>), Vec<u64>> = BTreeMap::new();
+        for entry in entries {
+            same.entry((
+                entry.project_id,
+                entry.minutes,
+                entry.description.as_deref(),
+            ))
+            .or_default()
+            .push(entry.id);
+        }
+        anomalies.extend(
+            same.into_values()
+                .filter(|entry_ids| entry_ids.len() > 1)
+                .map(|entry_ids| Anomaly::Duplicate { entry_ids }),
+        );
+
+        anomalies
+    }
+
+    /// The findings about time entries, given the capacity of their resources on their days and
+    /// the holidays and vacations over them. Findings are ordered by resource and date.
+    pub fn calculate(
+        &self,
+        time_entries: &[TimeEntry],
+        capacity: &[DayCapacity],
+        holidays: &[Holiday],
+        vacations: &[Vacation],
+    ) -> Vec<Finding> {
+        let capacity = capacity
+            .iter()
+            .map(|day| ((day.resource_id, day.date), day))
+            .collect::<HashMap<_, _>>();
+        let mut days: BTreeMap<(u64, NaiveDate), Vec<&TimeEntry>> = BTreeMap::new();
+        for entry in time_entries {
+            days.entry((entry.resource_id, entry.start_date))
+                .or_default()
+                .push(entry);
+        }
+
+        days.into_iter()
+            .flat_map(|((resource_id, date), entries)| {
+                let day = capacity.get(&(resource_id, date)).copied();
+                self.day_anomalies(&entries, day, holidays, vacations)
+                    .into_iter()
+                    .map(move |anomaly| Finding {
+                        resource_id,
+                        date,
+                        anomaly,
+                    })
+            })
+            .collect()
+    }
+
+    /// Fetch the time entries of resources, or of everyone without resources, from `from` to `to`
+    /// inclusive, with the holidays and vacations over them, and check them.
+    pub fn fetch<C: Client>(
+        &self,
+        resources: &[u64],
+        from: NaiveDate,
+        to: NaiveDate,
+        client: &C,
+    ) -> Result<Vec<Finding>, ApiError<C::Error>> {
+        let time_entries =
+            time_entries(TimeEntriesOf::Resources(resources), Some(from), to, client)?;
+        let logged = time_entries
+            .iter()
+            .map(|entry| entry.resource_id)
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect::<Vec<_>>();
+        if logged.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let absences = Absences::fetch(&logged, from, to, client)?;
+        let capacity = absences.capacity(&self.capacity, &logged, from, to);
+
+        Ok(self.calculate(
+            &time_entries,
+            &capacity,
+            &absences.holidays,
+            &absences.vacations,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::testing::fixtures::{date, insert_holiday, time_entry, vacation};
+    use crate::testing::FakeTeamdeck;
+
+    #[test]
+    fn finds_anomalies() {
+        let teamdeck = FakeTeamdeck::new();
+        let holiday = insert_holiday(&teamdeck, "2026-06-01");
+        let day_off = teamdeck.insert(
+            "vacations",
+            vacation(1, "2026-06-04", "2026-06-04", "approved"),
+        );
+        let entry = |id, day: &str, minutes, description: &str| {
+            let mut entry = time_entry(1, 10, day, minutes);
+            entry["id"] = json!(id);
+            entry["description"] = json!(description);
+            entry
+        };
+        for entry in [
+            entry(101, "2026-06-01", 60, "Hotfix"),
+            entry(102, "2026-06-02", 540, "Release"),
+            entry(103, "2026-06-02", 120, "Review"),
+            entry(104, "2026-06-03", 120, "Review"),
+            entry(105, "2026-06-03", 120, "Review"),
+            entry(106, "2026-06-03", 120, "Planning"),
+            entry(107, "2026-06-04", 30, "Call"),
+        ] {
+            teamdeck.insert("time-entries", entry);
+        }
+        let mut weekend = time_entry(1, 10, "2026-06-06", 90);
+        weekend["id"] = json!(108);
+        weekend["weekend_booking"] = json!(true);
+        teamdeck.insert("time-entries", weekend);
+
+        let findings = AnomalyCheck::new(Capacity::default())
+            .fetch(&[1], date(1), date(7), &teamdeck)
+            .unwrap();
+
+        let findings = findings
+            .into_iter()
+            .map(|finding| (finding.date, finding.anomaly))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            findings,
+            vec![
+                (
+                    date(1),
+                    Anomaly::Holiday {
+                        entry_id: 101,
+                        holiday_id: holiday,
+                        acknowledged: false,
+                    }
+                ),
+                (
+                    date(2),
+                    Anomaly::Overtime {
+                        logged: 660,
+                        limit: 600,
+                    }
+                ),
+                (
+                    date(2),
+                    Anomaly::LongEntry {
+                        entry_id: 102,
+                        minutes: 540,
+                    }
+                ),
+                (
+                    date(3),
+                    Anomaly::Duplicate {
+                        entry_ids: vec![104, 105],
+                    }
+                ),
+                (
+                    date(4),
+                    Anomaly::Vacation {
+                        entry_id: 107,
+                        vacation_id: day_off,
+                        acknowledged: false,
+                    }
+                ),
+                (
+                    date(6),
+                    Anomaly::Weekend {
+                        entry_id: 108,
+                        acknowledged: true,
+                    }
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn limits_are_configurable() {
+        let check = AnomalyCheck::new(Capacity::default())
+            .daily_limit(60)
+            .entry_limit(45);
+        let entry: TimeEntry = serde_json::from_value(json!({
+            "id": 1,
+            "resource_id": 1,
+            "project_id": 10,
+            "start_date": "2026-06-02",
+            "end_date": "2026-06-02",
+            "minutes": 90,
+        }))
+        .unwrap();
+
+        let findings = check.calculate(&[entry], &[], &[], &[]);
+
+        assert_eq!(
+            findings
+                .iter()
+                .map(|finding| &finding.anomaly)
+                .collect::<Vec<_>>(),
+            vec![
+                &Anomaly::Overtime {
+                    logged: 90,
+                    limit: 60,
+                },
+                &Anomaly::LongEntry {
+                    entry_id: 1,
+                    minutes: 90,
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,279 @@
+use std::collections::{BTreeMap, HashMap};
+
+use chrono::NaiveDate;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::api::{self, resources::Resources, ApiError, Client, Pagination, Query};
+use crate::capacity::{time_entries, Capacity, DayCapacity, TimeEntriesOf};
+use crate::models::TimeEntry;
+
+/// What is wrong with the time logged on a day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DayIssue {
+    /// Nothing logged on a working day.
+    Missing,
+    /// Less logged than expected, beyond the tolerance.
+    Short,
+    /// Far more logged than expected.
+    Excessive,
+    /// Time logged on a day off, a holiday or a vacation.
+    DayOff,
+}
+
+/// A day whose logged time does not match the expected time, in minutes.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DayFinding {
+    pub date: NaiveDate,
+    pub expected: u64,
+    pub logged: u64,
+    pub issue: DayIssue,
+}
+
+/// A resource with missing or suspicious days.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct IncompleteTimesheet {
+    pub resource_id: u64,
+    pub name: Option<String>,
+    pub email: Option<String>,
+    /// The minutes expected over the whole range.
+    pub expected: u64,
+    /// The minutes logged over the whole range.
+    pub logged: u64,
+    pub days: Vec<DayFinding>,
+}
+
+/// Finds the resources who have not logged the time they were expected to.
+///
+/// The expected time of a day is the available time of its capacity, so holidays and vacations
+/// are not expected to be logged. Logging less than the tolerance below it (10% by default) is
+/// short, logging more than the excess above it (50% by default) is excessive.
+#[derive(Debug, Clone)]
+pub struct CompletenessCheck {
+    capacity: Capacity,
+    tolerance: f64,
+    excess: f64,
+}
+
+impl CompletenessCheck {
+    pub fn new(capacity: Capacity) -> Self {
+        Self {
+            capacity,
+            tolerance: 0.1,
+            excess: 0.5,
+        }
+    }
+
+    /// The share of the expected time which may be missing from a day, 0.1 by default.
+    pub fn tolerance(mut self, share: f64) -> Self {
+        self.tolerance = share;
+        self
+    }
+
+    /// The share of the expected time which may be logged on top of it, 0.5 by default.
+    pub fn excess(mut self, share: f64) -> Self {
+        self.excess = share;
+        self
+    }
+
+    fn issue(&self, expected: u64, logged: u64) -> Option<DayIssue> {
+        let (expected_f, logged_f) = (expected as f64, logged as f64);
+        match (expected, logged) {
+            (0, 0) => None,
+            (0, _) => Some(DayIssue::DayOff),
+            (_, 0) => Some(DayIssue::Missing),
+            _ if logged_f < expected_f * (1.0 - self.tolerance) => Some(DayIssue::Short),
+            _ if logged_f > expected_f * (1.0 + self.excess) => Some(DayIssue::Excessive),
+            _ => None,
+        }
+    }
+
+    /// Compare the time logged by resources with their capacity on each day. `resources` are the
+    /// resource records, for their names and emails.
+    ///
+    /// Only resources with findings are returned, ordered by id.
+    pub fn calculate(
+        &self,
+        resources: &[Value],
+        capacity: &[DayCapacity],
+        time_entries: &[TimeEntry],
+    ) -> Vec<IncompleteTimesheet> {
+        let mut logged: HashMap<(u64, NaiveDate), u64> = HashMap::new();
+        for entry in time_entries {
+            *logged
+                .entry((entry.resource_id, entry.start_date))
+                .or_default() += entry.minutes;
+        }
+        let records = resources
+            .iter()
+            .filter_map(|resource| Some((resource["id"].as_u64()?, resource)))
+            .collect::<HashMap<_, _>>();
+
+        let mut timesheets: BTreeMap<u64, IncompleteTimesheet> = BTreeMap::new();
+        for day in capacity {
+            let logged = logged
+                .get(&(day.resource_id, day.date))
+                .copied()
+                .unwrap_or(0);
+            let timesheet = timesheets.entry(day.resource_id).or_insert_with(|| {
+                let record = records.get(&day.resource_id);
+                let text = |field: &str| {
+                    record.and_then(|record| record[field].as_str().map(String::from))
+                };
+                IncompleteTimesheet {
+                    resource_id: day.resource_id,
+                    name: text("name"),
+                    email: text("email"),
+                    expected: 0,
+                    logged: 0,
+                    days: vec![],
+                }
+            });
+            timesheet.expected += day.available;
+            timesheet.logged += logged;
+
+            if let Some(issue) = self.issue(day.available, logged) {
+                timesheet.days.push(DayFinding {
+                    date: day.date,
+                    expected: day.available,
+                    logged,
+                    issue,
+                });
+            }
+        }
+
+        timesheets
+            .into_values()
+            .filter(|timesheet| !timesheet.days.is_empty())
+            .collect()
+    }
+
+    /// Check the timesheets of the active resources from `from` to `to` inclusive.
+    pub fn fetch<C: Client>(
+        &self,
+        from: NaiveDate,
+        to: NaiveDate,
+        client: &C,
+    ) -> Result<Vec<IncompleteTimesheet>, ApiError<C::Error>> {
+        let endpoint = Resources::builder().active(true).build().unwrap();
+        let resources: Vec<Value> = api::paged(endpoint, Pagination::All).query(client)?;
+        let ids = resources
+            .iter()
+            .filter_map(|resource| resource["id"].as_u64())
+            .collect::<Vec<_>>();
+        if ids.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let capacity = self.capacity.fetch(&ids, from, to, client)?;
+        let time_entries = time_entries(TimeEntriesOf::Resources(&ids), Some(from), to, client)?;
+
+        Ok(self.calculate(&resources, &capacity, &time_entries))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::capacity::WorkWeek;
+    use crate::testing::fixtures::{date, insert_holiday, time_entry, vacation};
+    use crate::testing::FakeTeamdeck;
+
+    #[test]
+    fn finds_missing_and_suspicious_days() {
+        let teamdeck = FakeTeamdeck::new();
+        let ada = teamdeck.insert(
+            "resources",
+            json!({ "name": "Ada", "email": "ada@example.com", "active": true }),
+        );
+        let grace = teamdeck.insert(
+            "resources",
+            json!({ "name": "Grace", "email": "grace@example.com", "active": true }),
+        );
+        let former = teamdeck.insert(
+            "resources",
+            json!({ "name": "Charles", "email": "charles@example.com", "active": false }),
+        );
+        insert_holiday(&teamdeck, "2026-06-01");
+        teamdeck.insert(
+            "vacations",
+            vacation(ada, "2026-06-05", "2026-06-05", "approved"),
+        );
+        for (resource_id, day, minutes) in [
+            (ada, "2026-06-01", 60),
+            (ada, "2026-06-02", 240),
+            (ada, "2026-06-02", 200),
+            (ada, "2026-06-03", 300),
+            (ada, "2026-06-04", 900),
+            (grace, "2026-06-02", 480),
+            (grace, "2026-06-03", 480),
+            (grace, "2026-06-04", 480),
+            (grace, "2026-06-05", 480),
+            (former, "2026-06-02", 0),
+        ] {
+            teamdeck.insert("time-entries", time_entry(resource_id, 1, day, minutes));
+        }
+
+        let timesheets = CompletenessCheck::new(Capacity::new(WorkWeek::default()))
+            .fetch(date(1), date(7), &teamdeck)
+            .unwrap();
+
+        assert_eq!(timesheets.len(), 1);
+        let timesheet = &timesheets[0];
+        assert_eq!(timesheet.resource_id, ada);
+        assert_eq!(timesheet.name.as_deref(), Some("Ada"));
+        assert_eq!(timesheet.email.as_deref(), Some("ada@example.com"));
+        assert_eq!((timesheet.expected, timesheet.logged), (3 * 480, 1700));
+
+        let days = timesheet
+            .days
+            .iter()
+            .map(|day| (day.date, day.issue))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            days,
+            vec![
+                (date(1), DayIssue::DayOff),
+                (date(3), DayIssue::Short),
+                (date(4), DayIssue::Excessive),
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_missing_days() {
+        let check = CompletenessCheck::new(Capacity::default()).tolerance(0.0);
+        let day = |date, available| DayCapacity {
+            resource_id: 4,
+            date,
+            scheduled: 480,
+            holidays: 0,
+            vacations: 480 - available,
+            available,
+        };
+
+        let timesheets = check.calculate(&[], &[day(date(1), 480), day(date(2), 240)], &[]);
+
+        assert_eq!(timesheets[0].name, None);
+        assert_eq!(
+            timesheets[0].days,
+            vec![
+                DayFinding {
+                    date: date(1),
+                    expected: 480,
+                    logged: 0,
+                    issue: DayIssue::Missing,
+                },
+                DayFinding {
+                    date: date(2),
+                    expected: 240,
+                    logged: 0,
+                    issue: DayIssue::Missing,
+                },
+            ]
+        );
+    }
+}
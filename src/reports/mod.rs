@@ -0,0 +1,56 @@
+//! Reports on bookings, time entries and capacity, such as utilization, timesheet completeness,
+//! the variance of logged time from bookings and anomalies in time entries.
+
+mod anomalies;
+mod completeness;
+mod utilization;
+mod variance;
+
+pub use self::anomalies::{Anomaly, AnomalyCheck, Finding};
+pub use self::completeness::{CompletenessCheck, DayFinding, DayIssue, IncompleteTimesheet};
+pub use self::utilization::{
+    Allocation, ProjectUtilization, ResourceUtilization, Utilization, UtilizationReport,
+};
+pub use self::variance::{Variance, VarianceReport};
+
+use chrono::{Datelike, Duration, NaiveDate};
+use serde::Serialize;
+
+/// The length of the periods a report sums over.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Period {
+    /// Weeks starting on Monday.
+    #[default]
+    Week,
+    Month,
+}
+
+impl Period {
+    /// The first day of the period of a date.
+    pub fn start(self, date: NaiveDate) -> NaiveDate {
+        match self {
+            Self::Week => date - Duration::days(date.weekday().num_days_from_monday().into()),
+            Self::Month => date.with_day(1).unwrap(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn period_starts() {
+        let date = NaiveDate::from_ymd_opt(2026, 7, 16).unwrap();
+
+        assert_eq!(
+            Period::Week.start(date),
+            NaiveDate::from_ymd_opt(2026, 7, 13).unwrap()
+        );
+        assert_eq!(
+            Period::Month.start(date),
+            NaiveDate::from_ymd_opt(2026, 7, 1).unwrap()
+        );
+    }
+}
@@ -0,0 +1,356 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use chrono::NaiveDate;
+use serde::Serialize;
+
+use super::Period;
+use crate::api::{ApiError, Client};
+use crate::capacity::{bookings, time_entries, BookingsOf, Capacity, DayCapacity, TimeEntriesOf};
+use crate::models::{Booking, TimeEntry};
+
+/// How the booked time of a resource compares to their capacity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Allocation {
+    /// Booked for more than the available time.
+    OverBooked,
+    /// Booked for less than the threshold of the available time.
+    UnderBooked,
+    Balanced,
+}
+
+/// The time of a resource over a period, in minutes.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ResourceUtilization {
+    pub resource_id: u64,
+    /// The first day of the period.
+    pub period: NaiveDate,
+    pub available: u64,
+    pub booked: u64,
+    /// The time of bookings on working days lost to holidays or vacations, which is not part of
+    /// the booked time.
+    pub booked_on_absences: u64,
+    pub logged: u64,
+    /// The booked time as a percentage of the available time, none without available time.
+    pub booked_percent: Option<f64>,
+    /// The logged time as a percentage of the available time, none without available time.
+    pub logged_percent: Option<f64>,
+    pub allocation: Allocation,
+}
+
+/// The time spent on a project over a period, in minutes.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ProjectUtilization {
+    pub project_id: u64,
+    /// The first day of the period.
+    pub period: NaiveDate,
+    pub booked: u64,
+    pub logged: u64,
+}
+
+/// Utilization by resource and by project, ordered by id and period.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Utilization {
+    pub period: Period,
+    pub resources: Vec<ResourceUtilization>,
+    pub projects: Vec<ProjectUtilization>,
+}
+
+fn percent(minutes: u64, available: u64) -> Option<f64> {
+    if available == 0 {
+        None
+    } else {
+        Some(minutes as f64 * 100.0 / available as f64)
+    }
+}
+
+/// Compares the time resources are booked for and have logged with their capacity.
+///
+/// Bookings count on the days [`DayCapacity::books`] them; the bookings over holidays and
+/// vacations are summed on their own. Time entries count on their start date.
+#[derive(Debug, Clone)]
+pub struct UtilizationReport {
+    capacity: Capacity,
+    period: Period,
+    under_booked_below: f64,
+}
+
+impl UtilizationReport {
+    pub fn new(capacity: Capacity) -> Self {
+        Self {
+            capacity,
+            period: Period::Week,
+            under_booked_below: 0.8,
+        }
+    }
+
+    /// Sum over weeks or months, weeks by default.
+    pub fn period(mut self, period: Period) -> Self {
+        self.period = period;
+        self
+    }
+
+    /// The share of the available time below which a resource is under-booked, 0.8 by default.
+    pub fn under_booked_below(mut self, share: f64) -> Self {
+        self.under_booked_below = share;
+        self
+    }
+
+    fn allocation(&self, booked: u64, available: u64) -> Allocation {
+        if booked > available {
+            Allocation::OverBooked
+        } else if (booked as f64) < available as f64 * self.under_booked_below {
+            Allocation::UnderBooked
+        } else {
+            Allocation::Balanced
+        }
+    }
+
+    /// The utilization of resources from their capacity on each day, their bookings and their
+    /// time entries. Only the days of the capacity count.
+    pub fn calculate(
+        &self,
+        capacity: &[DayCapacity],
+        bookings: &[Booking],
+        time_entries: &[TimeEntry],
+    ) -> Utilization {
+        // (available, booked, booked on absences, logged) by resource and period.
+        let mut resources: BTreeMap<(u64, NaiveDate), (u64, u64, u64, u64)> = BTreeMap::new();
+        // (booked, logged) by project and period.
+        let mut projects: BTreeMap<(u64, NaiveDate), (u64, u64)> = BTreeMap::new();
+
+        let mut resource_bookings: HashMap<u64, Vec<&Booking>> = HashMap::new();
+        for booking in bookings {
+            resource_bookings
+                .entry(booking.resource_id)
+                .or_default()
+                .push(booking);
+        }
+
+        let mut days = HashSet::new();
+        for day in capacity {
+            days.insert((day.resource_id, day.date));
+            let period = self.period.start(day.date);
+            let row = resources.entry((day.resource_id, period)).or_default();
+            row.0 += day.available;
+
+            let bookings = resource_bookings
+                .get(&day.resource_id)
+                .into_iter()
+                .flatten();
+            for booking in bookings {
+                if day.books(booking) {
+                    row.1 += booking.minutes;
+                    projects.entry((booking.project_id, period)).or_default().0 += booking.minutes;
+                } else if day.scheduled > 0 && day.spans(booking) {
+                    row.2 += booking.minutes;
+                }
+            }
+        }
+
+        for entry in time_entries {
+            if !days.contains(&(entry.resource_id, entry.start_date)) {
+                continue;
+            }
+            let period = self.period.start(entry.start_date);
+            resources.entry((entry.resource_id, period)).or_default().3 += entry.minutes;
+            projects.entry((entry.project_id, period)).or_default().1 += entry.minutes;
+        }
+
+        Utilization {
+            period: self.period,
+            resources: resources
+                .into_iter()
+                .map(
+                    |((resource_id, period), (available, booked, booked_on_absences, logged))| {
+                        ResourceUtilization {
+                            resource_id,
+                            period,
+                            available,
+                            booked,
+                            booked_on_absences,
+                            logged,
+                            booked_percent: percent(booked, available),
+                            logged_percent: percent(logged, available),
+                            allocation: self.allocation(booked, available),
+                        }
+                    },
+                )
+                .collect(),
+            projects: projects
+                .into_iter()
+                .map(
+                    |((project_id, period), (booked, logged))| ProjectUtilization {
+                        project_id,
+                        period,
+                        booked,
+                        logged,
+                    },
+                )
+                .collect(),
+        }
+    }
+
+    /// Fetch the capacity, bookings and time entries of resources from `from` to `to` inclusive
+    /// and calculate their utilization.
+    pub fn fetch<C: Client>(
+        &self,
+        resources: &[u64],
+        from: NaiveDate,
+        to: NaiveDate,
+        client: &C,
+    ) -> Result<Utilization, ApiError<C::Error>> {
+        let capacity = self.capacity.fetch(resources, from, to, client)?;
+        if capacity.is_empty() {
+            return Ok(self.calculate(&[], &[], &[]));
+        }
+
+        let bookings = bookings(BookingsOf::Resources(resources), from, Some(to), client)?;
+        let time_entries =
+            time_entries(TimeEntriesOf::Resources(resources), Some(from), to, client)?;
+
+        Ok(self.calculate(&capacity, &bookings, &time_entries))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::capacity::WorkWeek;
+    use crate::testing::fixtures::{booking, date, insert_holiday, time_entry};
+    use crate::testing::FakeTeamdeck;
+
+    fn teamdeck() -> FakeTeamdeck {
+        let teamdeck = FakeTeamdeck::new();
+        insert_holiday(&teamdeck, "2026-06-01");
+        // Over the weekend and the holiday, which do not count.
+        teamdeck.insert("bookings", booking(1, 10, "2026-05-30", "2026-06-05", 240));
+        teamdeck.insert("bookings", booking(1, 11, "2026-06-04", "2026-06-12", 300));
+        teamdeck.insert("bookings", booking(3, 11, "2026-06-01", "2026-06-12", 480));
+        teamdeck.insert("time-entries", time_entry(1, 10, "2026-06-02", 300));
+        teamdeck.insert("time-entries", time_entry(1, 11, "2026-06-08", 120));
+        teamdeck.insert("time-entries", time_entry(2, 11, "2026-06-09", 480));
+
+        teamdeck
+    }
+
+    #[test]
+    fn sums_by_resource_project_and_week() {
+        let teamdeck = teamdeck();
+        let report = UtilizationReport::new(Capacity::new(WorkWeek::default()));
+
+        let utilization = report.fetch(&[1, 2], date(1), date(14), &teamdeck).unwrap();
+
+        let resources = utilization
+            .resources
+            .iter()
+            .map(|row| {
+                (
+                    row.resource_id,
+                    row.period.to_string(),
+                    row.available,
+                    row.booked,
+                    row.logged,
+                    row.allocation,
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            resources,
+            vec![
+                (
+                    1,
+                    "2026-06-01".into(),
+                    1920,
+                    1560,
+                    300,
+                    Allocation::Balanced
+                ),
+                (
+                    1,
+                    "2026-06-08".into(),
+                    2400,
+                    1500,
+                    120,
+                    Allocation::UnderBooked
+                ),
+                (2, "2026-06-01".into(), 1920, 0, 0, Allocation::UnderBooked),
+                (
+                    2,
+                    "2026-06-08".into(),
+                    2400,
+                    0,
+                    480,
+                    Allocation::UnderBooked
+                ),
+            ]
+        );
+        assert_eq!(utilization.resources[0].booked_percent, Some(81.25));
+        assert_eq!(utilization.resources[0].booked_on_absences, 240);
+        assert_eq!(utilization.resources[1].booked_on_absences, 0);
+        assert_eq!(utilization.resources[3].logged_percent, Some(20.0));
+
+        assert_eq!(
+            utilization.projects,
+            vec![
+                ProjectUtilization {
+                    project_id: 10,
+                    period: date(1),
+                    booked: 960,
+                    logged: 300,
+                },
+                ProjectUtilization {
+                    project_id: 11,
+                    period: date(1),
+                    booked: 600,
+                    logged: 0,
+                },
+                ProjectUtilization {
+                    project_id: 11,
+                    period: date(8),
+                    booked: 1500,
+                    logged: 600,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn flags_over_booking_by_month() {
+        let teamdeck = teamdeck();
+        let report = UtilizationReport::new(Capacity::new(WorkWeek::new(
+            [chrono::Weekday::Thu, chrono::Weekday::Fri],
+            8.0,
+        )))
+        .period(Period::Month);
+
+        let utilization = report.fetch(&[1], date(1), date(30), &teamdeck).unwrap();
+
+        assert_eq!(utilization.resources.len(), 1);
+        let june = &utilization.resources[0];
+        assert_eq!(june.period, date(1));
+        // 8 Thursdays and Fridays, booked 2 of them for 4 hours and 4 of them for 5 hours.
+        assert_eq!(june.available, 8 * 480);
+        assert_eq!(june.booked, 240 * 2 + 300 * 4);
+        assert_eq!(june.allocation, Allocation::UnderBooked);
+
+        let over = report.calculate(
+            &[DayCapacity {
+                resource_id: 1,
+                date: date(4),
+                scheduled: 480,
+                holidays: 0,
+                vacations: 240,
+                available: 240,
+            }],
+            &teamdeck
+                .records("bookings")
+                .into_iter()
+                .map(|booking| serde_json::from_value(booking).unwrap())
+                .collect::<Vec<_>>(),
+            &[],
+        );
+        assert_eq!(over.resources[0].booked, 540);
+        assert_eq!(over.resources[0].allocation, Allocation::OverBooked);
+    }
+}
@@ -0,0 +1,214 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use chrono::NaiveDate;
+use serde::Serialize;
+
+use super::Period;
+use crate::api::{ApiError, Client};
+use crate::capacity::{bookings, time_entries, BookingsOf, Capacity, DayCapacity, TimeEntriesOf};
+use crate::models::{Booking, TimeEntry};
+
+/// The booked and logged time of a resource on a project over a period, in minutes.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Variance {
+    pub project_id: u64,
+    pub resource_id: u64,
+    /// The first day of the period.
+    pub period: NaiveDate,
+    pub booked: u64,
+    pub logged: u64,
+    /// The logged time less the booked time.
+    pub variance: i64,
+    /// The variance as a percentage of the booked time, none without booked time.
+    pub variance_percent: Option<f64>,
+    /// The bookings over the period.
+    pub bookings: Vec<Booking>,
+    /// The time entries of the period.
+    pub time_entries: Vec<TimeEntry>,
+}
+
+#[derive(Default)]
+struct Sums {
+    booked: u64,
+    logged: u64,
+    bookings: Vec<Booking>,
+    time_entries: Vec<TimeEntry>,
+}
+
+/// Compares the time booked on projects with the time logged on them, by project, resource and
+/// week or month.
+///
+/// Bookings count on the days [`DayCapacity::books`] them. Time entries count on their start date.
+#[derive(Debug, Clone, Default)]
+pub struct VarianceReport {
+    capacity: Capacity,
+    period: Period,
+}
+
+impl VarianceReport {
+    pub fn new(capacity: Capacity) -> Self {
+        Self {
+            capacity,
+            period: Period::Week,
+        }
+    }
+
+    /// Sum over weeks or months, weeks by default.
+    pub fn period(mut self, period: Period) -> Self {
+        self.period = period;
+        self
+    }
+
+    /// The variance of bookings and time entries, given the capacity of their resources on the
+    /// days to report on. Rows are ordered by project, resource and period.
+    pub fn calculate(
+        &self,
+        capacity: &[DayCapacity],
+        bookings: &[Booking],
+        time_entries: &[TimeEntry],
+    ) -> Vec<Variance> {
+        let mut resource_days: HashMap<u64, Vec<&DayCapacity>> = HashMap::new();
+        for day in capacity {
+            resource_days.entry(day.resource_id).or_default().push(day);
+        }
+        let days = capacity
+            .iter()
+            .map(|day| (day.resource_id, day.date))
+            .collect::<BTreeSet<_>>();
+
+        let mut sums: BTreeMap<(u64, u64, NaiveDate), Sums> = BTreeMap::new();
+        for booking in bookings {
+            let days = resource_days
+                .get(&booking.resource_id)
+                .into_iter()
+                .flatten()
+                .filter(|day| day.books(booking));
+            let mut periods = BTreeSet::new();
+            for day in days {
+                let period = self.period.start(day.date);
+                let sums = sums
+                    .entry((booking.project_id, booking.resource_id, period))
+                    .or_default();
+                sums.booked += booking.minutes;
+                if periods.insert(period) {
+                    sums.bookings.push(booking.clone());
+                }
+            }
+        }
+        for entry in time_entries {
+            if !days.contains(&(entry.resource_id, entry.start_date)) {
+                continue;
+            }
+            let period = self.period.start(entry.start_date);
+            let sums = sums
+                .entry((entry.project_id, entry.resource_id, period))
+                .or_default();
+            sums.logged += entry.minutes;
+            sums.time_entries.push(entry.clone());
+        }
+
+        sums.into_iter()
+            .map(|((project_id, resource_id, period), sums)| {
+                let variance = sums.logged as i64 - sums.booked as i64;
+                Variance {
+                    project_id,
+                    resource_id,
+                    period,
+                    booked: sums.booked,
+                    logged: sums.logged,
+                    variance,
+                    variance_percent: if sums.booked == 0 {
+                        None
+                    } else {
+                        Some(variance as f64 * 100.0 / sums.booked as f64)
+                    },
+                    bookings: sums.bookings,
+                    time_entries: sums.time_entries,
+                }
+            })
+            .collect()
+    }
+
+    /// Fetch the bookings and time entries of projects, or of all projects without any, from
+    /// `from` to `to` inclusive and compare them.
+    pub fn fetch<C: Client>(
+        &self,
+        projects: &[u64],
+        from: NaiveDate,
+        to: NaiveDate,
+        client: &C,
+    ) -> Result<Vec<Variance>, ApiError<C::Error>> {
+        let bookings = bookings(BookingsOf::Projects(projects), from, Some(to), client)?;
+
+        let time_entries = time_entries(TimeEntriesOf::Projects(projects), Some(from), to, client)?;
+
+        let resources = bookings
+            .iter()
+            .map(|booking| booking.resource_id)
+            .chain(time_entries.iter().map(|entry| entry.resource_id))
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect::<Vec<_>>();
+        let capacity = self.capacity.fetch(&resources, from, to, client)?;
+
+        Ok(self.calculate(&capacity, &bookings, &time_entries))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::fixtures::{booking, date, insert_holiday, time_entry};
+    use crate::testing::FakeTeamdeck;
+
+    #[test]
+    fn compares_booked_and_logged_time() {
+        let teamdeck = FakeTeamdeck::new();
+        insert_holiday(&teamdeck, "2026-06-01");
+        let planned = teamdeck.insert("bookings", booking(1, 10, "2026-06-01", "2026-06-09", 240));
+        teamdeck.insert("bookings", booking(1, 11, "2026-06-01", "2026-06-05", 240));
+        let logged = teamdeck.insert("time-entries", time_entry(1, 10, "2026-06-02", 600));
+        teamdeck.insert("time-entries", time_entry(1, 10, "2026-06-03", 300));
+        teamdeck.insert("time-entries", time_entry(2, 10, "2026-06-10", 60));
+        teamdeck.insert("time-entries", time_entry(2, 11, "2026-06-10", 60));
+
+        let variance = VarianceReport::default()
+            .fetch(&[10], date(1), date(14), &teamdeck)
+            .unwrap();
+
+        let rows = variance
+            .iter()
+            .map(|row| {
+                (
+                    row.project_id,
+                    row.resource_id,
+                    row.period,
+                    row.booked,
+                    row.logged,
+                    row.variance,
+                    row.variance_percent,
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            rows,
+            vec![
+                (10, 1, date(1), 960, 900, -60, Some(-6.25)),
+                (10, 1, date(8), 480, 0, -480, Some(-100.0)),
+                (10, 2, date(8), 0, 60, 60, None),
+            ]
+        );
+
+        assert_eq!(variance[0].bookings.len(), 1);
+        assert_eq!(variance[0].bookings[0].id, planned);
+        assert_eq!(
+            variance[0]
+                .time_entries
+                .iter()
+                .map(|entry| entry.minutes)
+                .collect::<Vec<_>>(),
+            vec![600, 300]
+        );
+        assert_eq!(variance[0].time_entries[0].id, logged);
+    }
+}
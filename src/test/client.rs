@@ -34,14 +34,14 @@ impl TestClient {
         client
     }
 
-    pub(crate) fn expect(&mut self, req: ExpectedRequest) -> Mock {
+    pub(crate) fn expect(&mut self, req: ExpectedRequest) -> Mock<'_> {
         let mock = mock_request(&self.server, req);
         self.mocks.push(MockId(mock.id));
         mock
     }
 
     pub(crate) fn url_str(&self, path: &str) -> String {
-        self.server.url(&format!("/{}", path))
+        self.server.url(format!("/{}", path))
     }
 
     pub(crate) fn assert_mocks(&self) {
@@ -0,0 +1,461 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use http::{HeaderMap, Response};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use url::Url;
+
+use crate::api::{self, ApiError};
+
+use super::BASE_URL;
+
+/// The header carrying the Teamdeck API key, which is never written to a cassette.
+const API_KEY_HEADER: &str = "x-api-key";
+const REDACTED: &str = "[REDACTED]";
+
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum CassetteError {
+    #[error("failed to access the cassette: {}", source)]
+    Io {
+        #[from]
+        source: std::io::Error,
+    },
+    #[error("invalid cassette: {}", source)]
+    Json {
+        #[from]
+        source: serde_json::Error,
+    },
+    #[error("invalid request: {}", source)]
+    Http {
+        #[from]
+        source: http::Error,
+    },
+    #[error("no recorded response for {} {}", method, endpoint)]
+    Unmatched { method: String, endpoint: String },
+}
+
+/// Errors of a [`RecordingClient`].
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum RecordingError<E>
+where
+    E: Error + Send + Sync + 'static,
+{
+    #[error("client error: {}", source)]
+    Client { source: E },
+    #[error("{}", source)]
+    Cassette {
+        #[from]
+        source: CassetteError,
+    },
+}
+
+/// A recorded request, with its URL relative to the base URL of the client.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecordedRequest {
+    pub method: String,
+    pub endpoint: String,
+    #[serde(default)]
+    pub headers: BTreeMap<String, String>,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub body: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecordedResponse {
+    pub status: u16,
+    #[serde(default)]
+    pub headers: BTreeMap<String, String>,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub body: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Interaction {
+    pub request: RecordedRequest,
+    pub response: RecordedResponse,
+}
+
+/// The request/response pairs recorded by a [`RecordingClient`], in the order they were sent.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Cassette {
+    pub interactions: Vec<Interaction>,
+}
+
+impl Cassette {
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, CassetteError> {
+        Ok(serde_json::from_slice(&fs::read(path)?)?)
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), CassetteError> {
+        Ok(fs::write(path, serde_json::to_vec_pretty(self)?)?)
+    }
+}
+
+fn recorded_headers(headers: Option<&HeaderMap>) -> BTreeMap<String, String> {
+    headers
+        .into_iter()
+        .flatten()
+        .map(|(name, value)| {
+            let value = if name == API_KEY_HEADER {
+                REDACTED.into()
+            } else {
+                String::from_utf8_lossy(value.as_bytes()).into_owned()
+            };
+            (name.as_str().into(), value)
+        })
+        .collect()
+}
+
+fn relative_endpoint(base_url: &Url, uri: Option<&http::Uri>) -> String {
+    let uri = uri.map(ToString::to_string).unwrap_or_default();
+    let endpoint = uri.strip_prefix(base_url.as_str()).unwrap_or(&uri);
+    endpoint.trim_end_matches('?').into()
+}
+
+/// A client which sends requests through another client, writing every request and its response
+/// to a cassette file which a [`ReplayClient`] can serve later.
+///
+/// The cassette is rewritten after every response so that it is complete even if the program is
+/// interrupted. The `x-api-key` header is redacted.
+#[derive(Debug)]
+pub struct RecordingClient<C> {
+    client: C,
+    path: PathBuf,
+    cassette: Mutex<Cassette>,
+}
+
+impl<C> RecordingClient<C>
+where
+    C: api::RestClient,
+{
+    /// Record the requests sent through `client` to a new cassette at `path`.
+    pub fn new<P: Into<PathBuf>>(client: C, path: P) -> Self {
+        Self {
+            client,
+            path: path.into(),
+            cassette: Mutex::default(),
+        }
+    }
+
+    /// The interactions recorded so far.
+    pub fn cassette(&self) -> Cassette {
+        self.cassette.lock().unwrap().clone()
+    }
+
+    pub fn into_inner(self) -> C {
+        self.client
+    }
+
+    fn recorded_request(
+        &self,
+        request: &http::request::Builder,
+        body: &[u8],
+    ) -> Result<RecordedRequest, ApiError<RecordingError<C::Error>>> {
+        let base_url = self
+            .client
+            .rest_endpoint("")
+            .map_err(|err| err.map_client(|source| RecordingError::Client { source }))?;
+
+        Ok(RecordedRequest {
+            method: request
+                .method_ref()
+                .map(ToString::to_string)
+                .unwrap_or_else(|| "GET".into()),
+            endpoint: relative_endpoint(&base_url, request.uri_ref()),
+            headers: recorded_headers(request.headers_ref()),
+            body: String::from_utf8_lossy(body).into_owned(),
+        })
+    }
+
+    fn record(
+        &self,
+        request: RecordedRequest,
+        response: &Response<Bytes>,
+    ) -> Result<(), ApiError<RecordingError<C::Error>>> {
+        let response = RecordedResponse {
+            status: response.status().as_u16(),
+            headers: recorded_headers(Some(response.headers())),
+            body: String::from_utf8_lossy(response.body()).into_owned(),
+        };
+
+        let mut cassette = self.cassette.lock().unwrap();
+        cassette
+            .interactions
+            .push(Interaction { request, response });
+        cassette
+            .save(&self.path)
+            .map_err(|source| ApiError::client(source.into()))
+    }
+}
+
+impl<C> api::RestClient for RecordingClient<C>
+where
+    C: api::RestClient,
+{
+    type Error = RecordingError<C::Error>;
+
+    fn rest_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>> {
+        self.client
+            .rest_endpoint(endpoint)
+            .map_err(|err| err.map_client(|source| RecordingError::Client { source }))
+    }
+}
+
+impl<C> api::Client for RecordingClient<C>
+where
+    C: api::Client,
+{
+    fn rest(
+        &self,
+        request: http::request::Builder,
+        body: Vec<u8>,
+    ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+        let recorded = self.recorded_request(&request, &body)?;
+        let response = self
+            .client
+            .rest(request, body)
+            .map_err(|err| err.map_client(|source| RecordingError::Client { source }))?;
+
+        self.record(recorded, &response)?;
+
+        Ok(response)
+    }
+}
+
+#[async_trait]
+impl<C> api::AsyncClient for RecordingClient<C>
+where
+    C: api::AsyncClient + Sync,
+{
+    async fn rest_async(
+        &self,
+        request: http::request::Builder,
+        body: Vec<u8>,
+    ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+        let recorded = self.recorded_request(&request, &body)?;
+        let response = self
+            .client
+            .rest_async(request, body)
+            .await
+            .map_err(|err| err.map_client(|source| RecordingError::Client { source }))?;
+
+        self.record(recorded, &response)?;
+
+        Ok(response)
+    }
+}
+
+/// A client which answers requests with the responses of a [`Cassette`].
+///
+/// A request matches an interaction with the same method, endpoint and body; request headers are
+/// ignored. Every interaction is served once, in the recorded order, and a request which matches
+/// no remaining interaction fails with [`CassetteError::Unmatched`].
+#[derive(Debug)]
+pub struct ReplayClient {
+    base_url: Url,
+    interactions: Vec<Interaction>,
+    served: Mutex<Vec<bool>>,
+}
+
+impl ReplayClient {
+    pub fn new(cassette: Cassette) -> Self {
+        Self {
+            base_url: Url::parse(BASE_URL).unwrap(),
+            served: Mutex::new(vec![false; cassette.interactions.len()]),
+            interactions: cassette.interactions,
+        }
+    }
+
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, CassetteError> {
+        Ok(Self::new(Cassette::load(path)?))
+    }
+
+    /// The number of interactions which have not been served yet.
+    pub fn remaining(&self) -> usize {
+        self.served
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|served| !**served)
+            .count()
+    }
+
+    fn replay(&self, request: http::Request<Vec<u8>>) -> Result<Response<Bytes>, CassetteError> {
+        let method = request.method().to_string();
+        let endpoint = relative_endpoint(&self.base_url, Some(request.uri()));
+        let body = String::from_utf8_lossy(request.body());
+
+        let mut served = self.served.lock().unwrap();
+        let index = self
+            .interactions
+            .iter()
+            .enumerate()
+            .position(|(index, interaction)| {
+                !served[index]
+                    && interaction.request.method == method
+                    && interaction.request.endpoint == endpoint
+                    && interaction.request.body == body
+            })
+            .ok_or(CassetteError::Unmatched { method, endpoint })?;
+        served[index] = true;
+
+        let recorded = &self.interactions[index].response;
+        let mut response = Response::builder().status(recorded.status);
+        for (name, value) in &recorded.headers {
+            response = response.header(name, value);
+        }
+
+        Ok(response.body(recorded.body.clone().into())?)
+    }
+}
+
+impl api::RestClient for ReplayClient {
+    type Error = CassetteError;
+
+    fn rest_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>> {
+        Ok(self.base_url.join(endpoint)?)
+    }
+}
+
+impl api::Client for ReplayClient {
+    fn rest(
+        &self,
+        request: http::request::Builder,
+        body: Vec<u8>,
+    ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+        let request = request
+            .body(body)
+            .map_err(|source| ApiError::client(source.into()))?;
+
+        self.replay(request).map_err(ApiError::client)
+    }
+}
+
+#[async_trait]
+impl api::AsyncClient for ReplayClient {
+    async fn rest_async(
+        &self,
+        request: http::request::Builder,
+        body: Vec<u8>,
+    ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+        <Self as api::Client>::rest(self, request, body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+    use serde_json::{json, Value};
+
+    use super::*;
+    use crate::api::{
+        resources::{Resource, Resources},
+        AsyncQuery, Client, Query,
+    };
+    use crate::testing::FakeTeamdeck;
+
+    fn fake() -> FakeTeamdeck {
+        let fake = FakeTeamdeck::new();
+        fake.insert("resources", json!({ "name": "Ada", "active": true }));
+        fake.insert("resources", json!({ "name": "Grace", "active": false }));
+        fake
+    }
+
+    #[test]
+    fn replays_recorded_responses() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("resources.json");
+
+        let recorder = RecordingClient::new(fake(), &path);
+        let endpoint = Resources::builder().active(true).build().unwrap();
+        let recorded: Vec<Value> = endpoint.query(&recorder).unwrap();
+        assert_eq!(recorded.len(), 1);
+
+        let cassette = Cassette::load(&path).unwrap();
+        assert_eq!(cassette, recorder.cassette());
+        assert_eq!(
+            cassette.interactions[0].request.endpoint,
+            "resources?active=1"
+        );
+
+        let replay = ReplayClient::from_file(&path).unwrap();
+        let replayed: Vec<Value> = endpoint.query(&replay).unwrap();
+        assert_eq!(replayed, recorded);
+        assert_eq!(replay.remaining(), 0);
+    }
+
+    #[test]
+    fn unmatched_request_fails() {
+        let replay = ReplayClient::new(Cassette::default());
+
+        let err = api::ignore(Resource::builder().id(1).build().unwrap())
+            .query(&replay)
+            .unwrap_err();
+
+        if let ApiError::Client {
+            source: CassetteError::Unmatched { method, endpoint },
+        } = err
+        {
+            assert_eq!(method, "GET");
+            assert_eq!(endpoint, "resources/1");
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    #[test]
+    fn interactions_are_served_once() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("resource.json");
+
+        let recorder = RecordingClient::new(fake(), &path);
+        let endpoint = api::ignore(Resource::builder().id(1).build().unwrap());
+        endpoint.query(&recorder).unwrap();
+
+        let replay = ReplayClient::new(recorder.cassette());
+        endpoint.query(&replay).unwrap();
+        assert!(endpoint.query(&replay).is_err());
+    }
+
+    #[test]
+    fn redacts_api_key() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("redacted.json");
+
+        let recorder = RecordingClient::new(fake(), &path);
+        let request = http::Request::builder()
+            .method(Method::GET)
+            .uri(format!("{}resources", BASE_URL))
+            .header("X-Api-Key", "secret");
+        recorder.rest(request, Vec::new()).unwrap();
+
+        let contents = fs::read_to_string(&path).unwrap();
+        assert!(!contents.contains("secret"));
+        assert_eq!(
+            recorder.cassette().interactions[0].request.headers[API_KEY_HEADER],
+            REDACTED
+        );
+    }
+
+    #[tokio::test]
+    async fn records_async_requests() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("async.json");
+
+        let recorder = RecordingClient::new(fake(), &path);
+        let endpoint = Resources::builder().build().unwrap();
+        let recorded: Vec<Value> = endpoint.query_async(&recorder).await.unwrap();
+
+        let replay = ReplayClient::from_file(&path).unwrap();
+        let replayed: Vec<Value> = endpoint.query_async(&replay).await.unwrap();
+        assert_eq!(replayed, recorded);
+    }
+}
@@ -0,0 +1,603 @@
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use chrono::NaiveDate;
+use http::{Method, Request, Response, StatusCode};
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+use url::Url;
+
+use crate::api::{self, ApiError};
+
+use super::{TestingError, BASE_URL};
+
+/// The number of records per page, the same as the page size [`api::paged()`] expects.
+pub const PAGE_SIZE: usize = 10;
+
+#[derive(Debug, Default)]
+struct State {
+    collections: BTreeMap<String, Vec<Value>>,
+    next_id: u64,
+}
+
+impl State {
+    fn next_id(&mut self) -> u64 {
+        self.next_id += 1;
+        self.next_id
+    }
+}
+
+/// A stateful, in-memory stand-in for the Teamdeck API.
+///
+/// Records are kept as JSON objects in collections named after the endpoint URLs, e.g.
+/// `"resources"`, `"time-entries"` or `"projects/1/milestones"`. List endpoints honour filters,
+/// `sort` and `page`, and create, update and delete endpoints change the stored records:
+///
+/// - `field=value` keeps records whose field equals the value, or any of comma separated values.
+///   `name` matches case-insensitively on a part of the name.
+/// - `field_from` and `field_to` keep records whose field is within the (inclusive) range.
+/// - `date` keeps records whose `start_date`..`end_date` range contains the date.
+/// - `from` and `to` keep records whose `date` is within the range.
+pub struct FakeTeamdeck {
+    base_url: Url,
+    state: Mutex<State>,
+}
+
+impl fmt::Debug for FakeTeamdeck {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("FakeTeamdeck")
+            .field("base_url", &self.base_url)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Default for FakeTeamdeck {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FakeTeamdeck {
+    pub fn new() -> Self {
+        Self {
+            base_url: Url::parse(BASE_URL).unwrap(),
+            state: Mutex::default(),
+        }
+    }
+
+    fn state(&self) -> std::sync::MutexGuard<'_, State> {
+        self.state
+            .lock()
+            .expect("the fake Teamdeck state is poisoned")
+    }
+
+    /// Store a record in a collection, returning its id. Records without an `id` get a new one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the record does not serialize to a JSON object.
+    pub fn insert<T: Serialize>(&self, collection: &str, record: T) -> u64 {
+        let mut record = match serde_json::to_value(record) {
+            Ok(Value::Object(record)) => record,
+            _ => panic!("records of the fake Teamdeck must be JSON objects"),
+        };
+
+        let mut state = self.state();
+        let id = match record.get("id").and_then(Value::as_u64) {
+            Some(id) => {
+                state.next_id = state.next_id.max(id);
+                id
+            }
+            None => {
+                let id = state.next_id();
+                record.insert("id".into(), id.into());
+                id
+            }
+        };
+
+        state
+            .collections
+            .entry(collection.trim_matches('/').into())
+            .or_default()
+            .push(Value::Object(record));
+
+        id
+    }
+
+    /// All the records of a collection, in insertion order.
+    pub fn records(&self, collection: &str) -> Vec<Value> {
+        self.state()
+            .collections
+            .get(collection.trim_matches('/'))
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// A record of a collection.
+    pub fn record(&self, collection: &str, id: u64) -> Option<Value> {
+        self.records(collection)
+            .into_iter()
+            .find(|record| record_id(record) == Some(id))
+    }
+
+    fn handle(&self, request: Request<Vec<u8>>) -> Response<Bytes> {
+        let url = match Url::parse(&request.uri().to_string()) {
+            Ok(url) => url,
+            Err(_) => return error(StatusCode::BAD_REQUEST, "invalid URL"),
+        };
+        let path = url
+            .path()
+            .strip_prefix(self.base_url.path())
+            .unwrap_or_else(|| url.path())
+            .trim_matches('/');
+        let params = url.query_pairs().into_owned().collect::<Vec<_>>();
+
+        let (collection, id) = match path.rsplit_once('/') {
+            Some((collection, id)) => match id.parse::<u64>() {
+                Ok(id) => (collection, Some(id)),
+                Err(_) => (path, None),
+            },
+            None => (path, None),
+        };
+
+        match (request.method().clone(), id) {
+            (Method::GET, None) => self.list(collection, &params),
+            (Method::GET, Some(id)) => match self.record(collection, id) {
+                Some(record) => respond(StatusCode::OK, &record),
+                None => not_found(),
+            },
+            (Method::POST, None) => self.create(collection, request.body()),
+            (Method::PUT, Some(id)) => self.update(collection, id, request.body()),
+            (Method::DELETE, Some(id)) => self.delete(collection, id),
+            _ => error(StatusCode::METHOD_NOT_ALLOWED, "method not allowed"),
+        }
+    }
+
+    fn list(&self, collection: &str, params: &[(String, String)]) -> Response<Bytes> {
+        let mut records = self
+            .records(collection)
+            .into_iter()
+            .filter(|record| matches_filters(record, params))
+            .collect::<Vec<_>>();
+
+        if let Some((_, sort)) = params.iter().find(|(key, _)| key == "sort") {
+            let (field, descending) = match sort.strip_prefix('-') {
+                Some(field) => (field, true),
+                None => (sort.as_str(), false),
+            };
+            records.sort_by(|a, b| {
+                let ordering = compare(&a[field], &b[field]);
+                if descending {
+                    ordering.reverse()
+                } else {
+                    ordering
+                }
+            });
+        }
+
+        let page = params
+            .iter()
+            .rev()
+            .find(|(key, _)| key == "page")
+            .and_then(|(_, page)| page.parse::<usize>().ok())
+            .unwrap_or(1)
+            .max(1);
+
+        let page = records
+            .into_iter()
+            .skip((page - 1) * PAGE_SIZE)
+            .take(PAGE_SIZE)
+            .collect::<Vec<_>>();
+
+        respond(StatusCode::OK, &page)
+    }
+
+    fn create(&self, collection: &str, body: &[u8]) -> Response<Bytes> {
+        let fields = match serde_json::from_slice::<Map<String, Value>>(body) {
+            Ok(fields) => fields,
+            Err(_) => return error(StatusCode::BAD_REQUEST, "body must be a JSON object"),
+        };
+
+        let id = self.insert(collection, Value::Object(without_id(fields)));
+        let record = self
+            .record(collection, id)
+            .expect("the record was just inserted");
+
+        respond(StatusCode::CREATED, &record)
+    }
+
+    fn update(&self, collection: &str, id: u64, body: &[u8]) -> Response<Bytes> {
+        let fields = match serde_json::from_slice::<Map<String, Value>>(body) {
+            Ok(fields) => fields,
+            Err(_) => return error(StatusCode::BAD_REQUEST, "body must be a JSON object"),
+        };
+
+        let mut state = self.state();
+        let record = state
+            .collections
+            .get_mut(collection)
+            .and_then(|records| {
+                records
+                    .iter_mut()
+                    .find(|record| record_id(record) == Some(id))
+            })
+            .and_then(Value::as_object_mut);
+
+        match record {
+            Some(record) => {
+                record.extend(without_id(fields));
+                respond(StatusCode::OK, &Value::Object(record.clone()))
+            }
+            None => not_found(),
+        }
+    }
+
+    fn delete(&self, collection: &str, id: u64) -> Response<Bytes> {
+        let mut state = self.state();
+        let records = match state.collections.get_mut(collection) {
+            Some(records) => records,
+            None => return not_found(),
+        };
+
+        let count = records.len();
+        records.retain(|record| record_id(record) != Some(id));
+
+        if records.len() == count {
+            return not_found();
+        }
+
+        Response::builder()
+            .status(StatusCode::NO_CONTENT)
+            .body(Bytes::new())
+            .unwrap()
+    }
+}
+
+fn record_id(record: &Value) -> Option<u64> {
+    record.get("id").and_then(Value::as_u64)
+}
+
+fn without_id(mut fields: Map<String, Value>) -> Map<String, Value> {
+    fields.remove("id");
+    fields
+}
+
+fn respond<T: Serialize>(status: StatusCode, body: &T) -> Response<Bytes> {
+    Response::builder()
+        .status(status)
+        .header("Content-Type", "application/json")
+        .body(serde_json::to_vec(body).unwrap().into())
+        .unwrap()
+}
+
+fn error(status: StatusCode, message: &str) -> Response<Bytes> {
+    respond(status, &json!({ "message": message }))
+}
+
+fn not_found() -> Response<Bytes> {
+    error(StatusCode::NOT_FOUND, "Not found")
+}
+
+/// The value of a field as it would be written in a query parameter.
+fn param_value(value: &Value) -> Option<String> {
+    match value {
+        Value::Bool(true) => Some("1".into()),
+        Value::Bool(false) => Some("0".into()),
+        Value::Number(number) => Some(number.to_string()),
+        Value::String(string) => Some(string.clone()),
+        _ => None,
+    }
+}
+
+fn matches_filters(record: &Value, params: &[(String, String)]) -> bool {
+    params.iter().all(|(key, value)| match key.as_str() {
+        "sort" | "page" | "expand" => true,
+        "date" => {
+            let start = record.get("start_date").and_then(param_value);
+            let end = record.get("end_date").and_then(param_value);
+            match (start, end) {
+                (Some(start), Some(end)) => start <= *value && *value <= end,
+                _ => record.get("date").and_then(param_value).as_ref() == Some(value),
+            }
+        }
+        "from" => matches_bound(record, "date", value, Ordering::Greater),
+        "to" => matches_bound(record, "date", value, Ordering::Less),
+        "name" => record
+            .get("name")
+            .and_then(Value::as_str)
+            .map(|name| name.to_lowercase().contains(&value.to_lowercase()))
+            .unwrap_or(false),
+        _ => {
+            if let Some(field) = key.strip_suffix("_from") {
+                matches_bound(record, field, value, Ordering::Greater)
+            } else if let Some(field) = key.strip_suffix("_to") {
+                matches_bound(record, field, value, Ordering::Less)
+            } else {
+                let field = record.get(key).and_then(param_value);
+                value
+                    .split(',')
+                    .any(|value| field.as_deref() == Some(value))
+            }
+        }
+    })
+}
+
+fn matches_bound(record: &Value, field: &str, bound: &str, allowed: Ordering) -> bool {
+    match record.get(field).and_then(param_value) {
+        Some(value) => {
+            let ordering = compare_bound(&value, bound);
+            ordering == Ordering::Equal || ordering == allowed
+        }
+        None => false,
+    }
+}
+
+/// Compare a field with a filter bound as dates, or as numbers, falling back to text.
+fn compare_bound(value: &str, bound: &str) -> Ordering {
+    // Date-times are compared by their date, as Teamdeck filters on days.
+    let date = |text: &str| {
+        let day = text.split(['T', ' ']).next().unwrap_or(text);
+        NaiveDate::parse_from_str(day, "%Y-%m-%d").ok()
+    };
+    if let (Some(value), Some(bound)) = (date(value), date(bound)) {
+        return value.cmp(&bound);
+    }
+    if let (Ok(value), Ok(bound)) = (value.parse::<f64>(), bound.parse::<f64>()) {
+        return value.partial_cmp(&bound).unwrap_or(Ordering::Equal);
+    }
+
+    value.cmp(bound)
+}
+
+fn compare(a: &Value, b: &Value) -> Ordering {
+    match (a, b) {
+        (Value::Number(a), Value::Number(b)) => a
+            .as_f64()
+            .partial_cmp(&b.as_f64())
+            .unwrap_or(Ordering::Equal),
+        (Value::String(a), Value::String(b)) => a.cmp(b),
+        (Value::Bool(a), Value::Bool(b)) => a.cmp(b),
+        (Value::Null, Value::Null) => Ordering::Equal,
+        (Value::Null, _) => Ordering::Less,
+        (_, Value::Null) => Ordering::Greater,
+        _ => Ordering::Equal,
+    }
+}
+
+impl api::RestClient for FakeTeamdeck {
+    type Error = TestingError;
+
+    fn rest_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>> {
+        Ok(self.base_url.join(endpoint)?)
+    }
+}
+
+impl api::Client for FakeTeamdeck {
+    fn rest(
+        &self,
+        request: http::request::Builder,
+        body: Vec<u8>,
+    ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+        let request = request
+            .body(body)
+            .map_err(|source| ApiError::client(TestingError::Request { source }))?;
+
+        Ok(self.handle(request))
+    }
+}
+
+#[async_trait]
+impl api::AsyncClient for FakeTeamdeck {
+    async fn rest_async(
+        &self,
+        request: http::request::Builder,
+        body: Vec<u8>,
+    ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+        <Self as api::Client>::rest(self, request, body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use serde_json::json;
+
+    use super::*;
+    use crate::api::{
+        self,
+        resources::{Resources, ResourcesSortBy},
+        sort_by::SortBy,
+        time_entries::{
+            CreateTimeEntry, DeleteTimeEntry, PartialUpdateTimeEntry, TimeEntries, TimeEntry,
+            TimeEntryOperation,
+        },
+        AsyncQuery, Pagination, Query,
+    };
+    use crate::models;
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 1, day).unwrap()
+    }
+
+    fn create_time_entry(resource_id: u64, day: u32) -> CreateTimeEntry<'static> {
+        CreateTimeEntry::builder()
+            .resource_id(resource_id)
+            .project_id(1)
+            .minutes(60)
+            .start_date(date(day))
+            .end_date(date(day))
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn lists_with_filters_sorting_and_pagination() {
+        let fake = FakeTeamdeck::new();
+        for i in 0..25 {
+            fake.insert(
+                "resources",
+                json!({
+                    "name": format!("Resource {:02}", i),
+                    "email": format!("r{}@example.com", i),
+                    "active": i % 5 != 0,
+                }),
+            );
+        }
+
+        let endpoint = Resources::builder()
+            .active(true)
+            .sort(SortBy::Desc(ResourcesSortBy::Name))
+            .build()
+            .unwrap();
+
+        let first_page: Vec<Value> = endpoint.query(&fake).unwrap();
+        assert_eq!(first_page.len(), PAGE_SIZE);
+        assert_eq!(first_page[0]["name"], "Resource 24");
+
+        let all: Vec<Value> = api::paged(endpoint, Pagination::All).query(&fake).unwrap();
+        assert_eq!(all.len(), 20);
+        assert!(all.iter().all(|resource| resource["active"] == true));
+    }
+
+    #[test]
+    fn creates_updates_and_deletes_records() {
+        let fake = FakeTeamdeck::new();
+
+        let created: models::TimeEntry = create_time_entry(1, 5).query(&fake).unwrap();
+        assert_eq!(created.minutes, 60);
+
+        let update = PartialUpdateTimeEntry::builder()
+            .id(created.id)
+            .minutes(90)
+            .description("review")
+            .build()
+            .unwrap();
+        let updated: models::TimeEntry = update.query(&fake).unwrap();
+        assert_eq!(updated.minutes, 90);
+        assert_eq!(updated.description.as_deref(), Some("review"));
+
+        let fetched: models::TimeEntry = TimeEntry::builder()
+            .id(created.id as usize)
+            .build()
+            .unwrap()
+            .query(&fake)
+            .unwrap();
+        assert_eq!(fetched, updated);
+
+        api::ignore(
+            DeleteTimeEntry::builder()
+                .id(created.id as usize)
+                .build()
+                .unwrap(),
+        )
+        .query(&fake)
+        .unwrap();
+        assert!(fake.records("time-entries").is_empty());
+    }
+
+    #[test]
+    fn filters_by_date_range() {
+        let fake = FakeTeamdeck::new();
+        for day in 1..=10 {
+            api::ignore(create_time_entry(day as u64 % 2, day))
+                .query(&fake)
+                .unwrap();
+        }
+
+        let entries: Vec<models::TimeEntry> = TimeEntries::builder()
+            .resource_id(vec![1])
+            .start_date_from(date(3))
+            .end_date_to(date(8))
+            .build()
+            .unwrap()
+            .query(&fake)
+            .unwrap();
+
+        let days = entries
+            .iter()
+            .map(|entry| entry.start_date)
+            .collect::<Vec<_>>();
+        assert_eq!(days, vec![date(3), date(5), date(7)]);
+    }
+
+    #[test]
+    fn compares_bounds_as_dates_and_numbers() {
+        let fake = FakeTeamdeck::new();
+        for (day, minutes) in [("2026-01-09", 9), ("2026-01-10", 10), ("2026-01-11", 100)] {
+            fake.insert(
+                "time-entries",
+                json!({ "start_date": day, "end_date": day, "minutes": minutes }),
+            );
+        }
+
+        let params = |params: &[(&str, &str)]| {
+            params
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.to_string()))
+                .collect::<Vec<_>>()
+        };
+        let matching = |params: Vec<(String, String)>| {
+            fake.records("time-entries")
+                .iter()
+                .filter(|record| matches_filters(record, &params))
+                .map(|record| record["minutes"].as_u64().unwrap())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            matching(params(&[("start_date_from", "2026-1-10")])),
+            vec![10, 100]
+        );
+        assert_eq!(
+            matching(params(&[("start_date_to", "2026-01-9T12:00:00")])),
+            vec![9]
+        );
+        assert_eq!(matching(params(&[("minutes_from", "10")])), vec![10, 100]);
+        assert_eq!(matching(params(&[("minutes_to", "9.5")])), vec![9]);
+    }
+
+    #[test]
+    fn missing_record_is_not_found() {
+        let fake = FakeTeamdeck::new();
+
+        let err = api::ignore(TimeEntry::builder().id(1).build().unwrap())
+            .query(&fake)
+            .unwrap_err();
+
+        if let ApiError::Teamdeck { msg } = err {
+            assert_eq!(msg, "Not found");
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    #[tokio::test]
+    async fn serves_async_queries() {
+        let fake = FakeTeamdeck::new();
+        let operations = (1..=5)
+            .map(|day| TimeEntryOperation::from(create_time_entry(1, day)))
+            .chain(Some(
+                DeleteTimeEntry::builder().id(100).build().unwrap().into(),
+            ));
+
+        let report = api::batch(operations)
+            .concurrency(2)
+            .query_async(&fake)
+            .await
+            .unwrap();
+
+        let failed = report.failed().map(|item| item.index).collect::<Vec<_>>();
+        assert_eq!(failed, vec![5]);
+
+        let entries: Vec<models::TimeEntry> =
+            api::paged(TimeEntries::builder().build().unwrap(), Pagination::All)
+                .query_async(&fake)
+                .await
+                .unwrap();
+        assert_eq!(entries.len(), 5);
+    }
+}
@@ -0,0 +1,52 @@
+//! Records shared by the tests of the reports and calculators, in June 2026.
+
+use chrono::NaiveDate;
+use serde_json::{json, Value};
+
+use super::FakeTeamdeck;
+
+/// A day of June 2026, which starts on a Monday.
+pub(crate) fn date(day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(2026, 6, day).unwrap()
+}
+
+/// Add a holiday for everyone on a day, returning its id.
+pub(crate) fn insert_holiday(teamdeck: &FakeTeamdeck, day: &str) -> u64 {
+    teamdeck.insert("holidays", json!({ "name": "Holiday", "date": day }))
+}
+
+pub(crate) fn booking(
+    resource_id: u64,
+    project_id: u64,
+    start: &str,
+    end: &str,
+    minutes: u64,
+) -> Value {
+    json!({
+        "resource_id": resource_id,
+        "project_id": project_id,
+        "start_date": start,
+        "end_date": end,
+        "minutes": minutes,
+    })
+}
+
+/// A time entry of a single day.
+pub(crate) fn time_entry(resource_id: u64, project_id: u64, day: &str, minutes: u64) -> Value {
+    json!({
+        "resource_id": resource_id,
+        "project_id": project_id,
+        "start_date": day,
+        "end_date": day,
+        "minutes": minutes,
+    })
+}
+
+pub(crate) fn vacation(resource_id: u64, start: &str, end: &str, status: &str) -> Value {
+    json!({
+        "resource_id": resource_id,
+        "start_date": start,
+        "end_date": end,
+        "status": status,
+    })
+}
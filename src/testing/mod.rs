@@ -0,0 +1,27 @@
+//! Clients for testing code which uses the Teamdeck API without network access.
+
+mod cassette;
+mod fake;
+#[cfg(test)]
+pub(crate) mod fixtures;
+
+use thiserror::Error;
+
+pub use cassette::{
+    Cassette, CassetteError, Interaction, RecordedRequest, RecordedResponse, RecordingClient,
+    RecordingError, ReplayClient,
+};
+pub use fake::{FakeTeamdeck, PAGE_SIZE};
+
+/// The base URL of the clients which do not talk to a real Teamdeck.
+const BASE_URL: &str = "http://teamdeck.test/v1/";
+
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum TestingError {
+    #[error("invalid request: {}", source)]
+    Request {
+        #[from]
+        source: http::Error,
+    },
+}
@@ -0,0 +1,335 @@
+//! Vacation balances: the days of vacation resources are entitled to, have used, have pending and
+//! have left.
+
+use std::collections::{HashMap, HashSet};
+
+use chrono::NaiveDate;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::api::{self, vacations::VacationReasons, ApiError, Client, Pagination, Query};
+use crate::capacity::{Absences, Capacity, DayCapacity};
+use crate::models::{Vacation, VacationStatus};
+
+/// The days of vacation of some reasons each resource is entitled to over the balance dates.
+///
+/// An entitlement without reasons or payability covers every vacation.
+#[derive(Debug, Clone)]
+pub struct Entitlement {
+    name: String,
+    days: f64,
+    reasons: HashSet<u64>,
+    payable: Option<bool>,
+    resource_days: HashMap<u64, f64>,
+}
+
+impl Entitlement {
+    pub fn new(name: impl Into<String>, days: f64) -> Self {
+        Self {
+            name: name.into(),
+            days,
+            reasons: HashSet::new(),
+            payable: None,
+            resource_days: HashMap::new(),
+        }
+    }
+
+    /// Cover vacations with this reason.
+    pub fn reason(mut self, reason_id: u64) -> Self {
+        self.reasons.insert(reason_id);
+        self
+    }
+
+    /// Only cover vacations whose reason is payable, or is not.
+    pub fn payable(mut self, payable: bool) -> Self {
+        self.payable = Some(payable);
+        self
+    }
+
+    /// The days a resource is entitled to instead, e.g. for someone who joined during the year.
+    pub fn resource(mut self, resource_id: u64, days: f64) -> Self {
+        self.resource_days.insert(resource_id, days);
+        self
+    }
+
+    fn days(&self, resource_id: u64) -> f64 {
+        self.resource_days
+            .get(&resource_id)
+            .copied()
+            .unwrap_or(self.days)
+    }
+
+    fn covers(&self, vacation: &Vacation, payable: &HashMap<u64, bool>) -> bool {
+        let reason = self.reasons.is_empty()
+            || vacation
+                .reason_id
+                .is_some_and(|reason| self.reasons.contains(&reason));
+        let payable = self.payable.is_none_or(|wanted| {
+            vacation
+                .reason_id
+                .and_then(|reason| payable.get(&reason))
+                .is_some_and(|payable| *payable == wanted)
+        });
+
+        reason && payable
+    }
+}
+
+/// The vacation days of a resource under an entitlement.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Balance {
+    pub resource_id: u64,
+    /// The name of the entitlement.
+    pub entitlement: String,
+    pub entitled: f64,
+    /// The days of approved vacations.
+    pub used: f64,
+    /// The days of pending vacations.
+    pub pending: f64,
+    /// The days entitled to less the used and pending days.
+    pub remaining: f64,
+}
+
+/// Calculates the vacation balances of resources over some dates, usually a year.
+///
+/// Only working days count, weekends and holidays of the capacity's work weeks and organization
+/// units are skipped. Vacations with a period such as a half day count as the share of the day the
+/// period takes. Each vacation counts towards the first entitlement covering it, rejected ones are
+/// ignored.
+#[derive(Debug, Clone)]
+pub struct VacationBalance {
+    from: NaiveDate,
+    to: NaiveDate,
+    capacity: Capacity,
+    entitlements: Vec<Entitlement>,
+}
+
+impl VacationBalance {
+    /// The balances from `from` to `to` inclusive.
+    pub fn new(from: NaiveDate, to: NaiveDate) -> Self {
+        Self {
+            from,
+            to,
+            capacity: Capacity::default(),
+            entitlements: Vec::new(),
+        }
+    }
+
+    /// The working days, Monday to Friday by default.
+    pub fn capacity(mut self, capacity: Capacity) -> Self {
+        self.capacity = capacity;
+        self
+    }
+
+    pub fn entitlement(mut self, entitlement: Entitlement) -> Self {
+        self.entitlements.push(entitlement);
+        self
+    }
+
+    /// The balances of resources from their vacations, given their capacity on each day of the
+    /// balance, the minutes of vacation periods by id and whether vacation reasons are payable by
+    /// id. Balances are ordered by resource and entitlement.
+    pub fn calculate(
+        &self,
+        resources: &[u64],
+        vacations: &[Vacation],
+        capacity: &[DayCapacity],
+        period_minutes: &HashMap<u64, u64>,
+        payable: &HashMap<u64, bool>,
+    ) -> Vec<Balance> {
+        // Holidays are left out of the working minutes, vacations are not.
+        let working = capacity
+            .iter()
+            .map(|day| ((day.resource_id, day.date), day.scheduled - day.holidays))
+            .collect::<HashMap<_, _>>();
+
+        // (used, pending) by resource and entitlement.
+        let mut days: HashMap<(u64, usize), (f64, f64)> = HashMap::new();
+        for vacation in vacations {
+            let entitlement = match self
+                .entitlements
+                .iter()
+                .position(|entitlement| entitlement.covers(vacation, payable))
+            {
+                Some(entitlement) => entitlement,
+                None => continue,
+            };
+
+            let from = vacation.start_date.max(self.from);
+            let to = vacation.end_date.min(self.to);
+            let vacation_days = from
+                .iter_days()
+                .take_while(|date| *date <= to)
+                .filter_map(|date| working.get(&(vacation.resource_id, date)))
+                .filter(|working| **working > 0)
+                .map(|&working| {
+                    vacation
+                        .period_id
+                        .and_then(|period| period_minutes.get(&period))
+                        .map_or(1.0, |&minutes| minutes.min(working) as f64 / working as f64)
+                })
+                .sum::<f64>();
+
+            let days = days.entry((vacation.resource_id, entitlement)).or_default();
+            match vacation.status {
+                VacationStatus::Approved => days.0 += vacation_days,
+                VacationStatus::Pending => days.1 += vacation_days,
+                _ => {}
+            }
+        }
+
+        resources
+            .iter()
+            .flat_map(|&resource_id| {
+                self.entitlements
+                    .iter()
+                    .enumerate()
+                    .map(move |(index, entitlement)| (resource_id, index, entitlement))
+            })
+            .map(|(resource_id, index, entitlement)| {
+                let (used, pending) = days.get(&(resource_id, index)).copied().unwrap_or_default();
+                let entitled = entitlement.days(resource_id);
+                Balance {
+                    resource_id,
+                    entitlement: entitlement.name.clone(),
+                    entitled,
+                    used,
+                    pending,
+                    remaining: entitled - used - pending,
+                }
+            })
+            .collect()
+    }
+
+    /// Fetch the vacations, holidays, vacation periods and, when an entitlement needs them,
+    /// vacation reasons, and calculate the balances of resources.
+    pub fn fetch<C: Client>(
+        &self,
+        resources: &[u64],
+        client: &C,
+    ) -> Result<Vec<Balance>, ApiError<C::Error>> {
+        if resources.is_empty() || self.to < self.from {
+            return Ok(vec![]);
+        }
+
+        let absences = Absences::fetch(resources, self.from, self.to, client)?;
+        let capacity = absences.capacity(&self.capacity, resources, self.from, self.to);
+
+        let mut payable = HashMap::new();
+        if self
+            .entitlements
+            .iter()
+            .any(|entitlement| entitlement.payable.is_some())
+        {
+            let endpoint = VacationReasons::builder().build().unwrap();
+            let reasons: Vec<Value> = api::paged(endpoint, Pagination::All).query(client)?;
+            payable = reasons
+                .iter()
+                .filter_map(|reason| Some((reason["id"].as_u64()?, reason["payable"].as_bool()?)))
+                .collect();
+        }
+
+        Ok(self.calculate(
+            resources,
+            &absences.vacations,
+            &capacity,
+            &absences.period_minutes,
+            &payable,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::testing::fixtures::{insert_holiday, vacation};
+    use crate::testing::FakeTeamdeck;
+
+    fn date(month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, month, day).unwrap()
+    }
+
+    #[test]
+    fn counts_working_days_by_entitlement() {
+        let teamdeck = FakeTeamdeck::new();
+        let paid = teamdeck.insert(
+            "vacation-reasons",
+            json!({ "name": "Annual leave", "payable": true }),
+        );
+        let unpaid = teamdeck.insert(
+            "vacation-reasons",
+            json!({ "name": "Unpaid leave", "payable": false }),
+        );
+        let sick = teamdeck.insert(
+            "vacation-reasons",
+            json!({ "name": "Sick leave", "payable": true }),
+        );
+        let half_day = teamdeck.insert(
+            "vacation-periods",
+            json!({ "name": "Half day", "hours_per_day": 4 }),
+        );
+        // Whit Monday.
+        insert_holiday(&teamdeck, "2026-05-25");
+        let vacation = |resource_id, reason_id, start: &str, end: &str, status, period_id| {
+            let mut record = vacation(resource_id, start, end, status);
+            record["reason_id"] = json!(reason_id);
+            record["period_id"] = json!(period_id);
+            record
+        };
+        for vacation in [
+            // From Thursday to the next Tuesday, over the weekend and a holiday.
+            vacation(1, paid, "2026-05-21", "2026-05-26", "approved", None),
+            vacation(
+                1,
+                paid,
+                "2026-06-03",
+                "2026-06-03",
+                "approved",
+                Some(half_day),
+            ),
+            vacation(1, paid, "2026-07-06", "2026-07-10", "pending", None),
+            vacation(1, paid, "2026-08-03", "2026-08-04", "rejected", None),
+            vacation(1, sick, "2026-06-08", "2026-06-09", "approved", None),
+            vacation(1, unpaid, "2026-09-07", "2026-09-07", "approved", None),
+            // Partly in the previous year.
+            vacation(2, paid, "2025-12-29", "2026-01-02", "approved", None),
+        ] {
+            teamdeck.insert("vacations", vacation);
+        }
+
+        let balances = VacationBalance::new(date(1, 1), date(12, 31))
+            .entitlement(Entitlement::new("Sick", 10.0).reason(sick))
+            .entitlement(
+                Entitlement::new("Paid", 25.0)
+                    .payable(true)
+                    .resource(2, 20.0),
+            )
+            .fetch(&[1, 2], &teamdeck)
+            .unwrap();
+
+        let balances = balances
+            .iter()
+            .map(|balance| {
+                (
+                    balance.resource_id,
+                    balance.entitlement.as_str(),
+                    balance.entitled,
+                    balance.used,
+                    balance.pending,
+                    balance.remaining,
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            balances,
+            vec![
+                (1, "Sick", 10.0, 2.0, 0.0, 8.0),
+                (1, "Paid", 25.0, 3.5, 5.0, 16.5),
+                (2, "Sick", 10.0, 0.0, 0.0, 10.0),
+                (2, "Paid", 20.0, 2.0, 0.0, 18.0),
+            ]
+        );
+    }
+}
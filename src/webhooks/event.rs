@@ -0,0 +1,165 @@
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::api::webhooks::WebhookEventKind;
+use crate::models::{Booking, TimeEntry, Vacation};
+
+use super::WebhookError;
+
+/// An event delivered by a Teamdeck webhook.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum WebhookEvent {
+    BookingCreated(Booking),
+    BookingUpdated(Booking),
+    BookingDeleted(Booking),
+    TimeEntryCreated(TimeEntry),
+    TimeEntryUpdated(TimeEntry),
+    TimeEntryDeleted(TimeEntry),
+    VacationCreated(Vacation),
+    VacationUpdated(Vacation),
+    VacationApproved(Vacation),
+    VacationRejected(Vacation),
+    VacationDeleted(Vacation),
+    /// An event this version of the crate does not know about.
+    Unknown {
+        event: String,
+        data: Value,
+    },
+}
+
+#[derive(Debug, Deserialize)]
+struct Envelope {
+    event: String,
+    #[serde(default)]
+    data: Value,
+}
+
+impl WebhookEvent {
+    /// Parse a webhook payload. This does not verify the signature of the payload, see
+    /// [`WebhookVerifier::parse`](super::WebhookVerifier::parse).
+    pub fn from_slice(body: &[u8]) -> Result<Self, WebhookError> {
+        let Envelope { event, data } = serde_json::from_slice(body)?;
+
+        let event = match event.as_str() {
+            "booking.created" => Self::BookingCreated(serde_json::from_value(data)?),
+            "booking.updated" => Self::BookingUpdated(serde_json::from_value(data)?),
+            "booking.deleted" => Self::BookingDeleted(serde_json::from_value(data)?),
+            "time_entry.created" => Self::TimeEntryCreated(serde_json::from_value(data)?),
+            "time_entry.updated" => Self::TimeEntryUpdated(serde_json::from_value(data)?),
+            "time_entry.deleted" => Self::TimeEntryDeleted(serde_json::from_value(data)?),
+            "vacation.created" => Self::VacationCreated(serde_json::from_value(data)?),
+            "vacation.updated" => Self::VacationUpdated(serde_json::from_value(data)?),
+            "vacation.approved" => Self::VacationApproved(serde_json::from_value(data)?),
+            "vacation.rejected" => Self::VacationRejected(serde_json::from_value(data)?),
+            "vacation.deleted" => Self::VacationDeleted(serde_json::from_value(data)?),
+            _ => Self::Unknown { event, data },
+        };
+
+        Ok(event)
+    }
+
+    /// The kind of the event, `None` for unknown events.
+    pub fn kind(&self) -> Option<WebhookEventKind> {
+        let kind = match self {
+            Self::BookingCreated(_) => WebhookEventKind::BookingCreated,
+            Self::BookingUpdated(_) => WebhookEventKind::BookingUpdated,
+            Self::BookingDeleted(_) => WebhookEventKind::BookingDeleted,
+            Self::TimeEntryCreated(_) => WebhookEventKind::TimeEntryCreated,
+            Self::TimeEntryUpdated(_) => WebhookEventKind::TimeEntryUpdated,
+            Self::TimeEntryDeleted(_) => WebhookEventKind::TimeEntryDeleted,
+            Self::VacationCreated(_) => WebhookEventKind::VacationCreated,
+            Self::VacationUpdated(_) => WebhookEventKind::VacationUpdated,
+            Self::VacationApproved(_) => WebhookEventKind::VacationApproved,
+            Self::VacationRejected(_) => WebhookEventKind::VacationRejected,
+            Self::VacationDeleted(_) => WebhookEventKind::VacationDeleted,
+            Self::Unknown { .. } => return None,
+        };
+
+        Some(kind)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use serde_json::json;
+
+    use super::*;
+    use crate::models::VacationStatus;
+
+    #[test]
+    fn parses_booking_event() {
+        let body = json!({
+            "event": "booking.created",
+            "data": {
+                "id": 1,
+                "resource_id": 2,
+                "project_id": 3,
+                "start_date": "2026-01-05",
+                "end_date": "2026-01-09",
+                "minutes": 480,
+            },
+        });
+
+        let event = WebhookEvent::from_slice(body.to_string().as_bytes()).unwrap();
+
+        assert_eq!(event.kind(), Some(WebhookEventKind::BookingCreated));
+        if let WebhookEvent::BookingCreated(booking) = event {
+            assert_eq!(booking.id, 1);
+            assert_eq!(
+                booking.start_date,
+                NaiveDate::from_ymd_opt(2026, 1, 5).unwrap()
+            );
+        } else {
+            panic!("unexpected event: {:?}", event);
+        }
+    }
+
+    #[test]
+    fn parses_vacation_event() {
+        let body = json!({
+            "event": "vacation.approved",
+            "data": {
+                "id": 1,
+                "resource_id": 2,
+                "start_date": "2026-01-05",
+                "end_date": "2026-01-09",
+                "status": "approved",
+            },
+        });
+
+        let event = WebhookEvent::from_slice(body.to_string().as_bytes()).unwrap();
+
+        if let WebhookEvent::VacationApproved(vacation) = event {
+            assert_eq!(vacation.status, VacationStatus::Approved);
+        } else {
+            panic!("unexpected event: {:?}", event);
+        }
+    }
+
+    #[test]
+    fn keeps_unknown_events() {
+        let body = json!({ "event": "project.archived", "data": { "id": 1 } });
+
+        let event = WebhookEvent::from_slice(body.to_string().as_bytes()).unwrap();
+
+        assert_eq!(event.kind(), None);
+        assert_eq!(
+            event,
+            WebhookEvent::Unknown {
+                event: "project.archived".into(),
+                data: json!({ "id": 1 }),
+            }
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_payload() {
+        let body = json!({ "event": "time_entry.updated", "data": { "id": "one" } });
+
+        let err = WebhookEvent::from_slice(body.to_string().as_bytes()).unwrap_err();
+
+        assert!(matches!(err, WebhookError::Payload { .. }));
+    }
+}
@@ -0,0 +1,127 @@
+use bytes::Bytes;
+use http::{Method, Request, Response, StatusCode};
+use log::warn;
+
+use super::{WebhookError, WebhookEvent, WebhookVerifier};
+
+/// A framework-agnostic HTTP handler for Teamdeck webhook deliveries.
+///
+/// It works on `http` types, so it can be called from an axum or hyper service once the request
+/// body has been collected into [`Bytes`].
+pub struct WebhookHandler<F> {
+    verifier: WebhookVerifier,
+    on_event: F,
+}
+
+impl<F> WebhookHandler<F>
+where
+    F: Fn(WebhookEvent),
+{
+    pub fn new(verifier: WebhookVerifier, on_event: F) -> Self {
+        Self { verifier, on_event }
+    }
+
+    /// Verify and parse the request and pass the event to the callback.
+    ///
+    /// Responds with `204 No Content` once the event was handled, `401 Unauthorized` when the
+    /// signature is missing or invalid, `400 Bad Request` when the payload could not be parsed
+    /// and `405 Method Not Allowed` for anything but `POST`.
+    pub fn handle(&self, request: &Request<Bytes>) -> Response<Bytes> {
+        if request.method() != Method::POST {
+            return response(StatusCode::METHOD_NOT_ALLOWED);
+        }
+
+        match self.verifier.parse(request.headers(), request.body()) {
+            Ok(event) => {
+                (self.on_event)(event);
+                response(StatusCode::NO_CONTENT)
+            }
+            Err(err @ (WebhookError::MissingSignature | WebhookError::InvalidSignature)) => {
+                warn!(target: "teamdeck", "rejected webhook delivery: {}", err);
+                response(StatusCode::UNAUTHORIZED)
+            }
+            Err(err) => {
+                warn!(target: "teamdeck", "rejected webhook delivery: {}", err);
+                response(StatusCode::BAD_REQUEST)
+            }
+        }
+    }
+}
+
+fn response(status: StatusCode) -> Response<Bytes> {
+    Response::builder()
+        .status(status)
+        .body(Bytes::new())
+        .expect("a response with only a status is valid")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use serde_json::json;
+
+    use super::*;
+    use crate::webhooks::{sign, SIGNATURE_HEADER};
+
+    const SECRET: &[u8] = b"s3cr3t";
+
+    fn request(method: Method, body: &[u8], signature: Option<String>) -> Request<Bytes> {
+        let mut request = Request::builder().method(method).uri("/hooks/teamdeck");
+        if let Some(signature) = signature {
+            request = request.header(SIGNATURE_HEADER, signature);
+        }
+        request.body(Bytes::copy_from_slice(body)).unwrap()
+    }
+
+    #[test]
+    fn handles_signed_event() {
+        let events = RefCell::new(vec![]);
+        let handler = WebhookHandler::new(WebhookVerifier::new(SECRET), |event| {
+            events.borrow_mut().push(event)
+        });
+        let body = json!({ "event": "project.created", "data": {} }).to_string();
+
+        let response = handler.handle(&request(
+            Method::POST,
+            body.as_bytes(),
+            Some(sign(SECRET, body.as_bytes())),
+        ));
+
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+        assert_eq!(events.borrow().len(), 1);
+    }
+
+    #[test]
+    fn rejects_unsigned_event() {
+        let handler = WebhookHandler::new(WebhookVerifier::new(SECRET), |_| {
+            panic!("unsigned events must not be handled")
+        });
+
+        let response = handler.handle(&request(Method::POST, b"{}", None));
+
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[test]
+    fn rejects_malformed_payload() {
+        let handler = WebhookHandler::new(WebhookVerifier::new(SECRET), |_| {});
+
+        let response = handler.handle(&request(
+            Method::POST,
+            b"not json",
+            Some(sign(SECRET, b"not json")),
+        ));
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[test]
+    fn rejects_other_methods() {
+        let handler = WebhookHandler::new(WebhookVerifier::new(SECRET), |_| {});
+
+        let response = handler.handle(&request(Method::GET, b"", None));
+
+        assert_eq!(response.status(), StatusCode::METHOD_NOT_ALLOWED);
+    }
+}
@@ -0,0 +1,32 @@
+//! Receiving Teamdeck webhook deliveries.
+//!
+//! Deliveries are JSON objects of the form `{"event": "booking.created", "data": {...}}`,
+//! signed with HMAC-SHA256 using the secret given when the webhook was registered with
+//! [`CreateWebhook`](crate::api::webhooks::CreateWebhook). The hex encoded signature is sent in
+//! the [`SIGNATURE_HEADER`] header.
+
+mod event;
+#[cfg(feature = "webhook-handler")]
+mod handler;
+mod signature;
+
+use thiserror::Error;
+
+pub use event::WebhookEvent;
+#[cfg(feature = "webhook-handler")]
+pub use handler::WebhookHandler;
+pub use signature::{sign, verify_signature, WebhookVerifier, SIGNATURE_HEADER};
+
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum WebhookError {
+    #[error("missing `{}` header", SIGNATURE_HEADER)]
+    MissingSignature,
+    #[error("webhook signature does not match the payload")]
+    InvalidSignature,
+    #[error("could not parse webhook payload: {}", source)]
+    Payload {
+        #[from]
+        source: serde_json::Error,
+    },
+}
@@ -0,0 +1,145 @@
+use std::fmt;
+
+use hmac::{Hmac, Mac};
+use http::HeaderMap;
+use sha2::Sha256;
+
+use super::{WebhookError, WebhookEvent};
+
+/// The header carrying the HMAC-SHA256 signature of the request body.
+pub const SIGNATURE_HEADER: &str = "X-Teamdeck-Signature";
+
+const SIGNATURE_PREFIX: &str = "sha256=";
+
+type HmacSha256 = Hmac<Sha256>;
+
+fn mac(secret: &[u8]) -> HmacSha256 {
+    HmacSha256::new_from_slice(secret).expect("HMAC accepts keys of any length")
+}
+
+/// Compute the signature of a payload, in the format sent in [`SIGNATURE_HEADER`].
+pub fn sign(secret: &[u8], body: &[u8]) -> String {
+    let mut mac = mac(secret);
+    mac.update(body);
+    format!(
+        "{}{}",
+        SIGNATURE_PREFIX,
+        hex::encode(mac.finalize().into_bytes())
+    )
+}
+
+/// Verify the signature of a payload. The comparison is done in constant time.
+pub fn verify_signature(secret: &[u8], body: &[u8], signature: &str) -> Result<(), WebhookError> {
+    let signature = signature.trim();
+    let signature = signature
+        .strip_prefix(SIGNATURE_PREFIX)
+        .unwrap_or(signature);
+    let signature = hex::decode(signature).map_err(|_| WebhookError::InvalidSignature)?;
+
+    let mut mac = mac(secret);
+    mac.update(body);
+    mac.verify_slice(&signature)
+        .map_err(|_| WebhookError::InvalidSignature)
+}
+
+/// Checks that webhook requests were sent by Teamdeck before parsing them.
+#[derive(Clone)]
+pub struct WebhookVerifier {
+    secret: Vec<u8>,
+}
+
+impl fmt::Debug for WebhookVerifier {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("WebhookVerifier").finish_non_exhaustive()
+    }
+}
+
+impl WebhookVerifier {
+    pub fn new<T: Into<Vec<u8>>>(secret: T) -> Self {
+        Self {
+            secret: secret.into(),
+        }
+    }
+
+    /// Verify the signature header of a request against its body.
+    pub fn verify(&self, headers: &HeaderMap, body: &[u8]) -> Result<(), WebhookError> {
+        let signature = headers
+            .get(SIGNATURE_HEADER)
+            .ok_or(WebhookError::MissingSignature)?
+            .to_str()
+            .map_err(|_| WebhookError::InvalidSignature)?;
+
+        verify_signature(&self.secret, body, signature)
+    }
+
+    /// Verify a request and parse its body into an event.
+    pub fn parse(&self, headers: &HeaderMap, body: &[u8]) -> Result<WebhookEvent, WebhookError> {
+        self.verify(headers, body)?;
+        WebhookEvent::from_slice(body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECRET: &[u8] = b"s3cr3t";
+    const BODY: &[u8] = br#"{"event":"booking.deleted","data":{}}"#;
+
+    fn headers(signature: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(SIGNATURE_HEADER, signature.parse().unwrap());
+        headers
+    }
+
+    #[test]
+    fn accepts_valid_signature() {
+        let signature = sign(SECRET, BODY);
+
+        assert!(signature.starts_with("sha256="));
+        verify_signature(SECRET, BODY, &signature).unwrap();
+    }
+
+    #[test]
+    fn accepts_signature_without_prefix() {
+        let signature = sign(SECRET, BODY);
+
+        verify_signature(SECRET, BODY, signature.trim_start_matches("sha256=")).unwrap();
+    }
+
+    #[test]
+    fn rejects_tampered_body() {
+        let signature = sign(SECRET, BODY);
+
+        let err = verify_signature(SECRET, b"{}", &signature).unwrap_err();
+
+        assert!(matches!(err, WebhookError::InvalidSignature));
+    }
+
+    #[test]
+    fn rejects_wrong_secret() {
+        let signature = sign(b"other", BODY);
+
+        let err = WebhookVerifier::new(SECRET)
+            .verify(&headers(&signature), BODY)
+            .unwrap_err();
+
+        assert!(matches!(err, WebhookError::InvalidSignature));
+    }
+
+    #[test]
+    fn rejects_missing_signature() {
+        let err = WebhookVerifier::new(SECRET)
+            .verify(&HeaderMap::new(), BODY)
+            .unwrap_err();
+
+        assert!(matches!(err, WebhookError::MissingSignature));
+    }
+
+    #[test]
+    fn verifier_does_not_leak_secret() {
+        let debug = format!("{:?}", WebhookVerifier::new(SECRET));
+
+        assert!(!debug.contains("s3cr3t"));
+    }
+}